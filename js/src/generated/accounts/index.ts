@@ -1,7 +1,17 @@
+export * from './AuthorityProposal';
 export * from './CandyGuard';
+export * from './Delegate';
 export * from './FreezeEscrow';
+export * from './Receipt';
+export * from './ScheduledUpdate';
+export * from './UpdateBuffer';
 
 import { FreezeEscrow } from './FreezeEscrow';
+import { Receipt } from './Receipt';
+import { AuthorityProposal } from './AuthorityProposal';
 import { CandyGuard } from './CandyGuard';
+import { Delegate } from './Delegate';
+import { ScheduledUpdate } from './ScheduledUpdate';
+import { UpdateBuffer } from './UpdateBuffer';
 
-export const accountProviders = { FreezeEscrow, CandyGuard };
+export const accountProviders = { FreezeEscrow, Receipt, AuthorityProposal, CandyGuard, Delegate, ScheduledUpdate, UpdateBuffer };
@@ -961,6 +961,1077 @@ export class ExceededProgramListSizeError extends Error {
 createErrorFromCodeLookup.set(0x179d, () => new ExceededProgramListSizeError());
 createErrorFromNameLookup.set('ExceededProgramListSize', () => new ExceededProgramListSizeError());
 
+/**
+ * GuardNotAllowedInGroup: 'Guard is only allowed in the default guard set'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class GuardNotAllowedInGroupError extends Error {
+  readonly code: number = 0x179e;
+  readonly name: string = 'GuardNotAllowedInGroup';
+  constructor() {
+    super('Guard is only allowed in the default guard set');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, GuardNotAllowedInGroupError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new GuardNotAllowedInGroupError());
+createErrorFromNameLookup.set('GuardNotAllowedInGroup', () => new GuardNotAllowedInGroupError());
+
+/**
+ * ScheduledUpdateAlreadyExists: 'A scheduled update already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ScheduledUpdateAlreadyExistsError extends Error {
+  readonly code: number = 0x179f;
+  readonly name: string = 'ScheduledUpdateAlreadyExists';
+  constructor() {
+    super('A scheduled update already exists');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ScheduledUpdateAlreadyExistsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new ScheduledUpdateAlreadyExistsError());
+createErrorFromNameLookup.set(
+  'ScheduledUpdateAlreadyExists',
+  () => new ScheduledUpdateAlreadyExistsError(),
+);
+
+/**
+ * InvalidActivationTime: 'Activation time must be in the future'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidActivationTimeError extends Error {
+  readonly code: number = 0x17a0;
+  readonly name: string = 'InvalidActivationTime';
+  constructor() {
+    super('Activation time must be in the future');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidActivationTimeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a0, () => new InvalidActivationTimeError());
+createErrorFromNameLookup.set('InvalidActivationTime', () => new InvalidActivationTimeError());
+
+/**
+ * ScheduledUpdateNotActive: 'Scheduled update is not active'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ScheduledUpdateNotActiveError extends Error {
+  readonly code: number = 0x17a1;
+  readonly name: string = 'ScheduledUpdateNotActive';
+  constructor() {
+    super('Scheduled update is not active');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ScheduledUpdateNotActiveError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a1, () => new ScheduledUpdateNotActiveError());
+createErrorFromNameLookup.set(
+  'ScheduledUpdateNotActive',
+  () => new ScheduledUpdateNotActiveError(),
+);
+
+/**
+ * MissingScheduledUpdate: 'Missing scheduled update account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingScheduledUpdateError extends Error {
+  readonly code: number = 0x17a2;
+  readonly name: string = 'MissingScheduledUpdate';
+  constructor() {
+    super('Missing scheduled update account');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingScheduledUpdateError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a2, () => new MissingScheduledUpdateError());
+createErrorFromNameLookup.set('MissingScheduledUpdate', () => new MissingScheduledUpdateError());
+
+/**
+ * DelegateNotAuthorized: 'Signer is not authorized to perform the action'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DelegateNotAuthorizedError extends Error {
+  readonly code: number = 0x17a3;
+  readonly name: string = 'DelegateNotAuthorized';
+  constructor() {
+    super('Signer is not authorized to perform the action');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DelegateNotAuthorizedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a3, () => new DelegateNotAuthorizedError());
+createErrorFromNameLookup.set('DelegateNotAuthorized', () => new DelegateNotAuthorizedError());
+
+/**
+ * InvalidDelegateRoles: 'Invalid delegate roles'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidDelegateRolesError extends Error {
+  readonly code: number = 0x17a4;
+  readonly name: string = 'InvalidDelegateRoles';
+  constructor() {
+    super('Invalid delegate roles');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidDelegateRolesError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a4, () => new InvalidDelegateRolesError());
+createErrorFromNameLookup.set('InvalidDelegateRoles', () => new InvalidDelegateRolesError());
+
+/**
+ * DelegateUpdateNotAllowed: 'Update not allowed for the delegate role'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DelegateUpdateNotAllowedError extends Error {
+  readonly code: number = 0x17a5;
+  readonly name: string = 'DelegateUpdateNotAllowed';
+  constructor() {
+    super('Update not allowed for the delegate role');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DelegateUpdateNotAllowedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a5, () => new DelegateUpdateNotAllowedError());
+createErrorFromNameLookup.set(
+  'DelegateUpdateNotAllowed',
+  () => new DelegateUpdateNotAllowedError(),
+);
+
+/**
+ * ConfigLocked: 'Candy guard configuration is locked'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ConfigLockedError extends Error {
+  readonly code: number = 0x17a6;
+  readonly name: string = 'ConfigLocked';
+  constructor() {
+    super('Candy guard configuration is locked');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ConfigLockedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a6, () => new ConfigLockedError());
+createErrorFromNameLookup.set('ConfigLocked', () => new ConfigLockedError());
+
+/**
+ * InvalidLockTime: 'Lock time must be in the future and cannot shorten an existing lock'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidLockTimeError extends Error {
+  readonly code: number = 0x17a7;
+  readonly name: string = 'InvalidLockTime';
+  constructor() {
+    super('Lock time must be in the future and cannot shorten an existing lock');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidLockTimeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a7, () => new InvalidLockTimeError());
+createErrorFromNameLookup.set('InvalidLockTime', () => new InvalidLockTimeError());
+
+/**
+ * PendingUpdateNotAllowed: 'Configuration cannot be locked while an update is scheduled'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PendingUpdateNotAllowedError extends Error {
+  readonly code: number = 0x17a8;
+  readonly name: string = 'PendingUpdateNotAllowed';
+  constructor() {
+    super('Configuration cannot be locked while an update is scheduled');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PendingUpdateNotAllowedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a8, () => new PendingUpdateNotAllowedError());
+createErrorFromNameLookup.set('PendingUpdateNotAllowed', () => new PendingUpdateNotAllowedError());
+
+/**
+ * MintPaused: 'Mint is paused'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MintPausedError extends Error {
+  readonly code: number = 0x17a9;
+  readonly name: string = 'MintPaused';
+  constructor() {
+    super('Mint is paused');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MintPausedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a9, () => new MintPausedError());
+createErrorFromNameLookup.set('MintPaused', () => new MintPausedError());
+
+/**
+ * RoutePaused: 'Route instruction is paused'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RoutePausedError extends Error {
+  readonly code: number = 0x17aa;
+  readonly name: string = 'RoutePaused';
+  constructor() {
+    super('Route instruction is paused');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RoutePausedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17aa, () => new RoutePausedError());
+createErrorFromNameLookup.set('RoutePaused', () => new RoutePausedError());
+
+/**
+ * MintReceiptAlreadyExists: 'The mint receipt account already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MintReceiptAlreadyExistsError extends Error {
+  readonly code: number = 0x17ab;
+  readonly name: string = 'MintReceiptAlreadyExists';
+  constructor() {
+    super('The mint receipt account already exists');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MintReceiptAlreadyExistsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new MintReceiptAlreadyExistsError());
+createErrorFromNameLookup.set(
+  'MintReceiptAlreadyExists',
+  () => new MintReceiptAlreadyExistsError(),
+);
+
+/**
+ * ExceededExemptionListSize: 'Exceeded the maximum number of error codes in the exemption list'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ExceededExemptionListSizeError extends Error {
+  readonly code: number = 0x17ac;
+  readonly name: string = 'ExceededExemptionListSize';
+  constructor() {
+    super('Exceeded the maximum number of error codes in the exemption list');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ExceededExemptionListSizeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ac, () => new ExceededExemptionListSizeError());
+createErrorFromNameLookup.set(
+  'ExceededExemptionListSize',
+  () => new ExceededExemptionListSizeError(),
+);
+
+/**
+ * InvalidExemptionCode: 'Exemption list contains an invalid error code'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidExemptionCodeError extends Error {
+  readonly code: number = 0x17ad;
+  readonly name: string = 'InvalidExemptionCode';
+  constructor() {
+    super('Exemption list contains an invalid error code');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidExemptionCodeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ad, () => new InvalidExemptionCodeError());
+createErrorFromNameLookup.set('InvalidExemptionCode', () => new InvalidExemptionCodeError());
+
+/**
+ * DuplicatedExemptionCode: 'Duplicated error code in the exemption list'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DuplicatedExemptionCodeError extends Error {
+  readonly code: number = 0x17ae;
+  readonly name: string = 'DuplicatedExemptionCode';
+  constructor() {
+    super('Duplicated error code in the exemption list');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DuplicatedExemptionCodeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ae, () => new DuplicatedExemptionCodeError());
+createErrorFromNameLookup.set('DuplicatedExemptionCode', () => new DuplicatedExemptionCodeError());
+
+/**
+ * ExceededInstructionCount: 'Transaction exceeded the maximum number of instructions'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ExceededInstructionCountError extends Error {
+  readonly code: number = 0x17af;
+  readonly name: string = 'ExceededInstructionCount';
+  constructor() {
+    super('Transaction exceeded the maximum number of instructions');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ExceededInstructionCountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17af, () => new ExceededInstructionCountError());
+createErrorFromNameLookup.set(
+  'ExceededInstructionCount',
+  () => new ExceededInstructionCountError(),
+);
+
+/**
+ * ForbiddenInstructionFound: 'A forbidden instruction was found in the transaction'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ForbiddenInstructionFoundError extends Error {
+  readonly code: number = 0x17b0;
+  readonly name: string = 'ForbiddenInstructionFound';
+  constructor() {
+    super('A forbidden instruction was found in the transaction');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ForbiddenInstructionFoundError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b0, () => new ForbiddenInstructionFoundError());
+createErrorFromNameLookup.set(
+  'ForbiddenInstructionFound',
+  () => new ForbiddenInstructionFoundError(),
+);
+
+/**
+ * MultipleCandyGuardInstructions: 'Mint must be the only candy guard instruction in the transaction'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MultipleCandyGuardInstructionsError extends Error {
+  readonly code: number = 0x17b1;
+  readonly name: string = 'MultipleCandyGuardInstructions';
+  constructor() {
+    super('Mint must be the only candy guard instruction in the transaction');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MultipleCandyGuardInstructionsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b1, () => new MultipleCandyGuardInstructionsError());
+createErrorFromNameLookup.set(
+  'MultipleCandyGuardInstructions',
+  () => new MultipleCandyGuardInstructionsError(),
+);
+
+/**
+ * ExceededInstructionListSize: 'Exceeded the maximum number of instructions in the forbidden list'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ExceededInstructionListSizeError extends Error {
+  readonly code: number = 0x17b2;
+  readonly name: string = 'ExceededInstructionListSize';
+  constructor() {
+    super('Exceeded the maximum number of instructions in the forbidden list');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ExceededInstructionListSizeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b2, () => new ExceededInstructionListSizeError());
+createErrorFromNameLookup.set(
+  'ExceededInstructionListSize',
+  () => new ExceededInstructionListSizeError(),
+);
+
+/**
+ * InvalidDiscriminatorSize: 'Invalid instruction discriminator size'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidDiscriminatorSizeError extends Error {
+  readonly code: number = 0x17b3;
+  readonly name: string = 'InvalidDiscriminatorSize';
+  constructor() {
+    super('Invalid instruction discriminator size');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidDiscriminatorSizeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b3, () => new InvalidDiscriminatorSizeError());
+createErrorFromNameLookup.set(
+  'InvalidDiscriminatorSize',
+  () => new InvalidDiscriminatorSizeError(),
+);
+
+/**
+ * InvalidInstructionsSysvar: 'Invalid instructions sysvar data'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidInstructionsSysvarError extends Error {
+  readonly code: number = 0x17b4;
+  readonly name: string = 'InvalidInstructionsSysvar';
+  constructor() {
+    super('Invalid instructions sysvar data');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidInstructionsSysvarError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b4, () => new InvalidInstructionsSysvarError());
+createErrorFromNameLookup.set(
+  'InvalidInstructionsSysvar',
+  () => new InvalidInstructionsSysvarError(),
+);
+
+/**
+ * MissingVoucher: 'Missing signed voucher'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingVoucherError extends Error {
+  readonly code: number = 0x17b5;
+  readonly name: string = 'MissingVoucher';
+  constructor() {
+    super('Missing signed voucher');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingVoucherError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b5, () => new MissingVoucherError());
+createErrorFromNameLookup.set('MissingVoucher', () => new MissingVoucherError());
+
+/**
+ * VoucherExpired: 'Signed voucher has expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VoucherExpiredError extends Error {
+  readonly code: number = 0x17b6;
+  readonly name: string = 'VoucherExpired';
+  constructor() {
+    super('Signed voucher has expired');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VoucherExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b6, () => new VoucherExpiredError());
+createErrorFromNameLookup.set('VoucherExpired', () => new VoucherExpiredError());
+
+/**
+ * VoucherAlreadyUsed: 'Signed voucher has already been used'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VoucherAlreadyUsedError extends Error {
+  readonly code: number = 0x17b7;
+  readonly name: string = 'VoucherAlreadyUsed';
+  constructor() {
+    super('Signed voucher has already been used');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VoucherAlreadyUsedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b7, () => new VoucherAlreadyUsedError());
+createErrorFromNameLookup.set('VoucherAlreadyUsed', () => new VoucherAlreadyUsedError());
+
+/**
+ * ExceededSignerListSize: 'Exceeded the maximum number of signer keys'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ExceededSignerListSizeError extends Error {
+  readonly code: number = 0x17b8;
+  readonly name: string = 'ExceededSignerListSize';
+  constructor() {
+    super('Exceeded the maximum number of signer keys');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ExceededSignerListSizeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b8, () => new ExceededSignerListSizeError());
+createErrorFromNameLookup.set('ExceededSignerListSize', () => new ExceededSignerListSizeError());
+
+/**
+ * InvalidSignerThreshold: 'Signer threshold must be between 1 and the number of signer keys'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidSignerThresholdError extends Error {
+  readonly code: number = 0x17b9;
+  readonly name: string = 'InvalidSignerThreshold';
+  constructor() {
+    super('Signer threshold must be between 1 and the number of signer keys');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidSignerThresholdError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b9, () => new InvalidSignerThresholdError());
+createErrorFromNameLookup.set('InvalidSignerThreshold', () => new InvalidSignerThresholdError());
+
+/**
+ * DuplicatedSignerKey: 'Duplicated key in the signer list'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DuplicatedSignerKeyError extends Error {
+  readonly code: number = 0x17ba;
+  readonly name: string = 'DuplicatedSignerKey';
+  constructor() {
+    super('Duplicated key in the signer list');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DuplicatedSignerKeyError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ba, () => new DuplicatedSignerKeyError());
+createErrorFromNameLookup.set('DuplicatedSignerKey', () => new DuplicatedSignerKeyError());
+
+/**
+ * NonTransferableToken: 'Non-transferable tokens cannot be used as payment'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NonTransferableTokenError extends Error {
+  readonly code: number = 0x17bb;
+  readonly name: string = 'NonTransferableToken';
+  constructor() {
+    super('Non-transferable tokens cannot be used as payment');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NonTransferableTokenError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bb, () => new NonTransferableTokenError());
+createErrorFromNameLookup.set('NonTransferableToken', () => new NonTransferableTokenError());
+
+/**
+ * DuplicatedTokenAccount: 'Duplicated token account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DuplicatedTokenAccountError extends Error {
+  readonly code: number = 0x17bc;
+  readonly name: string = 'DuplicatedTokenAccount';
+  constructor() {
+    super('Duplicated token account');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DuplicatedTokenAccountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bc, () => new DuplicatedTokenAccountError());
+createErrorFromNameLookup.set('DuplicatedTokenAccount', () => new DuplicatedTokenAccountError());
+
+/**
+ * MissingMintArgument: 'Missing mint argument'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingMintArgumentError extends Error {
+  readonly code: number = 0x17bd;
+  readonly name: string = 'MissingMintArgument';
+  constructor() {
+    super('Missing mint argument');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingMintArgumentError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bd, () => new MissingMintArgumentError());
+createErrorFromNameLookup.set('MissingMintArgument', () => new MissingMintArgumentError());
+
+/**
+ * InvalidStakeRecord: 'Invalid stake record'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidStakeRecordError extends Error {
+  readonly code: number = 0x17be;
+  readonly name: string = 'InvalidStakeRecord';
+  constructor() {
+    super('Invalid stake record');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidStakeRecordError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17be, () => new InvalidStakeRecordError());
+createErrorFromNameLookup.set('InvalidStakeRecord', () => new InvalidStakeRecordError());
+
+/**
+ * NftUsageLimitReached: 'NFT has reached the maximum number of uses'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NftUsageLimitReachedError extends Error {
+  readonly code: number = 0x17bf;
+  readonly name: string = 'NftUsageLimitReached';
+  constructor() {
+    super('NFT has reached the maximum number of uses');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NftUsageLimitReachedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bf, () => new NftUsageLimitReachedError());
+createErrorFromNameLookup.set('NftUsageLimitReached', () => new NftUsageLimitReachedError());
+
+/**
+ * InvalidCollectionListSize: 'Collection list must contain between 1 and 5 collections'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidCollectionListSizeError extends Error {
+  readonly code: number = 0x17c0;
+  readonly name: string = 'InvalidCollectionListSize';
+  constructor() {
+    super('Collection list must contain between 1 and 5 collections');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidCollectionListSizeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c0, () => new InvalidCollectionListSizeError());
+createErrorFromNameLookup.set(
+  'InvalidCollectionListSize',
+  () => new InvalidCollectionListSizeError(),
+);
+
+/**
+ * InvalidNftCreator: 'NFT first creator is not verified or does not match'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidNftCreatorError extends Error {
+  readonly code: number = 0x17c1;
+  readonly name: string = 'InvalidNftCreator';
+  constructor() {
+    super('NFT first creator is not verified or does not match');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidNftCreatorError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c1, () => new InvalidNftCreatorError());
+createErrorFromNameLookup.set('InvalidNftCreator', () => new InvalidNftCreatorError());
+
+/**
+ * InvalidProgrammableMetadata: 'Invalid programmable NFT account data'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidProgrammableMetadataError extends Error {
+  readonly code: number = 0x17c2;
+  readonly name: string = 'InvalidProgrammableMetadata';
+  constructor() {
+    super('Invalid programmable NFT account data');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidProgrammableMetadataError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c2, () => new InvalidProgrammableMetadataError());
+createErrorFromNameLookup.set(
+  'InvalidProgrammableMetadata',
+  () => new InvalidProgrammableMetadataError(),
+);
+
+/**
+ * InvalidNftAmount: 'Invalid number of NFTs (must be at least 1)'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidNftAmountError extends Error {
+  readonly code: number = 0x17c3;
+  readonly name: string = 'InvalidNftAmount';
+  constructor() {
+    super('Invalid number of NFTs (must be at least 1)');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidNftAmountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c3, () => new InvalidNftAmountError());
+createErrorFromNameLookup.set('InvalidNftAmount', () => new InvalidNftAmountError());
+
+/**
+ * DuplicatedNftMint: 'Duplicated NFT mint'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DuplicatedNftMintError extends Error {
+  readonly code: number = 0x17c4;
+  readonly name: string = 'DuplicatedNftMint';
+  constructor() {
+    super('Duplicated NFT mint');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DuplicatedNftMintError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c4, () => new DuplicatedNftMintError());
+createErrorFromNameLookup.set('DuplicatedNftMint', () => new DuplicatedNftMintError());
+
+/**
+ * InvalidTokenStandard: 'Token standard not supported by the guard'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTokenStandardError extends Error {
+  readonly code: number = 0x17c5;
+  readonly name: string = 'InvalidTokenStandard';
+  constructor() {
+    super('Token standard not supported by the guard');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTokenStandardError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c5, () => new InvalidTokenStandardError());
+createErrorFromNameLookup.set('InvalidTokenStandard', () => new InvalidTokenStandardError());
+
+/**
+ * InvalidEdition: 'Token is not a print edition of the master edition'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidEditionError extends Error {
+  readonly code: number = 0x17c6;
+  readonly name: string = 'InvalidEdition';
+  constructor() {
+    super('Token is not a print edition of the master edition');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidEditionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c6, () => new InvalidEditionError());
+createErrorFromNameLookup.set('InvalidEdition', () => new InvalidEditionError());
+
+/**
+ * InvalidTokenPaymentListSize: 'Invalid number of token payments'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTokenPaymentListSizeError extends Error {
+  readonly code: number = 0x17c7;
+  readonly name: string = 'InvalidTokenPaymentListSize';
+  constructor() {
+    super('Invalid number of token payments');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTokenPaymentListSizeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c7, () => new InvalidTokenPaymentListSizeError());
+createErrorFromNameLookup.set(
+  'InvalidTokenPaymentListSize',
+  () => new InvalidTokenPaymentListSizeError(),
+);
+
+/**
+ * DuplicatedPaymentMint: 'Duplicated mint in the token payment list'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DuplicatedPaymentMintError extends Error {
+  readonly code: number = 0x17c8;
+  readonly name: string = 'DuplicatedPaymentMint';
+  constructor() {
+    super('Duplicated mint in the token payment list');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DuplicatedPaymentMintError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c8, () => new DuplicatedPaymentMintError());
+createErrorFromNameLookup.set('DuplicatedPaymentMint', () => new DuplicatedPaymentMintError());
+
+/**
+ * StaleAuthorityProposal: 'Authority proposal was not created by the current authority'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class StaleAuthorityProposalError extends Error {
+  readonly code: number = 0x17c9;
+  readonly name: string = 'StaleAuthorityProposal';
+  constructor() {
+    super('Authority proposal was not created by the current authority');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, StaleAuthorityProposalError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c9, () => new StaleAuthorityProposalError());
+createErrorFromNameLookup.set('StaleAuthorityProposal', () => new StaleAuthorityProposalError());
+
+/**
+ * MissingBotTaxDestination: 'Missing bot tax destination account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingBotTaxDestinationError extends Error {
+  readonly code: number = 0x17ca;
+  readonly name: string = 'MissingBotTaxDestination';
+  constructor() {
+    super('Missing bot tax destination account');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingBotTaxDestinationError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ca, () => new MissingBotTaxDestinationError());
+createErrorFromNameLookup.set(
+  'MissingBotTaxDestination',
+  () => new MissingBotTaxDestinationError(),
+);
+
+/**
+ * MissingOffenseCounter: 'Missing bot tax offense counter account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingOffenseCounterError extends Error {
+  readonly code: number = 0x17cb;
+  readonly name: string = 'MissingOffenseCounter';
+  constructor() {
+    super('Missing bot tax offense counter account');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingOffenseCounterError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17cb, () => new MissingOffenseCounterError());
+createErrorFromNameLookup.set('MissingOffenseCounter', () => new MissingOffenseCounterError());
+
+/**
+ * InvalidTokenAccountCount: 'Invalid number of token accounts (must be at least 1)'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTokenAccountCountError extends Error {
+  readonly code: number = 0x17cc;
+  readonly name: string = 'InvalidTokenAccountCount';
+  constructor() {
+    super('Invalid number of token accounts (must be at least 1)');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTokenAccountCountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17cc, () => new InvalidTokenAccountCountError());
+createErrorFromNameLookup.set(
+  'InvalidTokenAccountCount',
+  () => new InvalidTokenAccountCountError(),
+);
+
+/**
+ * InvalidNftUsageLimit: 'Invalid NFT usage limit (must be at least 1)'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidNftUsageLimitError extends Error {
+  readonly code: number = 0x17cd;
+  readonly name: string = 'InvalidNftUsageLimit';
+  constructor() {
+    super('Invalid NFT usage limit (must be at least 1)');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidNftUsageLimitError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17cd, () => new InvalidNftUsageLimitError());
+createErrorFromNameLookup.set('InvalidNftUsageLimit', () => new InvalidNftUsageLimitError());
+
+/**
+ * InvalidNft: 'Token is not an NFT (mint supply of 1 with a master edition)'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidNftError extends Error {
+  readonly code: number = 0x17ce;
+  readonly name: string = 'InvalidNft';
+  constructor() {
+    super('Token is not an NFT (mint supply of 1 with a master edition)');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidNftError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ce, () => new InvalidNftError());
+createErrorFromNameLookup.set('InvalidNft', () => new InvalidNftError());
+
+/**
+ * InvalidNftUpdateAuthority: 'NFT update authority does not match'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidNftUpdateAuthorityError extends Error {
+  readonly code: number = 0x17cf;
+  readonly name: string = 'InvalidNftUpdateAuthority';
+  constructor() {
+    super('NFT update authority does not match');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidNftUpdateAuthorityError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17cf, () => new InvalidNftUpdateAuthorityError());
+createErrorFromNameLookup.set(
+  'InvalidNftUpdateAuthority',
+  () => new InvalidNftUpdateAuthorityError(),
+);
+
+/**
+ * TransferFeeNotSupported: 'Tokens with a transfer fee cannot be used as payment'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransferFeeNotSupportedError extends Error {
+  readonly code: number = 0x17d0;
+  readonly name: string = 'TransferFeeNotSupported';
+  constructor() {
+    super('Tokens with a transfer fee cannot be used as payment');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransferFeeNotSupportedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17d0, () => new TransferFeeNotSupportedError());
+createErrorFromNameLookup.set('TransferFeeNotSupported', () => new TransferFeeNotSupportedError());
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
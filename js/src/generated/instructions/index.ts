@@ -1,8 +1,22 @@
+export * from './acceptAuthority';
+export * from './applyScheduledUpdate';
+export * from './cancelAuthorityProposal';
+export * from './cancelScheduledUpdate';
+export * from './closeBuffer';
+export * from './commitBuffer';
 export * from './initialize';
+export * from './lockConfig';
 export * from './mint';
+export * from './pause';
+export * from './proposeAuthority';
+export * from './resume';
+export * from './revokeDelegate';
 export * from './route';
+export * from './scheduleUpdate';
 export * from './setAuthority';
+export * from './setDelegate';
 export * from './unwrap';
 export * from './update';
 export * from './withdraw';
 export * from './wrap';
+export * from './writeBuffer';
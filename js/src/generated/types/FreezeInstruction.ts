@@ -14,6 +14,7 @@ export enum FreezeInstruction {
   Initialize,
   Thaw,
   UnlockFunds,
+  EnableThaw,
 }
 
 /**
@@ -25,6 +25,21 @@ import { TokenBurn, tokenBurnBeet } from './TokenBurn';
 import { FreezeSolPayment, freezeSolPaymentBeet } from './FreezeSolPayment';
 import { FreezeTokenPayment, freezeTokenPaymentBeet } from './FreezeTokenPayment';
 import { ProgramGate, programGateBeet } from './ProgramGate';
+import { PendingUpdate, pendingUpdateBeet } from './PendingUpdate';
+import { ConfigLock, configLockBeet } from './ConfigLock';
+import { Pause, pauseBeet } from './Pause';
+import { MintReceipt, mintReceiptBeet } from './MintReceipt';
+import { BotTaxPolicy, botTaxPolicyBeet } from './BotTaxPolicy';
+import { InstructionGate, instructionGateBeet } from './InstructionGate';
+import { SignedVoucher, signedVoucherBeet } from './SignedVoucher';
+import { ThirdPartyMultisig, thirdPartyMultisigBeet } from './ThirdPartyMultisig';
+import { StakeGate, stakeGateBeet } from './StakeGate';
+import { NftUsageLimit, nftUsageLimitBeet } from './NftUsageLimit';
+import { NftMatchGate, nftMatchGateBeet } from './NftMatchGate';
+import { NftMatchBurn, nftMatchBurnBeet } from './NftMatchBurn';
+import { NftMatchPayment, nftMatchPaymentBeet } from './NftMatchPayment';
+import { EditionBurn, editionBurnBeet } from './EditionBurn';
+import { MultiTokenPayment, multiTokenPaymentBeet } from './MultiTokenPayment';
 export type GuardSet = {
   botTax: beet.COption<BotTax>;
   solPayment: beet.COption<SolPayment>;
@@ -45,6 +60,21 @@ export type GuardSet = {
   freezeSolPayment: beet.COption<FreezeSolPayment>;
   freezeTokenPayment: beet.COption<FreezeTokenPayment>;
   programGate: beet.COption<ProgramGate>;
+  pendingUpdate: beet.COption<PendingUpdate>;
+  configLock: beet.COption<ConfigLock>;
+  pause: beet.COption<Pause>;
+  mintReceipt: beet.COption<MintReceipt>;
+  botTaxPolicy: beet.COption<BotTaxPolicy>;
+  instructionGate: beet.COption<InstructionGate>;
+  signedVoucher: beet.COption<SignedVoucher>;
+  thirdPartyMultisig: beet.COption<ThirdPartyMultisig>;
+  stakeGate: beet.COption<StakeGate>;
+  nftUsageLimit: beet.COption<NftUsageLimit>;
+  nftMatchGate: beet.COption<NftMatchGate>;
+  nftMatchBurn: beet.COption<NftMatchBurn>;
+  nftMatchPayment: beet.COption<NftMatchPayment>;
+  editionBurn: beet.COption<EditionBurn>;
+  multiTokenPayment: beet.COption<MultiTokenPayment>;
 };
 
 /**
@@ -72,6 +102,21 @@ export const guardSetBeet = new beet.FixableBeetArgsStruct<GuardSet>(
     ['freezeSolPayment', beet.coption(freezeSolPaymentBeet)],
     ['freezeTokenPayment', beet.coption(freezeTokenPaymentBeet)],
     ['programGate', beet.coption(programGateBeet)],
+    ['pendingUpdate', beet.coption(pendingUpdateBeet)],
+    ['configLock', beet.coption(configLockBeet)],
+    ['pause', beet.coption(pauseBeet)],
+    ['mintReceipt', beet.coption(mintReceiptBeet)],
+    ['botTaxPolicy', beet.coption(botTaxPolicyBeet)],
+    ['instructionGate', beet.coption(instructionGateBeet)],
+    ['signedVoucher', beet.coption(signedVoucherBeet)],
+    ['thirdPartyMultisig', beet.coption(thirdPartyMultisigBeet)],
+    ['stakeGate', beet.coption(stakeGateBeet)],
+    ['nftUsageLimit', beet.coption(nftUsageLimitBeet)],
+    ['nftMatchGate', beet.coption(nftMatchGateBeet)],
+    ['nftMatchBurn', beet.coption(nftMatchBurnBeet)],
+    ['nftMatchPayment', beet.coption(nftMatchPaymentBeet)],
+    ['editionBurn', beet.coption(editionBurnBeet)],
+    ['multiTokenPayment', beet.coption(multiTokenPaymentBeet)],
   ],
   'GuardSet',
 );
@@ -30,6 +30,21 @@ export enum GuardType {
   FreezeSolPayment,
   FreezeTokenPayment,
   ProgramGate,
+  PendingUpdate,
+  ConfigLock,
+  Pause,
+  MintReceipt,
+  BotTaxPolicy,
+  InstructionGate,
+  SignedVoucher,
+  ThirdPartyMultisig,
+  StakeGate,
+  NftUsageLimit,
+  NftMatchGate,
+  NftMatchBurn,
+  NftMatchPayment,
+  EditionBurn,
+  MultiTokenPayment,
 }
 
 /**
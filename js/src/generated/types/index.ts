@@ -1,9 +1,16 @@
 export * from './AddressGate';
 export * from './AllowList';
 export * from './AllowListProof';
+export * from './AuthorityChangeKind';
 export * from './BotTax';
+export * from './BotTaxEscalation';
+export * from './BotTaxPolicy';
 export * from './CandyGuardData';
+export * from './ConfigLock';
+export * from './DelegateRole';
+export * from './EditionBurn';
 export * from './EndDate';
+export * from './ForbiddenInstruction';
 export * from './FreezeInstruction';
 export * from './FreezeSolPayment';
 export * from './FreezeTokenPayment';
@@ -11,17 +18,36 @@ export * from './Gatekeeper';
 export * from './Group';
 export * from './GuardSet';
 export * from './GuardType';
+export * from './InstructionGate';
 export * from './MintCounter';
 export * from './MintLimit';
+export * from './MintReceipt';
+export * from './MultiTokenPayment';
 export * from './NftBurn';
 export * from './NftGate';
+export * from './NftMatchBurn';
+export * from './NftMatchGate';
+export * from './NftMatchPayment';
+export * from './NftMatcher';
 export * from './NftPayment';
+export * from './NftUsageCounter';
+export * from './NftUsageLimit';
+export * from './OffenseCounter';
+export * from './Pause';
+export * from './Payment';
+export * from './PendingUpdate';
 export * from './ProgramGate';
 export * from './RedeemedAmount';
 export * from './RouteArgs';
+export * from './SignedVoucher';
 export * from './SolPayment';
+export * from './StakeGate';
+export * from './StakedAsset';
 export * from './StartDate';
+export * from './ThirdPartyMultisig';
 export * from './ThirdPartySigner';
+export * from './TokenAmount';
 export * from './TokenBurn';
 export * from './TokenGate';
 export * from './TokenPayment';
+export * from './UpdateKind';
@@ -25,6 +25,21 @@ import { addressGateBeet } from './generated/types/AddressGate';
 import { nftGateBeet } from './generated/types/NftGate';
 import { nftBurnBeet } from './generated/types/NftBurn';
 import { tokenBurnBeet } from './generated/types/TokenBurn';
+import { pendingUpdateBeet } from './generated/types/PendingUpdate';
+import { configLockBeet } from './generated/types/ConfigLock';
+import { pauseBeet } from './generated/types/Pause';
+import { mintReceiptBeet } from './generated/types/MintReceipt';
+import { botTaxPolicyBeet } from './generated/types/BotTaxPolicy';
+import { instructionGateBeet } from './generated/types/InstructionGate';
+import { signedVoucherBeet } from './generated/types/SignedVoucher';
+import { thirdPartyMultisigBeet } from './generated/types/ThirdPartyMultisig';
+import { stakeGateBeet } from './generated/types/StakeGate';
+import { nftUsageLimitBeet } from './generated/types/NftUsageLimit';
+import { nftMatchGateBeet } from './generated/types/NftMatchGate';
+import { nftMatchBurnBeet } from './generated/types/NftMatchBurn';
+import { nftMatchPaymentBeet } from './generated/types/NftMatchPayment';
+import { editionBurnBeet } from './generated/types/EditionBurn';
+import { multiTokenPaymentBeet } from './generated/types/MultiTokenPayment';
 import { u32, u64 } from '@metaplex-foundation/beet';
 
 type Guards = {
@@ -47,6 +62,21 @@ type Guards = {
   /* 17 */ freezeSolPaymentEnabled: boolean;
   /* 18 */ freezeTokenPaymentEnabled: boolean;
   /* 19 */ programGateEnabled: boolean;
+  /* 20 */ pendingUpdateEnabled: boolean;
+  /* 21 */ configLockEnabled: boolean;
+  /* 22 */ pauseEnabled: boolean;
+  /* 23 */ mintReceiptEnabled: boolean;
+  /* 24 */ botTaxPolicyEnabled: boolean;
+  /* 25 */ instructionGateEnabled: boolean;
+  /* 26 */ signedVoucherEnabled: boolean;
+  /* 27 */ thirdPartyMultisigEnabled: boolean;
+  /* 28 */ stakeGateEnabled: boolean;
+  /* 29 */ nftUsageLimitEnabled: boolean;
+  /* 30 */ nftMatchGateEnabled: boolean;
+  /* 31 */ nftMatchBurnEnabled: boolean;
+  /* 32 */ nftMatchPaymentEnabled: boolean;
+  /* 33 */ editionBurnEnabled: boolean;
+  /* 34 */ multiTokenPaymentEnabled: boolean;
 };
 
 const GUARDS_SIZE = {
@@ -69,6 +99,21 @@ const GUARDS_SIZE = {
   /* 17 */ freezeSolPayment: 40,
   /* 18 */ freezeTokenPayment: 72,
   /* 19 */ programGate: 164,
+  /* 20 */ pendingUpdate: 8,
+  /* 21 */ configLock: 8,
+  /* 22 */ pause: 12,
+  /* 23 */ mintReceipt: 0,
+  /* 24 */ botTaxPolicy: 86,
+  /* 25 */ instructionGate: 227,
+  /* 26 */ signedVoucher: 32,
+  /* 27 */ thirdPartyMultisig: 165,
+  /* 28 */ stakeGate: 123,
+  /* 29 */ nftUsageLimit: 34,
+  /* 30 */ nftMatchGate: 165,
+  /* 31 */ nftMatchBurn: 166,
+  /* 32 */ nftMatchPayment: 198,
+  /* 33 */ editionBurn: 40,
+  /* 34 */ multiTokenPayment: 364,
 };
 
 const GUARDS_NAME = [
@@ -91,11 +136,32 @@ const GUARDS_NAME = [
   /* 17 */ 'freezeSolPayment',
   /* 18 */ 'freezeTokenPayment',
   /* 19 */ 'programGate',
+  /* 20 */ 'pendingUpdate',
+  /* 21 */ 'configLock',
+  /* 22 */ 'pause',
+  /* 23 */ 'mintReceipt',
+  /* 24 */ 'botTaxPolicy',
+  /* 25 */ 'instructionGate',
+  /* 26 */ 'signedVoucher',
+  /* 27 */ 'thirdPartyMultisig',
+  /* 28 */ 'stakeGate',
+  /* 29 */ 'nftUsageLimit',
+  /* 30 */ 'nftMatchGate',
+  /* 31 */ 'nftMatchBurn',
+  /* 32 */ 'nftMatchPayment',
+  /* 33 */ 'editionBurn',
+  /* 34 */ 'multiTokenPayment',
 ];
 
 const GUARDS_COUNT = GUARDS_NAME.length;
 const MAX_LABEL_LENGTH = 6;
 const MAX_PROGRAM_COUNT = 5;
+const MAX_EXEMPTION_COUNT = 8;
+const MAX_FORBIDDEN_COUNT = 5;
+const MAX_SIGNER_COUNT = 5;
+const MAX_DISCRIMINATOR_SIZE = 8;
+const MAX_COLLECTION_COUNT = 5;
+const MAX_PAYMENT_COUNT = 5;
 
 /**
  * Returns the guards that are enabled.
@@ -132,6 +198,21 @@ function guardsFromData(buffer: Buffer): Guards {
     freezeSolPaymentEnabled,
     freezeTokenPaymentEnabled,
     programGateEnabled,
+    pendingUpdateEnabled,
+    configLockEnabled,
+    pauseEnabled,
+    mintReceiptEnabled,
+    botTaxPolicyEnabled,
+    instructionGateEnabled,
+    signedVoucherEnabled,
+    thirdPartyMultisigEnabled,
+    stakeGateEnabled,
+    nftUsageLimitEnabled,
+    nftMatchGateEnabled,
+    nftMatchBurnEnabled,
+    nftMatchPaymentEnabled,
+    editionBurnEnabled,
+    multiTokenPaymentEnabled,
   ] = guards;
 
   return {
@@ -154,6 +235,21 @@ function guardsFromData(buffer: Buffer): Guards {
     freezeSolPaymentEnabled,
     freezeTokenPaymentEnabled,
     programGateEnabled,
+    pendingUpdateEnabled,
+    configLockEnabled,
+    pauseEnabled,
+    mintReceiptEnabled,
+    botTaxPolicyEnabled,
+    instructionGateEnabled,
+    signedVoucherEnabled,
+    thirdPartyMultisigEnabled,
+    stakeGateEnabled,
+    nftUsageLimitEnabled,
+    nftMatchGateEnabled,
+    nftMatchBurnEnabled,
+    nftMatchPaymentEnabled,
+    editionBurnEnabled,
+    multiTokenPaymentEnabled,
   };
 }
 
@@ -298,6 +394,21 @@ function deserializeGuardSet(buffer: Buffer): { guardSet: GuardSet; offset: numb
     freezeSolPaymentEnabled,
     freezeTokenPaymentEnabled,
     programGateEnabled,
+    pendingUpdateEnabled,
+    configLockEnabled,
+    pauseEnabled,
+    mintReceiptEnabled,
+    botTaxPolicyEnabled,
+    instructionGateEnabled,
+    signedVoucherEnabled,
+    thirdPartyMultisigEnabled,
+    stakeGateEnabled,
+    nftUsageLimitEnabled,
+    nftMatchGateEnabled,
+    nftMatchBurnEnabled,
+    nftMatchPaymentEnabled,
+    editionBurnEnabled,
+    multiTokenPaymentEnabled,
   } = guards;
   logDebug('Guards: %O', guards);
 
@@ -419,6 +530,96 @@ function deserializeGuardSet(buffer: Buffer): { guardSet: GuardSet; offset: numb
     cursor += GUARDS_SIZE.programGate;
   }
 
+  if (pendingUpdateEnabled) {
+    const [pendingUpdate] = pendingUpdateBeet.deserialize(buffer, cursor);
+    data.pendingUpdate = pendingUpdate;
+    cursor += GUARDS_SIZE.pendingUpdate;
+  }
+
+  if (configLockEnabled) {
+    const [configLock] = configLockBeet.deserialize(buffer, cursor);
+    data.configLock = configLock;
+    cursor += GUARDS_SIZE.configLock;
+  }
+
+  if (pauseEnabled) {
+    const [pause] = pauseBeet.deserialize(buffer, cursor);
+    data.pause = pause;
+    cursor += GUARDS_SIZE.pause;
+  }
+
+  if (mintReceiptEnabled) {
+    const [mintReceipt] = mintReceiptBeet.deserialize(buffer, cursor);
+    data.mintReceipt = mintReceipt;
+    cursor += GUARDS_SIZE.mintReceipt;
+  }
+
+  if (botTaxPolicyEnabled) {
+    const [botTaxPolicy] = botTaxPolicyBeet.deserialize(buffer, cursor);
+    data.botTaxPolicy = botTaxPolicy;
+    cursor += GUARDS_SIZE.botTaxPolicy;
+  }
+
+  if (instructionGateEnabled) {
+    const [instructionGate] = instructionGateBeet.deserialize(buffer, cursor);
+    data.instructionGate = instructionGate;
+    cursor += GUARDS_SIZE.instructionGate;
+  }
+
+  if (signedVoucherEnabled) {
+    const [signedVoucher] = signedVoucherBeet.deserialize(buffer, cursor);
+    data.signedVoucher = signedVoucher;
+    cursor += GUARDS_SIZE.signedVoucher;
+  }
+
+  if (thirdPartyMultisigEnabled) {
+    const [thirdPartyMultisig] = thirdPartyMultisigBeet.deserialize(buffer, cursor);
+    data.thirdPartyMultisig = thirdPartyMultisig;
+    cursor += GUARDS_SIZE.thirdPartyMultisig;
+  }
+
+  if (stakeGateEnabled) {
+    const [stakeGate] = stakeGateBeet.deserialize(buffer, cursor);
+    data.stakeGate = stakeGate;
+    cursor += GUARDS_SIZE.stakeGate;
+  }
+
+  if (nftUsageLimitEnabled) {
+    const [nftUsageLimit] = nftUsageLimitBeet.deserialize(buffer, cursor);
+    data.nftUsageLimit = nftUsageLimit;
+    cursor += GUARDS_SIZE.nftUsageLimit;
+  }
+
+  if (nftMatchGateEnabled) {
+    const [nftMatchGate] = nftMatchGateBeet.deserialize(buffer, cursor);
+    data.nftMatchGate = nftMatchGate;
+    cursor += GUARDS_SIZE.nftMatchGate;
+  }
+
+  if (nftMatchBurnEnabled) {
+    const [nftMatchBurn] = nftMatchBurnBeet.deserialize(buffer, cursor);
+    data.nftMatchBurn = nftMatchBurn;
+    cursor += GUARDS_SIZE.nftMatchBurn;
+  }
+
+  if (nftMatchPaymentEnabled) {
+    const [nftMatchPayment] = nftMatchPaymentBeet.deserialize(buffer, cursor);
+    data.nftMatchPayment = nftMatchPayment;
+    cursor += GUARDS_SIZE.nftMatchPayment;
+  }
+
+  if (editionBurnEnabled) {
+    const [editionBurn] = editionBurnBeet.deserialize(buffer, cursor);
+    data.editionBurn = editionBurn;
+    cursor += GUARDS_SIZE.editionBurn;
+  }
+
+  if (multiTokenPaymentEnabled) {
+    const [multiTokenPayment] = multiTokenPaymentBeet.deserialize(buffer, cursor);
+    data.multiTokenPayment = multiTokenPayment;
+    cursor += GUARDS_SIZE.multiTokenPayment;
+  }
+
   return {
     guardSet: {
       botTax: data.botTax ?? null,
@@ -440,6 +641,21 @@ function deserializeGuardSet(buffer: Buffer): { guardSet: GuardSet; offset: numb
       freezeSolPayment: data.freezeSolPayment ?? null,
       freezeTokenPayment: data.freezeTokenPayment ?? null,
       programGate: data.programGate ?? null,
+      pendingUpdate: data.pendingUpdate ?? null,
+      configLock: data.configLock ?? null,
+      pause: data.pause ?? null,
+      mintReceipt: data.mintReceipt ?? null,
+      botTaxPolicy: data.botTaxPolicy ?? null,
+      instructionGate: data.instructionGate ?? null,
+      signedVoucher: data.signedVoucher ?? null,
+      thirdPartyMultisig: data.thirdPartyMultisig ?? null,
+      stakeGate: data.stakeGate ?? null,
+      nftUsageLimit: data.nftUsageLimit ?? null,
+      nftMatchGate: data.nftMatchGate ?? null,
+      nftMatchBurn: data.nftMatchBurn ?? null,
+      nftMatchPayment: data.nftMatchPayment ?? null,
+      editionBurn: data.editionBurn ?? null,
+      multiTokenPayment: data.multiTokenPayment ?? null,
     },
     offset: cursor,
   };
@@ -605,6 +821,174 @@ function serializeGuardSet(buffer: Buffer, offset: number, guardSet: GuardSet):
   }
   index++;
 
+  if (guardSet.pendingUpdate) {
+    pendingUpdateBeet.write(buffer, offset, guardSet.pendingUpdate);
+    offset += GUARDS_SIZE.pendingUpdate;
+    features |= 1 << index;
+  }
+  index++;
+
+  if (guardSet.configLock) {
+    configLockBeet.write(buffer, offset, guardSet.configLock);
+    offset += GUARDS_SIZE.configLock;
+    features |= 1 << index;
+  }
+  index++;
+
+  if (guardSet.pause) {
+    pauseBeet.write(buffer, offset, guardSet.pause);
+    offset += GUARDS_SIZE.pause;
+    features |= 1 << index;
+  }
+  index++;
+
+  if (guardSet.mintReceipt) {
+    mintReceiptBeet.write(buffer, offset, guardSet.mintReceipt);
+    offset += GUARDS_SIZE.mintReceipt;
+    features |= 1 << index;
+  }
+  index++;
+
+  if (guardSet.botTaxPolicy) {
+    if (guardSet.botTaxPolicy.exemptions.length > MAX_EXEMPTION_COUNT) {
+      throw `Exceeded maximum number of error codes on exemption list:\
+        ${guardSet.botTaxPolicy.exemptions.length} > ${MAX_EXEMPTION_COUNT}`;
+    }
+
+    const [data] = botTaxPolicyBeet.serialize(guardSet.botTaxPolicy, GUARDS_SIZE.botTaxPolicy);
+    data.copy(buffer, offset);
+    offset += GUARDS_SIZE.botTaxPolicy;
+    features |= 1 << index;
+  }
+  index++;
+
+  if (guardSet.instructionGate) {
+    if (guardSet.instructionGate.forbidden.length > MAX_FORBIDDEN_COUNT) {
+      throw `Exceeded maximum number of instructions on forbidden list:\
+        ${guardSet.instructionGate.forbidden.length} > ${MAX_FORBIDDEN_COUNT}`;
+    }
+
+    const [data] = instructionGateBeet.serialize(
+      guardSet.instructionGate,
+      GUARDS_SIZE.instructionGate,
+    );
+    data.copy(buffer, offset);
+    offset += GUARDS_SIZE.instructionGate;
+    features |= 1 << index;
+  }
+  index++;
+
+  if (guardSet.signedVoucher) {
+    signedVoucherBeet.write(buffer, offset, guardSet.signedVoucher);
+    offset += GUARDS_SIZE.signedVoucher;
+    features |= 1 << index;
+  }
+  index++;
+
+  if (guardSet.thirdPartyMultisig) {
+    if (guardSet.thirdPartyMultisig.signerKeys.length > MAX_SIGNER_COUNT) {
+      throw `Exceeded maximum number of signer keys:\
+        ${guardSet.thirdPartyMultisig.signerKeys.length} > ${MAX_SIGNER_COUNT}`;
+    }
+
+    const [data] = thirdPartyMultisigBeet.serialize(
+      guardSet.thirdPartyMultisig,
+      GUARDS_SIZE.thirdPartyMultisig,
+    );
+    data.copy(buffer, offset);
+    offset += GUARDS_SIZE.thirdPartyMultisig;
+    features |= 1 << index;
+  }
+  index++;
+
+  if (guardSet.stakeGate) {
+    if (guardSet.stakeGate.discriminator.length > MAX_DISCRIMINATOR_SIZE) {
+      throw `Exceeded maximum discriminator size:\
+        ${guardSet.stakeGate.discriminator.length} > ${MAX_DISCRIMINATOR_SIZE}`;
+    }
+
+    const [data] = stakeGateBeet.serialize(guardSet.stakeGate, GUARDS_SIZE.stakeGate);
+    data.copy(buffer, offset);
+    offset += GUARDS_SIZE.stakeGate;
+    features |= 1 << index;
+  }
+  index++;
+
+  if (guardSet.nftUsageLimit) {
+    nftUsageLimitBeet.write(buffer, offset, guardSet.nftUsageLimit);
+    offset += GUARDS_SIZE.nftUsageLimit;
+    features |= 1 << index;
+  }
+  index++;
+
+  if (guardSet.nftMatchGate) {
+    const { required } = guardSet.nftMatchGate;
+    if (required.__kind === 'Collections' && required.fields[0].length > MAX_COLLECTION_COUNT) {
+      throw `Exceeded maximum number of collections:\
+        ${required.fields[0].length} > ${MAX_COLLECTION_COUNT}`;
+    }
+
+    const [data] = nftMatchGateBeet.serialize(guardSet.nftMatchGate, GUARDS_SIZE.nftMatchGate);
+    data.copy(buffer, offset);
+    offset += GUARDS_SIZE.nftMatchGate;
+    features |= 1 << index;
+  }
+  index++;
+
+  if (guardSet.nftMatchBurn) {
+    const { required } = guardSet.nftMatchBurn;
+    if (required.__kind === 'Collections' && required.fields[0].length > MAX_COLLECTION_COUNT) {
+      throw `Exceeded maximum number of collections:\
+        ${required.fields[0].length} > ${MAX_COLLECTION_COUNT}`;
+    }
+
+    const [data] = nftMatchBurnBeet.serialize(guardSet.nftMatchBurn, GUARDS_SIZE.nftMatchBurn);
+    data.copy(buffer, offset);
+    offset += GUARDS_SIZE.nftMatchBurn;
+    features |= 1 << index;
+  }
+  index++;
+
+  if (guardSet.nftMatchPayment) {
+    const { required } = guardSet.nftMatchPayment;
+    if (required.__kind === 'Collections' && required.fields[0].length > MAX_COLLECTION_COUNT) {
+      throw `Exceeded maximum number of collections:\
+        ${required.fields[0].length} > ${MAX_COLLECTION_COUNT}`;
+    }
+
+    const [data] = nftMatchPaymentBeet.serialize(
+      guardSet.nftMatchPayment,
+      GUARDS_SIZE.nftMatchPayment,
+    );
+    data.copy(buffer, offset);
+    offset += GUARDS_SIZE.nftMatchPayment;
+    features |= 1 << index;
+  }
+  index++;
+
+  if (guardSet.editionBurn) {
+    editionBurnBeet.write(buffer, offset, guardSet.editionBurn);
+    offset += GUARDS_SIZE.editionBurn;
+    features |= 1 << index;
+  }
+  index++;
+
+  if (guardSet.multiTokenPayment) {
+    const { payments } = guardSet.multiTokenPayment;
+    if (payments.length > MAX_PAYMENT_COUNT) {
+      throw `Exceeded maximum number of payments: ${payments.length} > ${MAX_PAYMENT_COUNT}`;
+    }
+
+    const [data] = multiTokenPaymentBeet.serialize(
+      guardSet.multiTokenPayment,
+      GUARDS_SIZE.multiTokenPayment,
+    );
+    data.copy(buffer, offset);
+    offset += GUARDS_SIZE.multiTokenPayment;
+    features |= 1 << index;
+  }
+  index++;
+
   u64.write(buffer, start, features);
 
   return offset;
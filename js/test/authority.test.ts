@@ -0,0 +1,212 @@
+import test from 'tape';
+import spok from 'spok';
+import { newCandyGuardData, InitTransactions, killStuckProcess } from './setup';
+import { AuthorityProposal, CandyGuard } from '../src/generated';
+import { spokSamePubkey } from './utils';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('authority transfer', async (t) => {
+  const {
+    fstTxHandler: authorityHandler,
+    authorityPair: authority,
+    connection,
+  } = await API.authority();
+
+  const data = newCandyGuardData();
+
+  const { tx: txInit, candyGuard: address } = await API.initialize(
+    t,
+    data,
+    authority,
+    authorityHandler,
+  );
+  // executes the transaction
+  await txInit.assertSuccess(t);
+
+  // retrieves the created candy machine
+  let candyGuard = await CandyGuard.fromAccountAddress(connection, address);
+  spok(t, candyGuard, {
+    authority: spokSamePubkey(authority.publicKey),
+  });
+
+  const { fstTxHandler: minterHandler, minterPair: minter } = await API.minter();
+  const { tx: txPropose, proposal } = await API.proposeAuthority(
+    t,
+    address,
+    authority,
+    minter.publicKey,
+    authorityHandler,
+  );
+  await txPropose.assertSuccess(t);
+
+  const authorityProposal = await AuthorityProposal.fromAccountAddress(connection, proposal);
+  spok(t, authorityProposal, {
+    candyGuard: spokSamePubkey(address),
+    newAuthority: spokSamePubkey(minter.publicKey),
+    proposedBy: spokSamePubkey(authority.publicKey),
+  });
+
+  // the authority does not change until the proposal is accepted
+  candyGuard = await CandyGuard.fromAccountAddress(connection, address);
+  spok(t, candyGuard, {
+    authority: spokSamePubkey(authority.publicKey),
+  });
+
+  const { tx: txAccept } = await API.acceptAuthority(
+    t,
+    address,
+    authority.publicKey,
+    minter,
+    minterHandler,
+  );
+  await txAccept.assertSuccess(t);
+
+  // refresh the candy guard information
+  candyGuard = await CandyGuard.fromAccountAddress(connection, address);
+  spok(t, candyGuard, {
+    authority: spokSamePubkey(minter.publicKey),
+  });
+
+  // the proposal is closed
+  const proposalInfo = await connection.getAccountInfo(proposal);
+  t.true(proposalInfo === null, 'proposal account must be closed');
+
+  // the previous authority cannot propose a new transfer
+  const { tx: txPropose2 } = await API.proposeAuthority(
+    t,
+    address,
+    authority,
+    authority.publicKey,
+    authorityHandler,
+  );
+  await txPropose2.assertError(t, /has_one constraint was violated/i);
+});
+
+test('authority transfer: only the proposed authority can accept', async (t) => {
+  const { fstTxHandler: authorityHandler, authorityPair: authority } = await API.authority();
+
+  const { tx: txInit, candyGuard: address } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    authority,
+    authorityHandler,
+  );
+  await txInit.assertSuccess(t);
+
+  const { minterPair: minter } = await API.minter();
+  const { tx: txPropose } = await API.proposeAuthority(
+    t,
+    address,
+    authority,
+    minter.publicKey,
+    authorityHandler,
+  );
+  await txPropose.assertSuccess(t);
+
+  // a different signer
+  const { fstTxHandler: otherHandler, minterPair: other } = await API.minter();
+  const { tx: txAccept } = await API.acceptAuthority(
+    t,
+    address,
+    authority.publicKey,
+    other,
+    otherHandler,
+  );
+  await txAccept.assertError(t, /has_one constraint was violated/i);
+});
+
+test('authority transfer: cancel', async (t) => {
+  const {
+    fstTxHandler: authorityHandler,
+    authorityPair: authority,
+    connection,
+  } = await API.authority();
+
+  const { tx: txInit, candyGuard: address } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    authority,
+    authorityHandler,
+  );
+  await txInit.assertSuccess(t);
+
+  const { fstTxHandler: minterHandler, minterPair: minter } = await API.minter();
+  const { tx: txPropose, proposal } = await API.proposeAuthority(
+    t,
+    address,
+    authority,
+    minter.publicKey,
+    authorityHandler,
+  );
+  await txPropose.assertSuccess(t);
+
+  const { tx: txCancel } = await API.cancelAuthorityProposal(
+    t,
+    address,
+    authority,
+    authorityHandler,
+  );
+  await txCancel.assertSuccess(t);
+
+  const proposalInfo = await connection.getAccountInfo(proposal);
+  t.true(proposalInfo === null, 'proposal account must be closed');
+
+  const { tx: txAccept } = await API.acceptAuthority(
+    t,
+    address,
+    authority.publicKey,
+    minter,
+    minterHandler,
+  );
+  await txAccept.assertError(t, /AccountNotInitialized/i);
+
+  const candyGuard = await CandyGuard.fromAccountAddress(connection, address);
+  spok(t, candyGuard, {
+    authority: spokSamePubkey(authority.publicKey),
+  });
+});
+
+test('authority transfer: proposal is stale after set_authority', async (t) => {
+  const { fstTxHandler: authorityHandler, authorityPair: authority } = await API.authority();
+
+  const { tx: txInit, candyGuard: address } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    authority,
+    authorityHandler,
+  );
+  await txInit.assertSuccess(t);
+
+  const { fstTxHandler: minterHandler, minterPair: minter } = await API.minter();
+  const { tx: txPropose } = await API.proposeAuthority(
+    t,
+    address,
+    authority,
+    minter.publicKey,
+    authorityHandler,
+  );
+  await txPropose.assertSuccess(t);
+
+  // the authority changes without the proposal
+  const { minterPair: other } = await API.minter();
+  const { tx: txSet } = await API.setAuthority(
+    t,
+    address,
+    authority,
+    other.publicKey,
+    authorityHandler,
+  );
+  await txSet.assertSuccess(t);
+
+  const { tx: txAccept } = await API.acceptAuthority(
+    t,
+    address,
+    other.publicKey,
+    minter,
+    minterHandler,
+  );
+  await txAccept.assertError(t, /Authority proposal was not created by the current authority/i);
+});
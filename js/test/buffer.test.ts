@@ -0,0 +1,194 @@
+import test from 'tape';
+import spok from 'spok';
+import { BN } from 'bn.js';
+import { newCandyGuardData, newGuardSet, InitTransactions, killStuckProcess } from './setup';
+import { DATA_OFFSET, spokSameBignum } from './utils';
+import { deserialize, serialize } from '../src';
+
+const API = new InitTransactions();
+
+// Bytes offset for the start of the buffer data section:
+//     8 (discriminator)
+//  + 32 (candy guard)
+const BUFFER_DATA_OFFSET = 8 + 32;
+
+killStuckProcess();
+
+test('Buffer: commit configuration written in chunks', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const { tx: transaction, candyGuard: address } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    payerPair,
+    fstTxHandler,
+  );
+  await transaction.assertSuccess(t);
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1662394820,
+  };
+  data.groups = [];
+
+  for (let i = 0; i < 3; i++) {
+    const group = newGuardSet();
+    group.solPayment = {
+      lamports: new BN(1000 * (i + 1)),
+      destination: payerPair.publicKey,
+    };
+    data.groups.push({
+      label: `GROUP${i}`,
+      guards: group,
+    });
+  }
+
+  const serialized = serialize(data);
+  const middle = Math.floor(serialized.length / 2);
+
+  // the first chunk starts a new configuration
+  const { tx: writeTx1, buffer } = await API.writeBuffer(
+    t,
+    address,
+    0,
+    serialized.subarray(0, middle),
+    payerPair,
+    fstTxHandler,
+  );
+  await writeTx1.assertSuccess(t);
+
+  const { tx: writeTx2 } = await API.writeBuffer(
+    t,
+    address,
+    middle,
+    serialized.subarray(middle),
+    payerPair,
+    fstTxHandler,
+  );
+  await writeTx2.assertSuccess(t);
+
+  let bufferInfo = await connection.getAccountInfo(buffer);
+  t.equal(
+    bufferInfo!.data.length,
+    BUFFER_DATA_OFFSET + serialized.length,
+    'buffer size matches the staged data',
+  );
+
+  const { tx: commitTx } = await API.commitBuffer(t, address, payerPair, fstTxHandler);
+  await commitTx.assertSuccess(t);
+
+  const accountInfo = await connection.getAccountInfo(address);
+  const candyGuardData = deserialize(accountInfo!.data.subarray(DATA_OFFSET));
+
+  spok(t, candyGuardData.default.startDate?.date, spokSameBignum(1662394820));
+  t.true(candyGuardData.groups?.length === 3, 'expected 3 groups');
+  spok(t, candyGuardData.groups!.at(2)!.label, 'GROUP2');
+  spok(t, candyGuardData.groups!.at(2)!.guards.solPayment?.lamports, spokSameBignum(3000));
+
+  // the buffer is closed after the commit
+  bufferInfo = await connection.getAccountInfo(buffer);
+  t.true(bufferInfo === null, 'buffer account must be closed');
+});
+
+test('Buffer: writing at offset 0 discards previously staged data', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const { tx: transaction, candyGuard: address } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    payerPair,
+    fstTxHandler,
+  );
+  await transaction.assertSuccess(t);
+
+  // stages a configuration with groups
+  const large = newCandyGuardData();
+  large.groups = [];
+
+  for (let i = 0; i < 2; i++) {
+    const group = newGuardSet();
+    group.startDate = {
+      date: 1662394820 + i,
+    };
+    large.groups.push({
+      label: `GROUP${i}`,
+      guards: group,
+    });
+  }
+
+  const { tx: writeTx1, buffer } = await API.writeBuffer(
+    t,
+    address,
+    0,
+    serialize(large),
+    payerPair,
+    fstTxHandler,
+  );
+  await writeTx1.assertSuccess(t);
+
+  // replaces it with a smaller configuration
+  const small = newCandyGuardData();
+  small.default.solPayment = {
+    lamports: new BN(500),
+    destination: payerPair.publicKey,
+  };
+  const serialized = serialize(small);
+
+  const { tx: writeTx2 } = await API.writeBuffer(
+    t,
+    address,
+    0,
+    serialized,
+    payerPair,
+    fstTxHandler,
+  );
+  await writeTx2.assertSuccess(t);
+
+  const bufferInfo = await connection.getAccountInfo(buffer);
+  t.equal(
+    bufferInfo!.data.length,
+    BUFFER_DATA_OFFSET + serialized.length,
+    'buffer is truncated to the new configuration',
+  );
+
+  const { tx: commitTx } = await API.commitBuffer(t, address, payerPair, fstTxHandler);
+  await commitTx.assertSuccess(t);
+
+  const accountInfo = await connection.getAccountInfo(address);
+  const candyGuardData = deserialize(accountInfo!.data.subarray(DATA_OFFSET));
+
+  spok(t, candyGuardData.default.solPayment?.lamports, spokSameBignum(500));
+  t.true(candyGuardData.groups === null, 'expected no groups');
+});
+
+test('Buffer: close discards staged data', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const { tx: transaction, candyGuard: address } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    payerPair,
+    fstTxHandler,
+  );
+  await transaction.assertSuccess(t);
+
+  const { tx: writeTx, buffer } = await API.writeBuffer(
+    t,
+    address,
+    0,
+    serialize(newCandyGuardData()),
+    payerPair,
+    fstTxHandler,
+  );
+  await writeTx.assertSuccess(t);
+
+  const { tx: closeTx } = await API.closeBuffer(t, address, payerPair, fstTxHandler);
+  await closeTx.assertSuccess(t);
+
+  const bufferInfo = await connection.getAccountInfo(buffer);
+  t.true(bufferInfo === null, 'buffer account must be closed');
+
+  // nothing left to commit
+  const { tx: commitTx } = await API.commitBuffer(t, address, payerPair, fstTxHandler);
+  await commitTx.assertError(t, /AccountNotInitialized/i);
+});
@@ -0,0 +1,291 @@
+import test from 'tape';
+import spok from 'spok';
+import { AccountMeta, PublicKey } from '@solarti/web3.js';
+import { BN } from 'bn.js';
+import { InitTransactions, killStuckProcess, newCandyGuardData } from './setup';
+import { DATA_OFFSET, spokSameBignum, spokSamePubkey } from './utils';
+import { Delegate, DelegateRole } from '../src/generated';
+import { deserialize } from '../src';
+
+const API = new InitTransactions();
+
+const UPDATER = 1 << DelegateRole.Updater;
+const TREASURER = 1 << DelegateRole.Treasurer;
+
+killStuckProcess();
+
+function delegateAccounts(delegateRecord: PublicKey): AccountMeta[] {
+  return [
+    {
+      pubkey: delegateRecord,
+      isSigner: false,
+      isWritable: false,
+    },
+  ];
+}
+
+test('Delegate: updater can only change dates and prices', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const { fstTxHandler: delegateHandler, minterPair: delegate } = await API.minter();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1662394820,
+  };
+  data.default.solPayment = {
+    lamports: new BN(100000000),
+    destination: payerPair.publicKey,
+  };
+
+  const { tx: initTx, candyGuard } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await initTx.assertSuccess(t);
+
+  const { tx: delegateTx, delegateRecord } = await API.setDelegate(
+    t,
+    candyGuard,
+    delegate.publicKey,
+    UPDATER,
+    payerPair,
+    fstTxHandler,
+  );
+  await delegateTx.assertSuccess(t);
+
+  const record = await Delegate.fromAccountAddress(connection, delegateRecord);
+  spok(t, record, {
+    candyGuard: spokSamePubkey(candyGuard),
+    delegate: spokSamePubkey(delegate.publicKey),
+    authority: spokSamePubkey(payerPair.publicKey),
+    roles: UPDATER,
+  });
+
+  // changes the date and price
+
+  data.default.startDate = {
+    date: 1672444800,
+  };
+  data.default.solPayment = {
+    lamports: new BN(200000000),
+    destination: payerPair.publicKey,
+  };
+
+  const { tx: updateTx } = await API.update(
+    t,
+    candyGuard,
+    data,
+    delegate,
+    delegateHandler,
+    delegateAccounts(delegateRecord),
+  );
+  await updateTx.assertSuccess(t);
+
+  const accountInfo = await connection.getAccountInfo(candyGuard);
+  const candyGuardData = deserialize(accountInfo!.data.subarray(DATA_OFFSET));
+  spok(t, candyGuardData.default.startDate?.date, spokSameBignum(1672444800));
+  spok(t, candyGuardData.default.solPayment?.lamports, spokSameBignum(200000000));
+
+  // changes the destination of the payment
+
+  data.default.solPayment = {
+    lamports: new BN(200000000),
+    destination: delegate.publicKey,
+  };
+
+  const { tx: destinationTx } = await API.update(
+    t,
+    candyGuard,
+    data,
+    delegate,
+    delegateHandler,
+    delegateAccounts(delegateRecord),
+  );
+  await destinationTx.assertError(t, /Update not allowed for the delegate role/i);
+
+  // adds a guard
+
+  data.default.solPayment = {
+    lamports: new BN(200000000),
+    destination: payerPair.publicKey,
+  };
+  data.default.endDate = {
+    date: 1672531200,
+  };
+
+  const { tx: guardTx } = await API.update(
+    t,
+    candyGuard,
+    data,
+    delegate,
+    delegateHandler,
+    delegateAccounts(delegateRecord),
+  );
+  await guardTx.assertError(t, /Update not allowed for the delegate role/i);
+});
+
+test('Delegate: update requires a delegate record with the updater role', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+  const { fstTxHandler: delegateHandler, minterPair: delegate } = await API.minter();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1662394820,
+  };
+
+  const { tx: initTx, candyGuard } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await initTx.assertSuccess(t);
+
+  data.default.startDate = {
+    date: 1672444800,
+  };
+
+  // without a delegate record
+
+  const { tx: noRecordTx } = await API.update(t, candyGuard, data, delegate, delegateHandler);
+  await noRecordTx.assertError(t, /Signer is not authorized/i);
+
+  // with the treasurer role only
+
+  const { tx: delegateTx, delegateRecord } = await API.setDelegate(
+    t,
+    candyGuard,
+    delegate.publicKey,
+    TREASURER,
+    payerPair,
+    fstTxHandler,
+  );
+  await delegateTx.assertSuccess(t);
+
+  const { tx: treasurerTx } = await API.update(
+    t,
+    candyGuard,
+    data,
+    delegate,
+    delegateHandler,
+    delegateAccounts(delegateRecord),
+  );
+  await treasurerTx.assertError(t, /Signer is not authorized/i);
+
+  // with the record of another delegate
+
+  const { fstTxHandler: otherHandler, minterPair: other } = await API.minter();
+
+  const { tx: otherTx } = await API.update(
+    t,
+    candyGuard,
+    data,
+    other,
+    otherHandler,
+    delegateAccounts(delegateRecord),
+  );
+  await otherTx.assertError(t, /Signer is not authorized/i);
+
+  // after the revoke
+
+  const { tx: updaterTx } = await API.setDelegate(
+    t,
+    candyGuard,
+    delegate.publicKey,
+    UPDATER,
+    payerPair,
+    fstTxHandler,
+  );
+  await updaterTx.assertSuccess(t);
+
+  const { tx: revokeTx } = await API.revokeDelegate(
+    t,
+    candyGuard,
+    delegate.publicKey,
+    payerPair,
+    fstTxHandler,
+  );
+  await revokeTx.assertSuccess(t);
+
+  const { tx: revokedTx } = await API.update(
+    t,
+    candyGuard,
+    data,
+    delegate,
+    delegateHandler,
+    delegateAccounts(delegateRecord),
+  );
+  await revokedTx.assertError(t, /Signer is not authorized/i);
+});
+
+test('Delegate: roles are cleared when the authority changes', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+  const { fstTxHandler: delegateHandler, minterPair: delegate } = await API.minter();
+  const { fstTxHandler: authorityHandler, authorityPair: authority } = await API.authority();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1662394820,
+  };
+
+  const { tx: initTx, candyGuard } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await initTx.assertSuccess(t);
+
+  const { tx: delegateTx, delegateRecord } = await API.setDelegate(
+    t,
+    candyGuard,
+    delegate.publicKey,
+    UPDATER,
+    payerPair,
+    fstTxHandler,
+  );
+  await delegateTx.assertSuccess(t);
+
+  const { tx: proposeTx } = await API.proposeAuthority(
+    t,
+    candyGuard,
+    payerPair,
+    authority.publicKey,
+    fstTxHandler,
+  );
+  await proposeTx.assertSuccess(t);
+
+  const { tx: acceptTx } = await API.acceptAuthority(
+    t,
+    candyGuard,
+    payerPair.publicKey,
+    authority,
+    authorityHandler,
+  );
+  await acceptTx.assertSuccess(t);
+
+  data.default.startDate = {
+    date: 1672444800,
+  };
+
+  // the roles were assigned by the previous authority
+
+  const { tx: staleTx } = await API.update(
+    t,
+    candyGuard,
+    data,
+    delegate,
+    delegateHandler,
+    delegateAccounts(delegateRecord),
+  );
+  await staleTx.assertError(t, /Signer is not authorized/i);
+
+  // the new authority assigns the roles again
+
+  const { tx: reassignTx } = await API.setDelegate(
+    t,
+    candyGuard,
+    delegate.publicKey,
+    UPDATER,
+    authority,
+    authorityHandler,
+  );
+  await reassignTx.assertSuccess(t);
+
+  const { tx: updateTx } = await API.update(
+    t,
+    candyGuard,
+    data,
+    delegate,
+    delegateHandler,
+    delegateAccounts(delegateRecord),
+  );
+  await updateTx.assertSuccess(t);
+});
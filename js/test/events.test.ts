@@ -0,0 +1,100 @@
+import test from 'tape';
+import { PublicKey } from '@solarti/web3.js';
+import { InitTransactions, killStuckProcess, newCandyGuardData } from './setup';
+import { findEvents } from './utils';
+import { AuthorityChangeKind, DelegateRole } from '../src/generated';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+function readPubkey(data: Buffer, offset: number): PublicKey {
+  return new PublicKey(data.subarray(offset, offset + 32));
+}
+
+test('Events: authority transfer', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+  const { fstTxHandler: authorityHandler, authorityPair: authority } = await API.authority();
+
+  const { tx: initTx, candyGuard } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    payerPair,
+    fstTxHandler,
+  );
+  await initTx.assertSuccess(t);
+
+  const { tx: proposeTx } = await API.proposeAuthority(
+    t,
+    candyGuard,
+    payerPair,
+    authority.publicKey,
+    fstTxHandler,
+  );
+  const { txSummary: proposeSummary } = await proposeTx.assertSuccess(t);
+
+  // candy guard + authority + new authority + kind
+  const [proposeEvent] = findEvents(proposeSummary.logMessages, 'AuthorityEvent');
+  t.true(readPubkey(proposeEvent, 0).equals(candyGuard), 'candy guard');
+  t.true(readPubkey(proposeEvent, 32).equals(payerPair.publicKey), 'authority');
+  t.true(readPubkey(proposeEvent, 64).equals(authority.publicKey), 'new authority');
+  t.equal(proposeEvent[96], AuthorityChangeKind.Propose, 'kind');
+
+  const { tx: acceptTx } = await API.acceptAuthority(
+    t,
+    candyGuard,
+    payerPair.publicKey,
+    authority,
+    authorityHandler,
+  );
+  const { txSummary: acceptSummary } = await acceptTx.assertSuccess(t);
+
+  const [acceptEvent] = findEvents(acceptSummary.logMessages, 'AuthorityEvent');
+  t.true(readPubkey(acceptEvent, 32).equals(payerPair.publicKey), 'authority');
+  t.true(readPubkey(acceptEvent, 64).equals(authority.publicKey), 'new authority');
+  t.equal(acceptEvent[96], AuthorityChangeKind.Accept, 'kind');
+});
+
+test('Events: delegate roles', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+  const { minterPair: delegate } = await API.minter();
+
+  const { tx: initTx, candyGuard } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    payerPair,
+    fstTxHandler,
+  );
+  await initTx.assertSuccess(t);
+
+  const roles = 1 << DelegateRole.Treasurer;
+  const { tx: delegateTx } = await API.setDelegate(
+    t,
+    candyGuard,
+    delegate.publicKey,
+    roles,
+    payerPair,
+    fstTxHandler,
+  );
+  const { txSummary: delegateSummary } = await delegateTx.assertSuccess(t);
+
+  // candy guard + authority + delegate + roles
+  const [setEvent] = findEvents(delegateSummary.logMessages, 'DelegateEvent');
+  t.true(readPubkey(setEvent, 0).equals(candyGuard), 'candy guard');
+  t.true(readPubkey(setEvent, 32).equals(payerPair.publicKey), 'authority');
+  t.true(readPubkey(setEvent, 64).equals(delegate.publicKey), 'delegate');
+  t.equal(setEvent[96], roles, 'roles');
+
+  const { tx: revokeTx } = await API.revokeDelegate(
+    t,
+    candyGuard,
+    delegate.publicKey,
+    payerPair,
+    fstTxHandler,
+  );
+  const { txSummary: revokeSummary } = await revokeTx.assertSuccess(t);
+
+  const [revokeEvent] = findEvents(revokeSummary.logMessages, 'DelegateEvent');
+  t.true(readPubkey(revokeEvent, 64).equals(delegate.publicKey), 'delegate');
+  t.equal(revokeEvent[96], 0, 'roles');
+});
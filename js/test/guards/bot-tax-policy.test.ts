@@ -0,0 +1,444 @@
+import test from 'tape';
+import { BN } from 'bn.js';
+import { Keypair, SystemProgram, Transaction } from '@solarti/web3.js';
+import {
+  amman,
+  InitTransactions,
+  killStuckProcess,
+  newCandyGuardData,
+  newGuardSet,
+} from '../setup';
+import { GroupNotFoundError, MintNotLiveError, PROGRAM_ID } from '../../src/generated';
+import { getOffenseCounterPDA } from '../utils';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('bot tax policy (destination)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const treasury = Keypair.generate().publicKey;
+
+  const data = newCandyGuardData();
+  data.default.botTax = {
+    lamports: 10000000,
+    lastInstruction: false,
+  };
+  data.default.startDate = {
+    date: 32534611200,
+  };
+  data.default.botTaxPolicy = {
+    destination: treasury,
+    escalation: null,
+    exemptions: [],
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: treasury,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+  await minterMintTx.assertSuccess(t, [/Mint is not live/i, /Botting/i]);
+
+  const balance = await connection.getBalance(treasury);
+  t.equal(balance, 10000000, 'tax transferred to the destination');
+});
+
+test('bot tax policy (missing destination account)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.botTax = {
+    lamports: 10000000,
+    lastInstruction: false,
+  };
+  data.default.startDate = {
+    date: 32534611200,
+  };
+  data.default.botTaxPolicy = {
+    destination: Keypair.generate().publicKey,
+    escalation: null,
+    exemptions: [],
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+  );
+  // the tax is charged to the candy machine account instead
+  const before = await connection.getBalance(candyMachine);
+  await minterMintTx.assertSuccess(t, [/Missing bot tax destination account/i, /Botting/i]);
+
+  const after = await connection.getBalance(candyMachine);
+  t.equal(after - before, 10000000, 'tax transferred to the candy machine');
+});
+
+test('bot tax policy (escalation)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.botTax = {
+    lamports: 10000000,
+    lastInstruction: false,
+  };
+  data.default.startDate = {
+    date: 32534611200,
+  };
+  data.default.botTaxPolicy = {
+    destination: null,
+    escalation: {
+      increment: new BN(10000000),
+      maxLamports: new BN(25000000),
+    },
+    exemptions: [],
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const offenseCounter = await getOffenseCounterPDA(PROGRAM_ID, minter.publicKey, candyGuard);
+
+  // the tax is increased for each offense, up to the maximum
+  const expected = [10000000, 20000000, 25000000];
+
+  for (let i = 0; i < expected.length; i++) {
+    const [, mintForMinter] = await amman.genLabeledKeypair(`Mint Account (minter ${i})`);
+    const before = await connection.getBalance(candyMachine);
+
+    const { tx: minterMintTx } = await API.mint(
+      t,
+      candyGuard,
+      candyMachine,
+      minter,
+      mintForMinter,
+      minterHandler,
+      minterConnection,
+      [
+        {
+          pubkey: offenseCounter,
+          isSigner: false,
+          isWritable: true,
+        },
+      ],
+    );
+    await minterMintTx.assertSuccess(t, [/Mint is not live/i, /Botting/i]);
+
+    const after = await connection.getBalance(candyMachine);
+    t.equal(after - before, expected[i], `offense ${i + 1} taxed at ${expected[i]} lamports`);
+  }
+
+  const counterInfo = await connection.getAccountInfo(offenseCounter);
+  t.equal(counterInfo?.data.readUInt32LE(0), 3, 'expected 3 offenses');
+});
+
+test('bot tax policy (missing offense counter)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.botTax = {
+    lamports: 10000000,
+    lastInstruction: false,
+  };
+  data.default.botTaxPolicy = {
+    destination: null,
+    escalation: {
+      increment: new BN(10000000),
+      maxLamports: new BN(25000000),
+    },
+    exemptions: [],
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // the offense counter is required even when the transaction is valid, but
+  // leaving it out does not avoid the (flat) tax
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+  );
+  const before = await connection.getBalance(candyMachine);
+  await minterMintTx.assertSuccess(t, [/Missing bot tax offense counter account/i, /Botting/i]);
+
+  const after = await connection.getBalance(candyMachine);
+  t.equal(after - before, 10000000, 'flat tax transferred to the candy machine');
+});
+
+test('bot tax policy (funded offense counter address)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.botTax = {
+    lamports: 10000000,
+    lastInstruction: false,
+  };
+  data.default.startDate = {
+    date: 32534611200,
+  };
+  data.default.botTaxPolicy = {
+    destination: null,
+    escalation: {
+      increment: new BN(10000000),
+      maxLamports: new BN(25000000),
+    },
+    exemptions: [],
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const offenseCounter = await getOffenseCounterPDA(PROGRAM_ID, minter.publicKey, candyGuard);
+
+  // lamports sent to the offense counter address before its creation
+  const transferTx = new Transaction().add(
+    SystemProgram.transfer({
+      fromPubkey: payerPair.publicKey,
+      toPubkey: offenseCounter,
+      lamports: 1000000,
+    }),
+  );
+  const transferHandler = fstTxHandler.sendAndConfirmTransaction(
+    transferTx,
+    [payerPair],
+    'tx: Transfer',
+  );
+  await transferHandler.assertSuccess(t);
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const before = await connection.getBalance(candyMachine);
+
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: offenseCounter,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+  await minterMintTx.assertSuccess(t, [/Mint is not live/i, /Botting/i]);
+
+  const after = await connection.getBalance(candyMachine);
+  t.equal(after - before, 10000000, 'tax transferred to the candy machine');
+
+  const counterInfo = await connection.getAccountInfo(offenseCounter);
+  t.ok(counterInfo?.owner.equals(PROGRAM_ID), 'expected offense counter created');
+  t.equal(counterInfo?.data.readUInt32LE(0), 1, 'expected 1 offense');
+});
+
+test('bot tax policy (exemptions)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.botTax = {
+    lamports: 10000000,
+    lastInstruction: false,
+  };
+  data.default.startDate = {
+    date: 32534611200,
+  };
+  data.default.botTaxPolicy = {
+    destination: null,
+    escalation: null,
+    exemptions: [new MintNotLiveError().code],
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+  );
+  await minterMintTx.assertError(t, /Mint is not live/i);
+});
+
+test('bot tax policy (exempted group error)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.botTax = {
+    lamports: 10000000,
+    lastInstruction: false,
+  };
+  data.default.botTaxPolicy = {
+    destination: null,
+    escalation: null,
+    exemptions: [new GroupNotFoundError().code],
+  };
+  data.groups = [
+    {
+      label: 'VIP',
+      guards: newGuardSet(),
+    },
+  ];
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    null,
+    null,
+    'OGs',
+  );
+  await minterMintTx.assertError(t, /Group not found/i);
+});
+
+test('bot tax policy (invalid exemption code)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.botTax = {
+    lamports: 10000000,
+    lastInstruction: false,
+  };
+  data.default.botTaxPolicy = {
+    destination: null,
+    escalation: null,
+    // not a candy guard error code
+    exemptions: [42],
+  };
+
+  const { tx: transaction } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await transaction.assertError(t, /Exemption list contains an invalid error code/i);
+});
+
+test('bot tax policy (duplicated exemption code)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.botTax = {
+    lamports: 10000000,
+    lastInstruction: false,
+  };
+  data.default.botTaxPolicy = {
+    destination: null,
+    escalation: null,
+    exemptions: [new MintNotLiveError().code, new MintNotLiveError().code],
+  };
+
+  const { tx: transaction } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await transaction.assertError(t, /Duplicated error code in the exemption list/i);
+});
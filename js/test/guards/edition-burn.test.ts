@@ -0,0 +1,212 @@
+import test from 'tape';
+import { AccountMeta, PublicKey } from '@solarti/web3.js';
+import { ASSOCIATED_TOKEN_PROGRAM_ID, getAccount, TOKEN_PROGRAM_ID } from '@solarti/spl-token';
+import { keypairIdentity, Metaplex, toBigNumber, token } from '@metaplex-solarti/js';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { METAPLEX_PROGRAM_ID } from '../utils';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('edition burn (fungible asset)', async (t) => {
+  const {
+    fstTxHandler: authorityHandler,
+    authorityPair: authority,
+    connection: authorityConnection,
+  } = await API.authority();
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+
+  // fungible asset (semi-fungible token with 0 decimals) held by the minter
+
+  const metaplex = Metaplex.make(authorityConnection).use(keypairIdentity(authority));
+  const { sft } = await metaplex.nfts().createSft({
+    uri: 'https://example.com/sft.json',
+    name: 'Fungible Asset',
+    sellerFeeBasisPoints: 0,
+    tokenOwner: minter.publicKey,
+    tokenAmount: token(5),
+  });
+
+  const data = newCandyGuardData();
+  data.default.editionBurn = {
+    mint: sft.address,
+    amount: 3,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    authority,
+    authorityHandler,
+    authorityConnection,
+  );
+
+  const [tokenAccount] = await PublicKey.findProgramAddress(
+    [minter.publicKey.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), sft.address.toBuffer()],
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+  );
+  const accounts: AccountMeta[] = [
+    // token account
+    { pubkey: tokenAccount, isSigner: false, isWritable: true },
+    // metadata
+    { pubkey: sft.metadataAddress, isSigner: false, isWritable: false },
+    // mint
+    { pubkey: sft.address, isSigner: false, isWritable: true },
+  ];
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    accounts,
+  );
+  await minterMintTx.assertSuccess(t);
+
+  const account = await getAccount(minterConnection, tokenAccount);
+  t.equal(account.amount.toString(), '2', 'expected 3 tokens burned');
+
+  // not enough tokens left
+  const [, mintForMinter2] = await amman.genLabeledKeypair('Mint Account 2 (minter)');
+  const { tx: minterMintTx2 } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter2,
+    minterHandler,
+    minterConnection,
+    accounts,
+  );
+  await minterMintTx2.assertError(t, /Not enough tokens on the account/i);
+});
+
+test('edition burn (print edition)', async (t) => {
+  const {
+    fstTxHandler: authorityHandler,
+    authorityPair: authority,
+    connection: authorityConnection,
+  } = await API.authority();
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+
+  // master edition held by the authority and a print edition held by the minter
+
+  const metaplex = Metaplex.make(authorityConnection).use(keypairIdentity(authority));
+  const { nft: master } = await metaplex.nfts().createNft({
+    uri: 'https://example.com/master.json',
+    name: 'Master Edition',
+    sellerFeeBasisPoints: 0,
+    maxSupply: toBigNumber(10),
+  });
+  const { nft: print } = await metaplex.nfts().printNewEdition({
+    originalMint: master.address,
+    newOwner: minter.publicKey,
+  });
+
+  const data = newCandyGuardData();
+  data.default.editionBurn = {
+    mint: master.address,
+    amount: 1,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    authority,
+    authorityHandler,
+    authorityConnection,
+  );
+
+  // the first print edition belongs to the edition marker 0
+  const [editionMarker] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from('metadata'),
+      METAPLEX_PROGRAM_ID.toBuffer(),
+      master.address.toBuffer(),
+      Buffer.from('edition'),
+      Buffer.from('0'),
+    ],
+    METAPLEX_PROGRAM_ID,
+  );
+
+  const printAccounts: AccountMeta[] = [
+    // token account of the print edition
+    { pubkey: print.token.address, isSigner: false, isWritable: true },
+    // metadata of the print edition
+    { pubkey: print.metadataAddress, isSigner: false, isWritable: true },
+    // mint of the print edition
+    { pubkey: print.address, isSigner: false, isWritable: true },
+    // edition account of the print edition
+    { pubkey: print.edition.address, isSigner: false, isWritable: true },
+    // mint of the master edition
+    { pubkey: master.address, isSigner: false, isWritable: false },
+    // token account of the master edition
+    { pubkey: master.token.address, isSigner: false, isWritable: false },
+    // master edition account
+    { pubkey: master.edition.address, isSigner: false, isWritable: true },
+    // edition marker
+    { pubkey: editionMarker, isSigner: false, isWritable: true },
+  ];
+
+  // invalid edition marker
+  const [, markerMint] = await amman.genLabeledKeypair('Mint Account (marker)');
+  const { tx: markerMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    markerMint,
+    minterHandler,
+    minterConnection,
+    [...printAccounts.slice(0, 7), { ...printAccounts[7], pubkey: master.edition.address }],
+  );
+  await markerMintTx.assertError(t, /Public key mismatch/i);
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    printAccounts,
+  );
+  await minterMintTx.assertSuccess(t);
+
+  const printInfo = await minterConnection.getAccountInfo(print.token.address);
+  t.equal(printInfo, null, 'expected print edition burned');
+
+  // the master edition is not a fungible asset
+
+  const [, mintForAuthority] = await amman.genLabeledKeypair('Mint Account (authority)');
+  const { tx: authorityMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    authority,
+    mintForAuthority,
+    authorityHandler,
+    authorityConnection,
+    [
+      { pubkey: master.token.address, isSigner: false, isWritable: true },
+      { pubkey: master.metadataAddress, isSigner: false, isWritable: false },
+      { pubkey: master.address, isSigner: false, isWritable: true },
+    ],
+  );
+  await authorityMintTx.assertError(t, /Token standard not supported by the guard/i);
+});
@@ -8,11 +8,13 @@ import {
   RouteInstructionArgs,
 } from '../../src/generated/instructions/route';
 import { GuardType } from '../../src/generated/types/GuardType';
-import { i64 } from '@metaplex-foundation/beet';
+import { i64, u64 } from '@metaplex-foundation/beet';
+import { BN } from 'bn.js';
 import { getAccount, getAssociatedTokenAddress, TOKEN_PROGRAM_ID } from '@solarti/spl-token';
 // import { findAssociatedTokenAccountPda, findMasterEditionV2Pda } from '@metaplex-solarti/js';
 import { findMasterEditionV2Pda } from '@metaplex-solarti/js';
-import { METAPLEX_PROGRAM_ID } from '../utils';
+import { findEvents, METAPLEX_PROGRAM_ID } from '../utils';
+import { FreezeEscrow } from '../../src/generated/accounts/FreezeEscrow';
 import {
   FreezeInstruction,
   freezeInstructionBeet,
@@ -477,11 +479,17 @@ test('Freeze Sol Payment (thaw enabled)', async (t) => {
     'tx: Route (Unlock Funds)',
   );
 
-  await unlockHandler.assertSuccess(t);
+  const { txSummary } = await unlockHandler.assertSuccess(t);
 
   const updatedAuthorityWallet = await connection.getAccountInfo(authorityPair.publicKey);
 
   t.true(authorityWallet!.lamports < updatedAuthorityWallet!.lamports);
+
+  // the unlocked amount does not include the rent of the escrow account
+  const [unlockEvent] = findEvents(txSummary.logMessages, 'UnlockFundsEvent');
+  // 4 pubkeys (candy guard, candy machine, escrow and destination) + option (none)
+  const amount = u64.read(unlockEvent, 4 * 32 + 1);
+  t.equal(new BN(amount).toNumber(), 1000000000, 'unlocked amount');
 });
 
 test('Freeze Sol Payment (unlock not enabled)', async (t) => {
@@ -856,3 +864,291 @@ test('Freeze Sol Payment (thaw with closed candy guard)', async (t) => {
   nftAtaAccount = await getAccount(minterConnection, nftAta);
   t.false(nftAtaAccount.isFrozen);
 });
+
+test('Freeze Sol Payment (enable thaw)', async (t) => {
+  const { fstTxHandler: authorityHandler, authorityPair, connection } = await API.authority();
+
+  const data = newCandyGuardData();
+  data.default.freezeSolPayment = {
+    lamports: 1000000000,
+    destination: authorityPair.publicKey,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    authorityPair,
+    authorityHandler,
+    connection,
+  );
+
+  const [freezeEscrow] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from('freeze_escrow'),
+      authorityPair.publicKey.toBuffer(),
+      candyGuard.toBuffer(),
+      candyMachine.toBuffer(),
+    ],
+    PROGRAM_ID,
+  );
+  amman.addr.addLabel('Freeze Escrow', freezeEscrow);
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair,
+    connection: minterConnection,
+  } = await API.minter();
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minterPair,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: freezeEscrow,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+
+  await minterMintTx.assertError(t, /Freeze must be initialized/i);
+
+  // route instruction to enable freeze
+
+  const freeze_accounts: RouteInstructionAccounts = {
+    candyGuard: candyGuard,
+    candyMachine: candyMachine,
+    payer: authorityPair.publicKey,
+  };
+
+  const freeze_buffer = Buffer.alloc(freezeInstructionBeet.byteSize + i64.byteSize);
+  freezeInstructionBeet.write(freeze_buffer, 0, FreezeInstruction.Initialize);
+  i64.write(freeze_buffer, freezeInstructionBeet.byteSize, 24 * 60 * 60);
+
+  const freeze_args: RouteInstructionArgs = {
+    args: {
+      guard: GuardType.FreezeSolPayment,
+      data: freeze_buffer,
+    },
+    label: null,
+  };
+
+  const freezeRouteIx = createRouteInstruction(freeze_accounts, freeze_args);
+  freezeRouteIx.keys.push(
+    ...[
+      {
+        pubkey: freezeEscrow,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: authorityPair.publicKey,
+        isSigner: true,
+        isWritable: false,
+      },
+      {
+        pubkey: SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+
+  const freezeTx = new Transaction().add(freezeRouteIx);
+
+  const freezeHandler = authorityHandler.sendAndConfirmTransaction(
+    freezeTx,
+    [authorityPair],
+    'tx: Route (Initialize)',
+  );
+
+  await freezeHandler.assertSuccess(t);
+
+  // minting
+
+  const [, mintForMinter2] = await amman.genLabeledKeypair('Mint Account 2 (minter)');
+  // const nftAta = findAssociatedTokenAccountPda(mintForMinter2.publicKey, minterPair.publicKey);
+  const nftAta = await getAssociatedTokenAddress(mintForMinter2.publicKey, minterPair.publicKey);
+  const { tx: minterMintTx2 } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minterPair,
+    mintForMinter2,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: freezeEscrow,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: nftAta,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+
+  await minterMintTx2.assertSuccess(t);
+
+  const nftAtaAccount = await getAccount(minterConnection, nftAta);
+  t.true(nftAtaAccount.isFrozen);
+
+  // thaw
+
+  const thaw_accounts: RouteInstructionAccounts = {
+    candyGuard: candyGuard,
+    candyMachine: candyMachine,
+    payer: minterPair.publicKey,
+  };
+
+  const thaw_buffer = Buffer.alloc(freezeInstructionBeet.byteSize);
+  freezeInstructionBeet.write(thaw_buffer, 0, FreezeInstruction.Thaw);
+
+  const thaw_args: RouteInstructionArgs = {
+    args: {
+      guard: GuardType.FreezeSolPayment,
+      data: thaw_buffer,
+    },
+    label: null,
+  };
+
+  const thawRouteIx = createRouteInstruction(thaw_accounts, thaw_args);
+  thawRouteIx.keys.push(
+    ...[
+      {
+        pubkey: freezeEscrow,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: mintForMinter2.publicKey,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: minterPair.publicKey,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: nftAta,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: findMasterEditionV2Pda(mintForMinter2.publicKey),
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: METAPLEX_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+
+  const thawTx = new Transaction().add(thawRouteIx);
+
+  const thawHandler = minterHandler.sendAndConfirmTransaction(
+    thawTx,
+    [minterPair],
+    'tx: Route (Thaw)',
+  );
+
+
+  await thawHandler.assertError(t, /Thaw is not enabled/i);
+
+  // enable thaw
+
+  const enable_buffer = Buffer.alloc(freezeInstructionBeet.byteSize);
+  freezeInstructionBeet.write(enable_buffer, 0, FreezeInstruction.EnableThaw);
+
+  const enable_args: RouteInstructionArgs = {
+    args: {
+      guard: GuardType.FreezeSolPayment,
+      data: enable_buffer,
+    },
+    label: null,
+  };
+
+  // only the candy guard authority can enable the thaw
+  const minterEnableRouteIx = createRouteInstruction(thaw_accounts, enable_args);
+  minterEnableRouteIx.keys.push(
+    ...[
+      {
+        pubkey: freezeEscrow,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: minterPair.publicKey,
+        isSigner: true,
+        isWritable: false,
+      },
+    ],
+  );
+
+  const minterEnableHandler = minterHandler.sendAndConfirmTransaction(
+    new Transaction().add(minterEnableRouteIx),
+    [minterPair],
+    'tx: Route (EnableThaw)',
+  );
+
+  await minterEnableHandler.assertError(t, /A signature was required but not found/i);
+
+  const enableRouteIx = createRouteInstruction(freeze_accounts, enable_args);
+  enableRouteIx.keys.push(
+    ...[
+      {
+        pubkey: freezeEscrow,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: authorityPair.publicKey,
+        isSigner: true,
+        isWritable: false,
+      },
+    ],
+  );
+
+  const enableHandler = authorityHandler.sendAndConfirmTransaction(
+    new Transaction().add(enableRouteIx),
+    [authorityPair],
+    'tx: Route (EnableThaw)',
+  );
+
+  await enableHandler.assertSuccess(t);
+
+  // the freeze period of the escrow is over
+  const escrowAccount = await FreezeEscrow.fromAccountAddress(connection, freezeEscrow);
+  t.equal(escrowAccount.freezePeriod.toString(), '0', 'expected freeze period to be over');
+
+  // thaw
+
+  const enabledThawHandler = minterHandler.sendAndConfirmTransaction(
+    new Transaction().add(thawRouteIx),
+    [minterPair],
+    'tx: Route (Thaw)',
+  );
+
+  await enabledThawHandler.assertSuccess(t);
+
+  const thawedAtaAccount = await getAccount(minterConnection, nftAta);
+  t.false(thawedAtaAccount.isFrozen);
+});
@@ -0,0 +1,142 @@
+import test from 'tape';
+import { TOKEN_PROGRAM_ID } from '@solarti/spl-token';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('instruction gate', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.instructionGate = {
+    // the mint transaction has 5 instructions
+    maxInstructions: 5,
+    singleMint: true,
+    forbidden: [],
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+  );
+  await minterMintTx.assertSuccess(t);
+});
+
+test('instruction gate (exceeded instruction count)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.instructionGate = {
+    maxInstructions: 4,
+    singleMint: false,
+    forbidden: [],
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+  );
+  await minterMintTx.assertError(t, /exceeded the maximum number of instructions/i);
+});
+
+test('instruction gate (forbidden instruction)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.instructionGate = {
+    maxInstructions: 0,
+    singleMint: false,
+    forbidden: [
+      {
+        programId: TOKEN_PROGRAM_ID,
+        // spl-token MintTo instruction
+        discriminator: Uint8Array.from([7]),
+      },
+    ],
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+  );
+  await minterMintTx.assertError(t, /forbidden instruction was found/i);
+});
+
+test('instruction gate (invalid discriminator)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.instructionGate = {
+    maxInstructions: 0,
+    singleMint: false,
+    forbidden: [
+      {
+        programId: TOKEN_PROGRAM_ID,
+        // an empty discriminator would match every instruction
+        discriminator: Uint8Array.from([]),
+      },
+    ],
+  };
+
+  const { tx: transaction } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await transaction.assertError(t, /Invalid instruction discriminator size/i);
+});
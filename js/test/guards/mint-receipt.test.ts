@@ -0,0 +1,104 @@
+import test from 'tape';
+import spok from 'spok';
+import { BN } from 'bn.js';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { PROGRAM_ID, Receipt } from '../../src/generated';
+import { getMintReceiptPDA, spokSameBignum, spokSamePubkey } from '../utils';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('mint receipt', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.solPayment = {
+    lamports: new BN(100000000),
+    destination: payerPair.publicKey,
+  };
+  data.default.mintReceipt = {};
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const receipt = await getMintReceiptPDA(PROGRAM_ID, mintForMinter.publicKey);
+
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: payerPair.publicKey,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: receipt,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+  await minterMintTx.assertSuccess(t);
+
+  const mintReceipt = await Receipt.fromAccountAddress(connection, receipt);
+  spok(t, mintReceipt, {
+    nftMint: spokSamePubkey(mintForMinter.publicKey),
+    candyGuard: spokSamePubkey(candyGuard),
+    candyMachine: spokSamePubkey(candyMachine),
+    payer: spokSamePubkey(minter.publicKey),
+  });
+  t.equal(mintReceipt.label, null, 'default guard set has no label');
+  t.equal(mintReceipt.payments.length, 1, 'expected 1 payment');
+  spok(t, mintReceipt.payments[0].amount, spokSameBignum(100000000));
+});
+
+test('mint receipt (missing receipt account)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.mintReceipt = {};
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+  );
+  await minterMintTx.assertError(t, /Missing expected remaining account/i);
+});
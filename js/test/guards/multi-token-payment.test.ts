@@ -0,0 +1,293 @@
+import test from 'tape';
+import {
+  AccountMeta,
+  Keypair,
+  PublicKey,
+  sendAndConfirmTransaction,
+  SystemProgram,
+  Transaction,
+} from '@solarti/web3.js';
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createMint,
+  ExtensionType,
+  getAccount,
+  getAssociatedTokenAddress,
+  getMintLen,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from '@solarti/spl-token';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('Multi Token Payment', async (t) => {
+  // creates two token mints to act as payment tokens
+  const {
+    fstTxHandler: authorityHandler,
+    authorityPair: authority,
+    connection: authorityConnection,
+  } = await API.authority();
+
+  const mints = [
+    await createMint(authorityConnection, authority, authority.publicKey, null, 0),
+    await createMint(authorityConnection, authority, authority.publicKey, null, 0),
+  ];
+
+  // the destination wallet does not have token accounts of the mints
+  const destination = Keypair.generate().publicKey;
+
+  const data = newCandyGuardData();
+  data.default.multiTokenPayment = {
+    payments: [
+      { amount: 5, mint: mints[0], destination },
+      { amount: 2, mint: mints[1], destination },
+    ],
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    authority,
+    authorityHandler,
+    authorityConnection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+
+  const accounts: AccountMeta[] = [];
+  const destinationAtas: PublicKey[] = [];
+
+  for (const mint of mints) {
+    const minterATA = await getOrCreateAssociatedTokenAccount(
+      minterConnection,
+      minter,
+      mint,
+      minter.publicKey,
+    );
+    // airdrop 10 tokens
+    await mintTo(authorityConnection, authority, mint, minterATA.address, authority.publicKey, 10);
+
+    const destinationAta = await getAssociatedTokenAddress(mint, destination);
+    destinationAtas.push(destinationAta);
+
+    accounts.push(
+      // token account
+      { pubkey: minterATA.address, isSigner: false, isWritable: true },
+      // destination wallet
+      { pubkey: destination, isSigner: false, isWritable: false },
+      // destination ATA
+      { pubkey: destinationAta, isSigner: false, isWritable: true },
+      // token mint
+      { pubkey: mint, isSigner: false, isWritable: false },
+      // token program
+      { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    );
+  }
+  // associated token program
+  accounts.push({ pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false });
+
+  // accounts of a single payment
+  const [, singleMint] = await amman.genLabeledKeypair('Mint Account (single)');
+  const { tx: singleMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    singleMint,
+    minterHandler,
+    minterConnection,
+    [...accounts.slice(0, 5), accounts[10]],
+  );
+  await singleMintTx.assertError(t, /Missing expected remaining account/i);
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    accounts,
+  );
+  await minterMintTx.assertSuccess(t);
+
+  // the destination ATAs were created with the payments
+  const first = await getAccount(authorityConnection, destinationAtas[0]);
+  t.equal(first.amount.toString(), '5', 'expected 5 tokens of the first mint');
+  const second = await getAccount(authorityConnection, destinationAtas[1]);
+  t.equal(second.amount.toString(), '2', 'expected 2 tokens of the second mint');
+
+  // the destination ATAs already exist
+  const [, mintForMinter2] = await amman.genLabeledKeypair('Mint Account 2 (minter)');
+  const { tx: minterMintTx2 } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter2,
+    minterHandler,
+    minterConnection,
+    accounts,
+  );
+  await minterMintTx2.assertSuccess(t);
+
+  // not enough tokens of the first mint (10 - 5 - 5)
+  const [, mintForMinter3] = await amman.genLabeledKeypair('Mint Account 3 (minter)');
+  const { tx: minterMintTx3 } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter3,
+    minterHandler,
+    minterConnection,
+    accounts,
+  );
+  await minterMintTx3.assertError(t, /Not enough tokens on the account/i);
+});
+
+test('Multi Token Payment (transfer fee)', async (t) => {
+  const {
+    fstTxHandler: authorityHandler,
+    authorityPair: authority,
+    connection: authorityConnection,
+  } = await API.authority();
+
+  // Token-2022 mint with a transfer fee of 1%
+  const mintPair = Keypair.generate();
+  const mint = mintPair.publicKey;
+  const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+
+  const mintTx = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: authority.publicKey,
+      newAccountPubkey: mint,
+      space: mintLen,
+      lamports: await authorityConnection.getMinimumBalanceForRentExemption(mintLen),
+      programId: TOKEN_2022_PROGRAM_ID,
+    }),
+    createInitializeTransferFeeConfigInstruction(
+      mint,
+      authority.publicKey,
+      authority.publicKey,
+      100,
+      BigInt(100),
+      TOKEN_2022_PROGRAM_ID,
+    ),
+    createInitializeMintInstruction(mint, 0, authority.publicKey, null, TOKEN_2022_PROGRAM_ID),
+  );
+  await sendAndConfirmTransaction(authorityConnection, mintTx, [authority, mintPair]);
+
+  const destination = Keypair.generate().publicKey;
+
+  const data = newCandyGuardData();
+  data.default.multiTokenPayment = {
+    payments: [{ amount: 5, mint, destination }],
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    authority,
+    authorityHandler,
+    authorityConnection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+
+  const minterATA = await getOrCreateAssociatedTokenAccount(
+    minterConnection,
+    minter,
+    mint,
+    minter.publicKey,
+    false,
+    undefined,
+    undefined,
+    TOKEN_2022_PROGRAM_ID,
+  );
+  await mintTo(
+    authorityConnection,
+    authority,
+    mint,
+    minterATA.address,
+    authority.publicKey,
+    10,
+    [],
+    undefined,
+    TOKEN_2022_PROGRAM_ID,
+  );
+
+  const destinationAta = await getAssociatedTokenAddress(
+    mint,
+    destination,
+    false,
+    TOKEN_2022_PROGRAM_ID,
+  );
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [
+      // token account
+      { pubkey: minterATA.address, isSigner: false, isWritable: true },
+      // destination wallet
+      { pubkey: destination, isSigner: false, isWritable: false },
+      // destination ATA
+      { pubkey: destinationAta, isSigner: false, isWritable: true },
+      // token mint
+      { pubkey: mint, isSigner: false, isWritable: false },
+      // token program
+      { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+      // associated token program
+      { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+  );
+  await minterMintTx.assertError(t, /Tokens with a transfer fee cannot be used as payment/i);
+});
+
+test('Multi Token Payment (invalid payments)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+  const mint = Keypair.generate().publicKey;
+
+  const emptyData = newCandyGuardData();
+  emptyData.default.multiTokenPayment = {
+    payments: [],
+  };
+
+  const { tx: emptyTx } = await API.initialize(t, emptyData, payerPair, fstTxHandler);
+  await emptyTx.assertError(t, /Invalid number of token payments/i);
+
+  const duplicatedData = newCandyGuardData();
+  duplicatedData.default.multiTokenPayment = {
+    payments: [
+      { amount: 1, mint, destination: payerPair.publicKey },
+      { amount: 2, mint, destination: payerPair.publicKey },
+    ],
+  };
+
+  const { tx: duplicatedTx } = await API.initialize(t, duplicatedData, payerPair, fstTxHandler);
+  await duplicatedTx.assertError(t, /Duplicated mint in the token payment list/i);
+});
@@ -0,0 +1,166 @@
+import test from 'tape';
+import { AccountMeta, Keypair, PublicKey } from '@solarti/web3.js';
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solarti/spl-token';
+import { findMasterEditionV2Pda, Metaplex, keypairIdentity } from '@metaplex-solarti/js';
+import { CandyMachine } from '@metaplex-solarti/mpl-candy-machine-core';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('nft match burn', async (t) => {
+  const { fstTxHandler: payerHandler, payerPair, connection: payerConnection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1662479807,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    payerHandler,
+    payerConnection,
+  );
+
+  // mints 2 NFTs of the collection
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const nftMints: Keypair[] = [];
+
+  for (let i = 0; i < 2; i++) {
+    const [, nftMint] = await amman.genLabeledKeypair(`Mint Account (nft ${i})`);
+    const { tx: nftMintTx } = await API.mint(
+      t,
+      candyGuard,
+      candyMachine,
+      minter,
+      nftMint,
+      minterHandler,
+      minterConnection,
+    );
+    await nftMintTx.assertSuccess(t);
+    nftMints.push(nftMint);
+  }
+
+  const candyMachineObject = await CandyMachine.fromAccountAddress(payerConnection, candyMachine);
+  const metaplex = Metaplex.make(minterConnection).use(keypairIdentity(minter));
+  const collection = await metaplex
+    .nfts()
+    .findByMint({ mintAddress: candyMachineObject.collectionMint });
+
+  const accounts: AccountMeta[][] = [];
+
+  for (const nftMint of nftMints) {
+    const nft = await metaplex.nfts().findByMint({ mintAddress: nftMint.publicKey });
+    const [tokenAccount] = await PublicKey.findProgramAddress(
+      [minter.publicKey.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), nftMint.publicKey.toBuffer()],
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+    );
+
+    accounts.push(
+      [
+        tokenAccount,
+        nft.metadataAddress,
+        findMasterEditionV2Pda(nftMint.publicKey),
+        nftMint.publicKey,
+        collection.metadataAddress,
+      ].map((pubkey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+      })),
+    );
+  }
+
+  // each mint requires 2 NFTs of the collection to be burned
+
+  const burnData = newCandyGuardData();
+  burnData.default.nftMatchBurn = {
+    required: {
+      __kind: 'Collections',
+      fields: [[candyMachineObject.collectionMint]],
+    },
+    amount: 2,
+  };
+
+  const { candyGuard: burnCandyGuard, candyMachine: burnCandyMachine } = await API.deploy(
+    t,
+    burnData,
+    payerPair,
+    payerHandler,
+    payerConnection,
+  );
+
+  // the accounts of a single NFT
+  const [, singleMint] = await amman.genLabeledKeypair('Mint Account (single)');
+  const { tx: singleMintTx } = await API.mint(
+    t,
+    burnCandyGuard,
+    burnCandyMachine,
+    minter,
+    singleMint,
+    minterHandler,
+    minterConnection,
+    accounts[0],
+  );
+  await singleMintTx.assertError(t, /Missing expected remaining account/i);
+
+  // the same NFT cannot be used twice
+  const [, duplicatedMint] = await amman.genLabeledKeypair('Mint Account (duplicated)');
+  const { tx: duplicatedMintTx } = await API.mint(
+    t,
+    burnCandyGuard,
+    burnCandyMachine,
+    minter,
+    duplicatedMint,
+    minterHandler,
+    minterConnection,
+    [...accounts[0], ...accounts[0]],
+  );
+  await duplicatedMintTx.assertError(t, /Duplicated NFT mint/i);
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    burnCandyGuard,
+    burnCandyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [...accounts[0], ...accounts[1]],
+  );
+  await minterMintTx.assertSuccess(t);
+
+  // the NFTs were burned
+  for (const nftMint of nftMints) {
+    const [tokenAccount] = await PublicKey.findProgramAddress(
+      [minter.publicKey.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), nftMint.publicKey.toBuffer()],
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+    );
+    t.equal(await minterConnection.getAccountInfo(tokenAccount), null, 'expected NFT burned');
+  }
+});
+
+test('nft match burn (invalid amount)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.nftMatchBurn = {
+    required: {
+      __kind: 'FirstCreator',
+      fields: [PublicKey.default],
+    },
+    amount: 0,
+  };
+
+  const { tx: transaction } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await transaction.assertError(t, /Invalid number of NFTs/i);
+});
@@ -0,0 +1,163 @@
+import test from 'tape';
+import { AccountMeta, Keypair, PublicKey } from '@solarti/web3.js';
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solarti/spl-token';
+import { findMasterEditionV2Pda, Metaplex, keypairIdentity } from '@metaplex-solarti/js';
+import { CandyMachine } from '@metaplex-solarti/mpl-candy-machine-core';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('nft match gate', async (t) => {
+  const { fstTxHandler: payerHandler, payerPair, connection: payerConnection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1662479807,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    payerHandler,
+    payerConnection,
+  );
+
+  // mints an NFT of the collection
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, nftMint] = await amman.genLabeledKeypair('Mint Account (nft)');
+  const { tx: nftMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    nftMint,
+    minterHandler,
+    minterConnection,
+  );
+  await nftMintTx.assertSuccess(t);
+
+  const candyMachineObject = await CandyMachine.fromAccountAddress(payerConnection, candyMachine);
+  const metaplex = Metaplex.make(minterConnection).use(keypairIdentity(minter));
+  const nft = await metaplex.nfts().findByMint({ mintAddress: nftMint.publicKey });
+
+  const [tokenAccount] = await PublicKey.findProgramAddress(
+    [minter.publicKey.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), nftMint.publicKey.toBuffer()],
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+  );
+  const accounts: AccountMeta[] = [
+    tokenAccount,
+    nft.metadataAddress,
+    nftMint.publicKey,
+    findMasterEditionV2Pda(nftMint.publicKey),
+  ].map((pubkey) => ({
+    pubkey,
+    isSigner: false,
+    isWritable: false,
+  }));
+
+  // any of the collections in the list
+
+  const collectionData = newCandyGuardData();
+  collectionData.default.nftMatchGate = {
+    required: {
+      __kind: 'Collections',
+      fields: [[Keypair.generate().publicKey, candyMachineObject.collectionMint]],
+    },
+  };
+
+  const { candyGuard: collectionCandyGuard, candyMachine: collectionCandyMachine } =
+    await API.deploy(t, collectionData, payerPair, payerHandler, payerConnection);
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    collectionCandyGuard,
+    collectionCandyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    accounts,
+  );
+  await minterMintTx.assertSuccess(t);
+
+  // the first creator does not match
+
+  const creatorData = newCandyGuardData();
+  creatorData.default.nftMatchGate = {
+    required: {
+      __kind: 'FirstCreator',
+      fields: [Keypair.generate().publicKey],
+    },
+  };
+
+  const { candyGuard: creatorCandyGuard, candyMachine: creatorCandyMachine } = await API.deploy(
+    t,
+    creatorData,
+    payerPair,
+    payerHandler,
+    payerConnection,
+  );
+
+  const [, creatorMint] = await amman.genLabeledKeypair('Mint Account (creator)');
+  const { tx: creatorMintTx } = await API.mint(
+    t,
+    creatorCandyGuard,
+    creatorCandyMachine,
+    minter,
+    creatorMint,
+    minterHandler,
+    minterConnection,
+    accounts,
+  );
+  await creatorMintTx.assertError(t, /NFT first creator is not verified or does not match/i);
+
+  // the update authority of the NFT
+
+  const authorityData = newCandyGuardData();
+  authorityData.default.nftMatchGate = {
+    required: {
+      __kind: 'UpdateAuthority',
+      fields: [nft.updateAuthorityAddress],
+    },
+  };
+
+  const { candyGuard: authorityCandyGuard, candyMachine: authorityCandyMachine } =
+    await API.deploy(t, authorityData, payerPair, payerHandler, payerConnection);
+
+  const [, authorityMint] = await amman.genLabeledKeypair('Mint Account (update authority)');
+  const { tx: authorityMintTx } = await API.mint(
+    t,
+    authorityCandyGuard,
+    authorityCandyMachine,
+    minter,
+    authorityMint,
+    minterHandler,
+    minterConnection,
+    accounts,
+  );
+  await authorityMintTx.assertSuccess(t);
+});
+
+test('nft match gate (empty collection list)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.nftMatchGate = {
+    required: {
+      __kind: 'Collections',
+      fields: [[]],
+    },
+  };
+
+  const { tx: transaction } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await transaction.assertError(t, /Collection list must contain between 1 and 5 collections/i);
+});
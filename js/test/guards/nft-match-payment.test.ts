@@ -0,0 +1,158 @@
+import test from 'tape';
+import { AccountMeta, Keypair, PublicKey } from '@solarti/web3.js';
+import { ASSOCIATED_TOKEN_PROGRAM_ID, getAccount, TOKEN_PROGRAM_ID } from '@solarti/spl-token';
+import { findMasterEditionV2Pda, Metaplex, keypairIdentity } from '@metaplex-solarti/js';
+import { CandyMachine } from '@metaplex-solarti/mpl-candy-machine-core';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('nft match payment', async (t) => {
+  const { fstTxHandler: payerHandler, payerPair, connection: payerConnection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1662479807,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    payerHandler,
+    payerConnection,
+  );
+
+  // mints 2 NFTs of the collection
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const nftMints: Keypair[] = [];
+
+  for (let i = 0; i < 2; i++) {
+    const [, nftMint] = await amman.genLabeledKeypair(`Mint Account (nft ${i})`);
+    const { tx: nftMintTx } = await API.mint(
+      t,
+      candyGuard,
+      candyMachine,
+      minter,
+      nftMint,
+      minterHandler,
+      minterConnection,
+    );
+    await nftMintTx.assertSuccess(t);
+    nftMints.push(nftMint);
+  }
+
+  const candyMachineObject = await CandyMachine.fromAccountAddress(payerConnection, candyMachine);
+  const metaplex = Metaplex.make(minterConnection).use(keypairIdentity(minter));
+  const destination = payerPair.publicKey;
+
+  const accounts: AccountMeta[][] = [];
+  const destinationAtas: PublicKey[] = [];
+
+  for (const nftMint of nftMints) {
+    const nft = await metaplex.nfts().findByMint({ mintAddress: nftMint.publicKey });
+    const [tokenAccount] = await PublicKey.findProgramAddress(
+      [minter.publicKey.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), nftMint.publicKey.toBuffer()],
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+    );
+    const [destinationAta] = await PublicKey.findProgramAddress(
+      [destination.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), nftMint.publicKey.toBuffer()],
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+    );
+    destinationAtas.push(destinationAta);
+
+    accounts.push([
+      // nft account
+      { pubkey: tokenAccount, isSigner: false, isWritable: true },
+      // nft metadata
+      { pubkey: nft.metadataAddress, isSigner: false, isWritable: true },
+      // nft mint
+      { pubkey: nftMint.publicKey, isSigner: false, isWritable: false },
+      // destination
+      { pubkey: destination, isSigner: false, isWritable: false },
+      // destination ATA
+      { pubkey: destinationAta, isSigner: false, isWritable: true },
+      // associate token program
+      { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      // nft master edition
+      { pubkey: findMasterEditionV2Pda(nftMint.publicKey), isSigner: false, isWritable: false },
+    ]);
+  }
+
+  // each mint costs 2 NFTs of the collection
+
+  const paymentData = newCandyGuardData();
+  paymentData.default.nftMatchPayment = {
+    required: {
+      __kind: 'Collections',
+      fields: [[candyMachineObject.collectionMint]],
+    },
+    destination,
+    amount: 2,
+  };
+
+  const { candyGuard: paymentCandyGuard, candyMachine: paymentCandyMachine } = await API.deploy(
+    t,
+    paymentData,
+    payerPair,
+    payerHandler,
+    payerConnection,
+  );
+
+  // the same NFT cannot be used twice
+  const [, duplicatedMint] = await amman.genLabeledKeypair('Mint Account (duplicated)');
+  const { tx: duplicatedMintTx } = await API.mint(
+    t,
+    paymentCandyGuard,
+    paymentCandyMachine,
+    minter,
+    duplicatedMint,
+    minterHandler,
+    minterConnection,
+    [...accounts[0], ...accounts[0]],
+  );
+  await duplicatedMintTx.assertError(t, /Duplicated NFT mint/i);
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    paymentCandyGuard,
+    paymentCandyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [...accounts[0], ...accounts[1]],
+  );
+  await minterMintTx.assertSuccess(t);
+
+  // the NFTs were transferred to the destination
+  for (const destinationAta of destinationAtas) {
+    const account = await getAccount(payerConnection, destinationAta);
+    t.equal(account.amount.toString(), '1', 'expected NFT in the destination');
+  }
+});
+
+test('nft match payment (invalid amount)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.nftMatchPayment = {
+    required: {
+      __kind: 'FirstCreator',
+      fields: [PublicKey.default],
+    },
+    destination: payerPair.publicKey,
+    amount: 0,
+  };
+
+  const { tx: transaction } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await transaction.assertError(t, /Invalid number of NFTs/i);
+});
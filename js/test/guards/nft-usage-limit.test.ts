@@ -0,0 +1,140 @@
+import test from 'tape';
+import { AccountMeta, PublicKey } from '@solarti/web3.js';
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solarti/spl-token';
+import { Metaplex, keypairIdentity } from '@metaplex-solarti/js';
+import { CandyMachine } from '@metaplex-solarti/mpl-candy-machine-core';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { PROGRAM_ID } from '../../src/generated';
+import { getNftUsageCounterPDA } from '../utils';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('nft usage limit', async (t) => {
+  const { fstTxHandler: payerHandler, payerPair, connection: payerConnection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1662479807,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    payerHandler,
+    payerConnection,
+  );
+
+  // mints an NFT of the collection
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, nftMint] = await amman.genLabeledKeypair('Mint Account (nft)');
+  const { tx: nftMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    nftMint,
+    minterHandler,
+    minterConnection,
+  );
+  await nftMintTx.assertSuccess(t);
+
+  // each NFT of the collection unlocks a single mint on a second candy machine
+
+  const candyMachineObject = await CandyMachine.fromAccountAddress(payerConnection, candyMachine);
+
+  const secondData = newCandyGuardData();
+  secondData.default.nftUsageLimit = {
+    requiredCollection: candyMachineObject.collectionMint,
+    limit: 1,
+  };
+
+  const { candyGuard: secondCandyGuard, candyMachine: secondCandyMachine } = await API.deploy(
+    t,
+    secondData,
+    payerPair,
+    payerHandler,
+    payerConnection,
+  );
+
+  const metaplex = Metaplex.make(minterConnection).use(keypairIdentity(minter));
+  const nft = await metaplex.nfts().findByMint({ mintAddress: nftMint.publicKey });
+
+  const [tokenAccount] = await PublicKey.findProgramAddress(
+    [minter.publicKey.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), nftMint.publicKey.toBuffer()],
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+  );
+  const counter = await getNftUsageCounterPDA(PROGRAM_ID, secondCandyGuard, nftMint.publicKey);
+
+  const accounts: AccountMeta[] = [
+    // token account
+    {
+      pubkey: tokenAccount,
+      isSigner: false,
+      isWritable: false,
+    },
+    // token metadata
+    {
+      pubkey: nft.metadataAddress,
+      isSigner: false,
+      isWritable: false,
+    },
+    // usage counter
+    {
+      pubkey: counter,
+      isSigner: false,
+      isWritable: true,
+    },
+  ];
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    secondCandyGuard,
+    secondCandyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    accounts,
+  );
+  await minterMintTx.assertSuccess(t);
+
+  const counterInfo = await minterConnection.getAccountInfo(counter);
+  t.equal(counterInfo?.data.readUInt16LE(0), 1, 'expected 1 use');
+
+  // the NFT was already used
+
+  const [, mintForMinter2] = await amman.genLabeledKeypair('Mint Account 2 (minter)');
+  const { tx: minterMintTx2 } = await API.mint(
+    t,
+    secondCandyGuard,
+    secondCandyMachine,
+    minter,
+    mintForMinter2,
+    minterHandler,
+    minterConnection,
+    accounts,
+  );
+  await minterMintTx2.assertError(t, /NFT has reached the maximum number of uses/i);
+});
+
+test('nft usage limit (invalid limit)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.nftUsageLimit = {
+    requiredCollection: PublicKey.default,
+    limit: 0,
+  };
+
+  const { tx: transaction } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await transaction.assertError(t, /Invalid NFT usage limit/i);
+});
@@ -0,0 +1,266 @@
+import test from 'tape';
+import {
+  Ed25519Program,
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from '@solarti/web3.js';
+import { BN } from 'bn.js';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { PROGRAM_ID } from '../../src/generated';
+import { getVoucherNoncePDA } from '../utils';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+function createVoucher(
+  signer: Keypair,
+  payer: PublicKey,
+  candyGuard: PublicKey,
+  nonce: number,
+  expiry: number,
+): TransactionInstruction {
+  // payer + candy guard + label (default guard set) + nonce + expiry
+  const message = Buffer.concat([
+    payer.toBuffer(),
+    candyGuard.toBuffer(),
+    Buffer.alloc(6),
+    new BN(nonce).toArrayLike(Buffer, 'le', 8),
+    new BN(expiry).toArrayLike(Buffer, 'le', 8),
+  ]);
+
+  return Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: signer.secretKey,
+    message,
+  });
+}
+
+test('signed voucher', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const signer = Keypair.generate();
+
+  const data = newCandyGuardData();
+  data.default.signedVoucher = {
+    signerKey: signer.publicKey,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const nonce = await getVoucherNoncePDA(PROGRAM_ID, candyGuard, 1);
+  const voucher = createVoucher(signer, minter.publicKey, candyGuard, 1, 32534611200);
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mintWithSignedVoucher(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    voucher,
+    [
+      {
+        pubkey: nonce,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+  await minterMintTx.assertSuccess(t);
+
+  const nonceInfo = await connection.getAccountInfo(nonce);
+  t.ok(nonceInfo, 'nonce marked as used');
+
+  // the same voucher cannot be used again
+
+  const [, secondMintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: replayTx } = await API.mintWithSignedVoucher(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    secondMintForMinter,
+    minterHandler,
+    minterConnection,
+    voucher,
+    [
+      {
+        pubkey: nonce,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+  await replayTx.assertError(t, /Signed voucher has already been used/i);
+});
+
+test('signed voucher (funded nonce address)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const signer = Keypair.generate();
+
+  const data = newCandyGuardData();
+  data.default.signedVoucher = {
+    signerKey: signer.publicKey,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const nonce = await getVoucherNoncePDA(PROGRAM_ID, candyGuard, 1);
+  const voucher = createVoucher(signer, minter.publicKey, candyGuard, 1, 32534611200);
+
+  // lamports sent to the nonce address before the voucher is used
+  const transferTx = new Transaction().add(
+    SystemProgram.transfer({
+      fromPubkey: payerPair.publicKey,
+      toPubkey: nonce,
+      lamports: 1000000,
+    }),
+  );
+  const transferHandler = fstTxHandler.sendAndConfirmTransaction(
+    transferTx,
+    [payerPair],
+    'tx: Transfer',
+  );
+  await transferHandler.assertSuccess(t);
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mintWithSignedVoucher(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    voucher,
+    [
+      {
+        pubkey: nonce,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+  await minterMintTx.assertSuccess(t);
+
+  const nonceInfo = await connection.getAccountInfo(nonce);
+  t.ok(nonceInfo?.owner.equals(PROGRAM_ID), 'nonce marked as used');
+});
+
+test('signed voucher (expired)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const signer = Keypair.generate();
+
+  const data = newCandyGuardData();
+  data.default.signedVoucher = {
+    signerKey: signer.publicKey,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const nonce = await getVoucherNoncePDA(PROGRAM_ID, candyGuard, 1);
+  const voucher = createVoucher(signer, minter.publicKey, candyGuard, 1, 1662479807);
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mintWithSignedVoucher(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    voucher,
+    [
+      {
+        pubkey: nonce,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+  await minterMintTx.assertError(t, /Signed voucher has expired/i);
+});
+
+test('signed voucher (invalid signer)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.signedVoucher = {
+    signerKey: Keypair.generate().publicKey,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const nonce = await getVoucherNoncePDA(PROGRAM_ID, candyGuard, 1);
+  // voucher signed by a key other than the signer key of the guard
+  const voucher = createVoucher(Keypair.generate(), minter.publicKey, candyGuard, 1, 32534611200);
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mintWithSignedVoucher(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    voucher,
+    [
+      {
+        pubkey: nonce,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+  await minterMintTx.assertError(t, /Missing signed voucher/i);
+});
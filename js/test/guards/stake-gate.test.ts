@@ -0,0 +1,259 @@
+import test from 'tape';
+import { AccountMeta, Keypair, PublicKey } from '@solarti/web3.js';
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAccount,
+  createMint,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+} from '@solarti/spl-token';
+import { findMetadataPda, Metaplex, keypairIdentity } from '@metaplex-solarti/js';
+import { CandyMachine } from '@metaplex-solarti/mpl-candy-machine-core';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('stake gate (token)', async (t) => {
+  const {
+    fstTxHandler: authorityHandler,
+    authorityPair: authority,
+    connection: authorityConnection,
+  } = await API.authority();
+
+  const tokenMint = await createMint(authorityConnection, authority, authority.publicKey, null, 0);
+
+  // a token account is used as the stake record: the mint (also used as the
+  // discriminator) is at offset 0, the owner at 32 and the amount at 64
+
+  const data = newCandyGuardData();
+  data.default.stakeGate = {
+    asset: {
+      __kind: 'Token',
+      mint: tokenMint,
+      stake_key: tokenMint,
+      amount_offset: 64,
+    },
+    amount: 5,
+    stakeProgram: TOKEN_PROGRAM_ID,
+    discriminator: tokenMint.toBuffer().subarray(0, 8),
+    ownerOffset: 32,
+    stakeKeyOffset: 0,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    authority,
+    authorityHandler,
+    authorityConnection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+
+  const wallet = await createAccount(
+    minterConnection,
+    minter,
+    tokenMint,
+    minter.publicKey,
+    Keypair.generate(),
+  );
+  await mintTo(authorityConnection, authority, tokenMint, wallet, authority.publicKey, 3);
+  const staked = await createAccount(
+    minterConnection,
+    minter,
+    tokenMint,
+    minter.publicKey,
+    Keypair.generate(),
+  );
+  await mintTo(authorityConnection, authority, tokenMint, staked, authority.publicKey, 2);
+
+  const accounts = [wallet, staked].map((pubkey) => ({
+    pubkey,
+    isSigner: false,
+    isWritable: false,
+  }));
+
+  // at least one account is required
+  const [, zeroCountMint] = await amman.genLabeledKeypair('Mint Account (zero count)');
+  const { tx: zeroCountTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    zeroCountMint,
+    minterHandler,
+    minterConnection,
+    accounts,
+    Uint8Array.from([0, 0]),
+  );
+  await zeroCountTx.assertError(t, /Invalid number of token accounts/i);
+
+  // the wallet balance alone is not enough
+  const [, walletMint] = await amman.genLabeledKeypair('Mint Account (wallet)');
+  const { tx: walletTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    walletMint,
+    minterHandler,
+    minterConnection,
+    accounts,
+    Uint8Array.from([1, 0]),
+  );
+  await walletTx.assertError(t, /Not enough tokens on the account/i);
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    accounts,
+    Uint8Array.from([1, 1]),
+  );
+  await minterMintTx.assertSuccess(t);
+});
+
+test('stake gate (collection)', async (t) => {
+  const { fstTxHandler: payerHandler, payerPair, connection: payerConnection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1662479807,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    payerHandler,
+    payerConnection,
+  );
+
+  // mints an NFT of the collection
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, nftMint] = await amman.genLabeledKeypair('Mint Account (nft)');
+  const { tx: nftMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    nftMint,
+    minterHandler,
+    minterConnection,
+  );
+  await nftMintTx.assertSuccess(t);
+
+  const candyMachineObject = await CandyMachine.fromAccountAddress(payerConnection, candyMachine);
+  const metaplex = Metaplex.make(minterConnection).use(keypairIdentity(minter));
+  const nft = await metaplex.nfts().findByMint({ mintAddress: nftMint.publicKey });
+
+  // the token account of the NFT is used as the stake record: the mint of the
+  // NFT (also used as the discriminator) is at offset 0 and the owner at 32
+
+  const [tokenAccount] = await PublicKey.findProgramAddress(
+    [minter.publicKey.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), nftMint.publicKey.toBuffer()],
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+  );
+  const accounts: AccountMeta[] = [tokenAccount, nft.metadataAddress].map((pubkey) => ({
+    pubkey,
+    isSigner: false,
+    isWritable: false,
+  }));
+
+  const gatedData = newCandyGuardData();
+  gatedData.default.stakeGate = {
+    asset: {
+      __kind: 'Collection',
+      collection: candyMachineObject.collectionMint,
+    },
+    amount: 1,
+    stakeProgram: TOKEN_PROGRAM_ID,
+    discriminator: nftMint.publicKey.toBuffer().subarray(0, 8),
+    ownerOffset: 32,
+    stakeKeyOffset: 0,
+  };
+
+  const { candyGuard: gatedCandyGuard, candyMachine: gatedCandyMachine } = await API.deploy(
+    t,
+    gatedData,
+    payerPair,
+    payerHandler,
+    payerConnection,
+  );
+
+  // NFT held in the wallet
+  const [, walletMint] = await amman.genLabeledKeypair('Mint Account (wallet)');
+  const { tx: walletTx } = await API.mint(
+    t,
+    gatedCandyGuard,
+    gatedCandyMachine,
+    minter,
+    walletMint,
+    minterHandler,
+    minterConnection,
+    accounts,
+    Uint8Array.from([1, 0]),
+  );
+  await walletTx.assertSuccess(t);
+
+  // staked NFT
+  const [, stakedMint] = await amman.genLabeledKeypair('Mint Account (staked)');
+  const { tx: stakedTx } = await API.mint(
+    t,
+    gatedCandyGuard,
+    gatedCandyMachine,
+    minter,
+    stakedMint,
+    minterHandler,
+    minterConnection,
+    accounts,
+    Uint8Array.from([0, 1]),
+  );
+  await stakedTx.assertSuccess(t);
+
+  // the same NFT cannot be counted twice
+  const [, duplicatedMint] = await amman.genLabeledKeypair('Mint Account (duplicated)');
+  const { tx: duplicatedTx } = await API.mint(
+    t,
+    gatedCandyGuard,
+    gatedCandyMachine,
+    minter,
+    duplicatedMint,
+    minterHandler,
+    minterConnection,
+    [...accounts, ...accounts],
+    Uint8Array.from([1, 1]),
+  );
+  await duplicatedTx.assertError(t, /Duplicated token account/i);
+
+  // metadata of an NFT (the collection) that does not match the stake record
+  const [, otherMint] = await amman.genLabeledKeypair('Mint Account (other)');
+  const { tx: otherTx } = await API.mint(
+    t,
+    gatedCandyGuard,
+    gatedCandyMachine,
+    minter,
+    otherMint,
+    minterHandler,
+    minterConnection,
+    [accounts[0], { ...accounts[1], pubkey: findMetadataPda(nft.collection!.address) }],
+    Uint8Array.from([0, 1]),
+  );
+  await otherTx.assertError(t, /Invalid stake record/i);
+});
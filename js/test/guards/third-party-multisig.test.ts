@@ -0,0 +1,141 @@
+import test from 'tape';
+import { Keypair } from '@solarti/web3.js';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('third party multisig', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const signers = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+
+  const data = newCandyGuardData();
+  data.default.thirdPartyMultisig = {
+    signerKeys: signers.map((signer) => signer.publicKey),
+    threshold: 2,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+
+  // any 2 of the 3 signers
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mintWithSigners(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [signers[2], signers[0]],
+  );
+  await minterMintTx.assertSuccess(t);
+
+  // the same signer only counts once
+
+  const [, duplicatedMint] = await amman.genLabeledKeypair('Mint Account (duplicated)');
+  const { tx: duplicatedTx } = await API.mintWithSigners(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    duplicatedMint,
+    minterHandler,
+    minterConnection,
+    [signers[1], signers[1]],
+  );
+  await duplicatedTx.assertError(t, /A signature was required but not found/i);
+
+  // below the threshold
+
+  const [, singleMint] = await amman.genLabeledKeypair('Mint Account (single)');
+  const { tx: singleTx } = await API.mintWithSigners(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    singleMint,
+    minterHandler,
+    minterConnection,
+    [signers[1]],
+  );
+  await singleTx.assertError(t, /Missing expected remaining account/i);
+});
+
+test('third party multisig (unknown signer)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const signers = [Keypair.generate(), Keypair.generate()];
+
+  const data = newCandyGuardData();
+  data.default.thirdPartyMultisig = {
+    signerKeys: signers.map((signer) => signer.publicKey),
+    threshold: 1,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mintWithSigners(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [Keypair.generate()],
+  );
+  await minterMintTx.assertError(t, /A signature was required but not found/i);
+});
+
+test('third party multisig (invalid threshold)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.thirdPartyMultisig = {
+    signerKeys: [Keypair.generate().publicKey, Keypair.generate().publicKey],
+    threshold: 3,
+  };
+
+  const { tx: transaction } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await transaction.assertError(t, /Signer threshold must be between 1 and the number of signer/i);
+});
+
+test('third party multisig (duplicated signer key)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+  const signer = Keypair.generate().publicKey;
+
+  const data = newCandyGuardData();
+  data.default.thirdPartyMultisig = {
+    signerKeys: [signer, signer],
+    threshold: 1,
+  };
+
+  const { tx: transaction } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await transaction.assertError(t, /Duplicated key in the signer list/i);
+});
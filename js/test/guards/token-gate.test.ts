@@ -0,0 +1,252 @@
+import test from 'tape';
+import { Keypair } from '@solarti/web3.js';
+import { createAccount, createMint, mintTo } from '@solarti/spl-token';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('token gate (non-associated token account)', async (t) => {
+  const {
+    fstTxHandler: authorityHandler,
+    authorityPair: authority,
+    connection: authorityConnection,
+  } = await API.authority();
+
+  const tokenMint = await createMint(authorityConnection, authority, authority.publicKey, null, 0);
+
+  const data = newCandyGuardData();
+  data.default.tokenGate = {
+    amount: 5,
+    mint: tokenMint,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    authority,
+    authorityHandler,
+    authorityConnection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+
+  // token account created with a keypair (not the associated token account)
+  const tokenAccount = await createAccount(
+    minterConnection,
+    minter,
+    tokenMint,
+    minter.publicKey,
+    Keypair.generate(),
+  );
+  await mintTo(authorityConnection, authority, tokenMint, tokenAccount, authority.publicKey, 5);
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: tokenAccount,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+  await minterMintTx.assertSuccess(t);
+});
+
+test('token gate (token account of another wallet)', async (t) => {
+  const {
+    fstTxHandler: authorityHandler,
+    authorityPair: authority,
+    connection: authorityConnection,
+  } = await API.authority();
+
+  const tokenMint = await createMint(authorityConnection, authority, authority.publicKey, null, 0);
+
+  const data = newCandyGuardData();
+  data.default.tokenGate = {
+    amount: 5,
+    mint: tokenMint,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    authority,
+    authorityHandler,
+    authorityConnection,
+  );
+
+  // the tokens are held by the authority
+  const tokenAccount = await createAccount(
+    authorityConnection,
+    authority,
+    tokenMint,
+    authority.publicKey,
+    Keypair.generate(),
+  );
+  await mintTo(authorityConnection, authority, tokenMint, tokenAccount, authority.publicKey, 5);
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: tokenAccount,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+  await minterMintTx.assertError(t, /Public key mismatch/i);
+});
+
+test('token gate (multiple token accounts)', async (t) => {
+  const {
+    fstTxHandler: authorityHandler,
+    authorityPair: authority,
+    connection: authorityConnection,
+  } = await API.authority();
+
+  const tokenMint = await createMint(authorityConnection, authority, authority.publicKey, null, 0);
+
+  const data = newCandyGuardData();
+  data.default.tokenGate = {
+    amount: 5,
+    mint: tokenMint,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    authority,
+    authorityHandler,
+    authorityConnection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+
+  // the balance is split across two token accounts
+  const first = await createAccount(
+    minterConnection,
+    minter,
+    tokenMint,
+    minter.publicKey,
+    Keypair.generate(),
+  );
+  await mintTo(authorityConnection, authority, tokenMint, first, authority.publicKey, 3);
+  const second = await createAccount(
+    minterConnection,
+    minter,
+    tokenMint,
+    minter.publicKey,
+    Keypair.generate(),
+  );
+  await mintTo(authorityConnection, authority, tokenMint, second, authority.publicKey, 2);
+
+  const accounts = [first, second].map((pubkey) => ({
+    pubkey,
+    isSigner: false,
+    isWritable: false,
+  }));
+
+  // without the count, a single token account is used
+  const [, missingCountMint] = await amman.genLabeledKeypair('Mint Account (missing count)');
+  const { tx: missingCountTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    missingCountMint,
+    minterHandler,
+    minterConnection,
+    accounts,
+  );
+  await missingCountTx.assertError(t, /Not enough tokens on the account/i);
+
+  // at least one token account is required
+  const [, zeroCountMint] = await amman.genLabeledKeypair('Mint Account (zero count)');
+  const { tx: zeroCountTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    zeroCountMint,
+    minterHandler,
+    minterConnection,
+    accounts,
+    Uint8Array.from([0]),
+  );
+  await zeroCountTx.assertError(t, /Invalid number of token accounts/i);
+
+  // the same token account only counts once
+  const [, duplicatedMint] = await amman.genLabeledKeypair('Mint Account (duplicated)');
+  const { tx: duplicatedTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    duplicatedMint,
+    minterHandler,
+    minterConnection,
+    [accounts[0], accounts[0]],
+    Uint8Array.from([2]),
+  );
+  await duplicatedTx.assertError(t, /Duplicated token account/i);
+
+  // a single account does not hold enough tokens
+  const [, singleMint] = await amman.genLabeledKeypair('Mint Account (single)');
+  const { tx: singleTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    singleMint,
+    minterHandler,
+    minterConnection,
+    accounts,
+    Uint8Array.from([1]),
+  );
+  await singleTx.assertError(t, /Not enough tokens on the account/i);
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    accounts,
+    Uint8Array.from([2]),
+  );
+  await minterMintTx.assertSuccess(t);
+});
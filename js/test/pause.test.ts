@@ -0,0 +1,96 @@
+import test from 'tape';
+import spok from 'spok';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from './setup';
+import { DATA_OFFSET } from './utils';
+import { deserialize } from '../src';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('Pause: mint is blocked until resumed', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1662479807,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const { tx: pauseTx } = await API.pause(t, candyGuard, 7, [], payerPair, fstTxHandler);
+  await pauseTx.assertSuccess(t);
+
+  let accountInfo = await connection.getAccountInfo(candyGuard);
+  let candyGuardData = deserialize(accountInfo!.data.subarray(DATA_OFFSET));
+  spok(t, candyGuardData.default.pause, {
+    reason: 7,
+  });
+
+  const [, mintPaused] = await amman.genLabeledKeypair('Mint Account (paused)');
+  const { tx: pausedTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    payerPair,
+    mintPaused,
+    fstTxHandler,
+    connection,
+  );
+  await pausedTx.assertError(t, /Mint is paused/i);
+
+  // the pause is preserved by the update instruction
+
+  const { tx: updateTx } = await API.update(t, candyGuard, data, payerPair, fstTxHandler);
+  await updateTx.assertSuccess(t);
+
+  accountInfo = await connection.getAccountInfo(candyGuard);
+  candyGuardData = deserialize(accountInfo!.data.subarray(DATA_OFFSET));
+  t.true(candyGuardData.default.pause !== null, 'pause must be preserved');
+
+  const { tx: resumeTx } = await API.resume(t, candyGuard, payerPair, fstTxHandler);
+  await resumeTx.assertSuccess(t);
+
+  accountInfo = await connection.getAccountInfo(candyGuard);
+  candyGuardData = deserialize(accountInfo!.data.subarray(DATA_OFFSET));
+  t.true(candyGuardData.default.pause === null, 'pause must be removed');
+
+  const [, mintResumed] = await amman.genLabeledKeypair('Mint Account (resumed)');
+  const { tx: resumedTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    payerPair,
+    mintResumed,
+    fstTxHandler,
+    connection,
+  );
+  await resumedTx.assertSuccess(t);
+});
+
+test('Pause: allowed while the configuration is locked', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  const { tx: initTx, candyGuard } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    payerPair,
+    fstTxHandler,
+  );
+  await initTx.assertSuccess(t);
+
+  const { tx: lockTx } = await API.lockConfig(t, candyGuard, null, payerPair, fstTxHandler);
+  await lockTx.assertSuccess(t);
+
+  const { tx: pauseTx } = await API.pause(t, candyGuard, null, [], payerPair, fstTxHandler);
+  await pauseTx.assertSuccess(t);
+
+  const { tx: resumeTx } = await API.resume(t, candyGuard, payerPair, fstTxHandler);
+  await resumeTx.assertSuccess(t);
+});
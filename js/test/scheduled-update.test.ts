@@ -0,0 +1,174 @@
+import test from 'tape';
+import { AccountMeta } from '@solarti/web3.js';
+import {
+  amman,
+  InitTransactions,
+  killStuckProcess,
+  newCandyGuardData,
+  newGuardSet,
+  sleep,
+} from './setup';
+import { serialize } from '../src';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('Scheduled update: a new first group requires a label before and after apply', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1662479807,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // schedules a new group on a configuration without groups
+
+  const group = newGuardSet();
+  group.startDate = {
+    date: 1662479807,
+  };
+  // the guard set bytes are followed by the (u32) number of groups
+  const guards = serialize({ default: group, groups: null });
+  const groupData = guards.subarray(0, guards.length - 4);
+
+  const slot = await connection.getSlot();
+  const activation = (await connection.getBlockTime(slot))! + 3;
+
+  const { tx: scheduleTx, scheduledUpdate } = await API.scheduleUpdate(
+    t,
+    candyGuard,
+    activation,
+    'VIP',
+    groupData,
+    payerPair,
+    fstTxHandler,
+  );
+  await scheduleTx.assertSuccess(t);
+
+  // waits for the activation time
+  await sleep(6000);
+
+  const remainingAccounts: AccountMeta[] = [
+    {
+      pubkey: scheduledUpdate,
+      isSigner: false,
+      isWritable: false,
+    },
+  ];
+
+  // mint without a label (before apply)
+
+  const [, mintBeforeApply] = await amman.genLabeledKeypair('Mint Account (before apply)');
+  const { tx: beforeApplyTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    payerPair,
+    mintBeforeApply,
+    fstTxHandler,
+    connection,
+    remainingAccounts,
+  );
+  await beforeApplyTx.assertError(t, /Missing required group label/i);
+
+  // mint with the scheduled label
+
+  const [, mintForGroup] = await amman.genLabeledKeypair('Mint Account (group)');
+  const { tx: groupTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    payerPair,
+    mintForGroup,
+    fstTxHandler,
+    connection,
+    remainingAccounts,
+    null,
+    'VIP',
+  );
+  await groupTx.assertSuccess(t);
+
+  // applies the update
+
+  const { tx: applyTx } = await API.applyScheduledUpdate(t, candyGuard, payerPair, fstTxHandler);
+  await applyTx.assertSuccess(t);
+
+  // mint without a label (after apply)
+
+  const [, mintAfterApply] = await amman.genLabeledKeypair('Mint Account (after apply)');
+  const { tx: afterApplyTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    payerPair,
+    mintAfterApply,
+    fstTxHandler,
+    connection,
+  );
+  await afterApplyTx.assertError(t, /Missing required group label/i);
+});
+
+test('Scheduled update: cancel keeps the current configuration', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1662479807,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // schedules a start date in the future
+
+  const scheduled = newCandyGuardData();
+  scheduled.default.startDate = {
+    date: 1671926400,
+  };
+
+  const slot = await connection.getSlot();
+  const activation = (await connection.getBlockTime(slot))! + 60;
+
+  const { tx: scheduleTx, scheduledUpdate } = await API.scheduleUpdate(
+    t,
+    candyGuard,
+    activation,
+    null,
+    serialize(scheduled),
+    payerPair,
+    fstTxHandler,
+  );
+  await scheduleTx.assertSuccess(t);
+
+  const { tx: cancelTx } = await API.cancelScheduledUpdate(t, candyGuard, payerPair, fstTxHandler);
+  await cancelTx.assertSuccess(t);
+
+  const scheduledInfo = await connection.getAccountInfo(scheduledUpdate);
+  t.true(scheduledInfo === null, 'scheduled update account must be closed');
+
+  const [, mintForAuthority] = await amman.genLabeledKeypair('Mint Account (authority)');
+  const { tx: mintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    payerPair,
+    mintForAuthority,
+    fstTxHandler,
+    connection,
+  );
+  await mintTx.assertSuccess(t);
+});
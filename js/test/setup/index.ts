@@ -33,6 +33,21 @@ export function newGuardSet(): GuardSet {
     freezeSolPayment: null,
     freezeTokenPayment: null,
     programGate: null,
+    pendingUpdate: null,
+    configLock: null,
+    pause: null,
+    mintReceipt: null,
+    botTaxPolicy: null,
+    instructionGate: null,
+    signedVoucher: null,
+    thirdPartyMultisig: null,
+    stakeGate: null,
+    nftUsageLimit: null,
+    nftMatchGate: null,
+    nftMatchBurn: null,
+    nftMatchPayment: null,
+    editionBurn: null,
+    multiTokenPayment: null,
   };
 }
 
@@ -23,30 +23,69 @@ import { amman } from '.';
 import {
   CANDY_MACHINE_PROGRAM,
   CandyMachineHelper,
+  getAuthorityProposalPDA,
   getCandyGuardPDA,
+  getDelegatePDA,
+  getScheduledUpdatePDA,
+  getUpdateBufferPDA,
   METAPLEX_PROGRAM_ID,
 } from '../utils';
 import {
+  AcceptAuthorityInstructionAccounts,
+  ApplyScheduledUpdateInstructionAccounts,
+  CancelAuthorityProposalInstructionAccounts,
+  CancelScheduledUpdateInstructionAccounts,
   CandyGuardData,
+  CloseBufferInstructionAccounts,
+  CommitBufferInstructionAccounts,
+  createAcceptAuthorityInstruction,
+  createApplyScheduledUpdateInstruction,
+  createCancelAuthorityProposalInstruction,
+  createCancelScheduledUpdateInstruction,
+  createCloseBufferInstruction,
+  createCommitBufferInstruction,
   createInitializeInstruction,
+  createLockConfigInstruction,
   createMintInstruction,
+  createPauseInstruction,
+  createProposeAuthorityInstruction,
+  createResumeInstruction,
+  createRevokeDelegateInstruction,
+  createScheduleUpdateInstruction,
   createSetAuthorityInstruction,
+  createSetDelegateInstruction,
   createUnwrapInstruction,
   createUpdateInstruction,
   createWithdrawInstruction,
   createWrapInstruction,
+  createWriteBufferInstruction,
+  GuardType,
   InitializeInstructionAccounts,
   InitializeInstructionArgs,
+  LockConfigInstructionAccounts,
+  LockConfigInstructionArgs,
   MintInstructionAccounts,
   MintInstructionArgs,
+  PauseInstructionAccounts,
+  PauseInstructionArgs,
   PROGRAM_ID,
+  ProposeAuthorityInstructionAccounts,
+  ProposeAuthorityInstructionArgs,
+  ResumeInstructionAccounts,
+  RevokeDelegateInstructionAccounts,
+  ScheduleUpdateInstructionAccounts,
+  ScheduleUpdateInstructionArgs,
   SetAuthorityInstructionAccounts,
   SetAuthorityInstructionArgs,
+  SetDelegateInstructionAccounts,
+  SetDelegateInstructionArgs,
   UnwrapInstructionAccounts,
   UpdateInstructionAccounts,
   UpdateInstructionArgs,
   WithdrawInstructionAccounts,
   WrapInstructionAccounts,
+  WriteBufferInstructionAccounts,
+  WriteBufferInstructionArgs,
 } from '../../src/generated';
 import { CandyMachine } from '@metaplex-solarti/mpl-candy-machine-core';
 import {
@@ -208,6 +247,7 @@ export class InitTransactions {
     data: CandyGuardData,
     payer: Keypair,
     handler: PayerTransactionHandler,
+    remainingAccounts?: AccountMeta[] | null,
   ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
     const accounts: UpdateInstructionAccounts = {
       candyGuard,
@@ -220,7 +260,12 @@ export class InitTransactions {
       data: serialize(data),
     };
 
-    const tx = new Transaction().add(createUpdateInstruction(accounts, args));
+    const updateIx = createUpdateInstruction(accounts, args);
+    if (remainingAccounts) {
+      updateIx.keys.push(...remainingAccounts);
+    }
+
+    const tx = new Transaction().add(updateIx);
 
     return {
       tx: handler.sendAndConfirmTransaction(tx, [payer], 'tx: Update'),
@@ -250,6 +295,346 @@ export class InitTransactions {
     };
   }
 
+  async proposeAuthority(
+    t: Test,
+    candyGuard: PublicKey,
+    authority: Keypair,
+    newAuthority: PublicKey,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise; proposal: PublicKey }> {
+    const proposal = await getAuthorityProposalPDA(PROGRAM_ID, candyGuard);
+    amman.addr.addLabel('Authority Proposal Account', proposal);
+
+    const accounts: ProposeAuthorityInstructionAccounts = {
+      candyGuard,
+      proposal,
+      authority: authority.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const args: ProposeAuthorityInstructionArgs = {
+      newAuthority,
+    };
+
+    const tx = new Transaction().add(createProposeAuthorityInstruction(accounts, args));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: ProposeAuthority'),
+      proposal,
+    };
+  }
+
+  async acceptAuthority(
+    t: Test,
+    candyGuard: PublicKey,
+    authority: PublicKey,
+    newAuthority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: AcceptAuthorityInstructionAccounts = {
+      candyGuard,
+      proposal: await getAuthorityProposalPDA(PROGRAM_ID, candyGuard),
+      authority,
+      newAuthority: newAuthority.publicKey,
+    };
+
+    const tx = new Transaction().add(createAcceptAuthorityInstruction(accounts));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [newAuthority], 'tx: AcceptAuthority'),
+    };
+  }
+
+  async cancelAuthorityProposal(
+    t: Test,
+    candyGuard: PublicKey,
+    authority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: CancelAuthorityProposalInstructionAccounts = {
+      candyGuard,
+      proposal: await getAuthorityProposalPDA(PROGRAM_ID, candyGuard),
+      authority: authority.publicKey,
+    };
+
+    const tx = new Transaction().add(createCancelAuthorityProposalInstruction(accounts));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: CancelAuthorityProposal'),
+    };
+  }
+
+  async lockConfig(
+    t: Test,
+    candyGuard: PublicKey,
+    until: number | null,
+    payer: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: LockConfigInstructionAccounts = {
+      candyGuard,
+      authority: payer.publicKey,
+      payer: payer.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const args: LockConfigInstructionArgs = {
+      until,
+    };
+
+    const tx = new Transaction().add(createLockConfigInstruction(accounts, args));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [payer], 'tx: LockConfig'),
+    };
+  }
+
+  async pause(
+    t: Test,
+    candyGuard: PublicKey,
+    reason: number | null,
+    routes: GuardType[],
+    payer: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: PauseInstructionAccounts = {
+      candyGuard,
+      authority: payer.publicKey,
+      payer: payer.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const args: PauseInstructionArgs = {
+      reason,
+      routes,
+    };
+
+    const tx = new Transaction().add(createPauseInstruction(accounts, args));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [payer], 'tx: Pause'),
+    };
+  }
+
+  async resume(
+    t: Test,
+    candyGuard: PublicKey,
+    payer: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: ResumeInstructionAccounts = {
+      candyGuard,
+      authority: payer.publicKey,
+      payer: payer.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const tx = new Transaction().add(createResumeInstruction(accounts));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [payer], 'tx: Resume'),
+    };
+  }
+
+  async setDelegate(
+    t: Test,
+    candyGuard: PublicKey,
+    delegate: PublicKey,
+    roles: number,
+    authority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise; delegateRecord: PublicKey }> {
+    const delegateRecord = await getDelegatePDA(PROGRAM_ID, candyGuard, delegate);
+    amman.addr.addLabel('Delegate Record Account', delegateRecord);
+
+    const accounts: SetDelegateInstructionAccounts = {
+      candyGuard,
+      delegateRecord,
+      authority: authority.publicKey,
+      payer: authority.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const args: SetDelegateInstructionArgs = {
+      delegate,
+      roles,
+    };
+
+    const tx = new Transaction().add(createSetDelegateInstruction(accounts, args));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: SetDelegate'),
+      delegateRecord,
+    };
+  }
+
+  async revokeDelegate(
+    t: Test,
+    candyGuard: PublicKey,
+    delegate: PublicKey,
+    authority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: RevokeDelegateInstructionAccounts = {
+      candyGuard,
+      delegateRecord: await getDelegatePDA(PROGRAM_ID, candyGuard, delegate),
+      authority: authority.publicKey,
+    };
+
+    const tx = new Transaction().add(createRevokeDelegateInstruction(accounts));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: RevokeDelegate'),
+    };
+  }
+
+  async writeBuffer(
+    t: Test,
+    candyGuard: PublicKey,
+    offset: number,
+    data: Uint8Array,
+    payer: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise; buffer: PublicKey }> {
+    const buffer = await getUpdateBufferPDA(PROGRAM_ID, candyGuard);
+    amman.addr.addLabel('Update Buffer Account', buffer);
+
+    const accounts: WriteBufferInstructionAccounts = {
+      candyGuard,
+      buffer,
+      authority: payer.publicKey,
+      payer: payer.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const args: WriteBufferInstructionArgs = {
+      offset,
+      data,
+    };
+
+    const tx = new Transaction().add(createWriteBufferInstruction(accounts, args));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [payer], 'tx: WriteBuffer'),
+      buffer,
+    };
+  }
+
+  async commitBuffer(
+    t: Test,
+    candyGuard: PublicKey,
+    payer: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: CommitBufferInstructionAccounts = {
+      candyGuard,
+      buffer: await getUpdateBufferPDA(PROGRAM_ID, candyGuard),
+      authority: payer.publicKey,
+      payer: payer.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const tx = new Transaction().add(createCommitBufferInstruction(accounts));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [payer], 'tx: CommitBuffer'),
+    };
+  }
+
+  async closeBuffer(
+    t: Test,
+    candyGuard: PublicKey,
+    payer: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: CloseBufferInstructionAccounts = {
+      candyGuard,
+      buffer: await getUpdateBufferPDA(PROGRAM_ID, candyGuard),
+      authority: payer.publicKey,
+    };
+
+    const tx = new Transaction().add(createCloseBufferInstruction(accounts));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [payer], 'tx: CloseBuffer'),
+    };
+  }
+
+  async scheduleUpdate(
+    t: Test,
+    candyGuard: PublicKey,
+    activation: number,
+    label: string | null,
+    data: Uint8Array,
+    payer: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise; scheduledUpdate: PublicKey }> {
+    const scheduledUpdate = await getScheduledUpdatePDA(PROGRAM_ID, candyGuard);
+    amman.addr.addLabel('Scheduled Update Account', scheduledUpdate);
+
+    const accounts: ScheduleUpdateInstructionAccounts = {
+      candyGuard,
+      scheduledUpdate,
+      authority: payer.publicKey,
+      payer: payer.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const args: ScheduleUpdateInstructionArgs = {
+      activation,
+      label,
+      data,
+    };
+
+    const tx = new Transaction().add(createScheduleUpdateInstruction(accounts, args));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [payer], 'tx: ScheduleUpdate'),
+      scheduledUpdate,
+    };
+  }
+
+  async applyScheduledUpdate(
+    t: Test,
+    candyGuard: PublicKey,
+    payer: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: ApplyScheduledUpdateInstructionAccounts = {
+      candyGuard,
+      scheduledUpdate: await getScheduledUpdatePDA(PROGRAM_ID, candyGuard),
+      authority: payer.publicKey,
+      payer: payer.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const tx = new Transaction().add(createApplyScheduledUpdateInstruction(accounts));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [payer], 'tx: ApplyScheduledUpdate'),
+    };
+  }
+
+  async cancelScheduledUpdate(
+    t: Test,
+    candyGuard: PublicKey,
+    payer: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: CancelScheduledUpdateInstructionAccounts = {
+      candyGuard,
+      scheduledUpdate: await getScheduledUpdatePDA(PROGRAM_ID, candyGuard),
+      authority: payer.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const tx = new Transaction().add(createCancelScheduledUpdateInstruction(accounts));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [payer], 'tx: CancelScheduledUpdate'),
+    };
+  }
+
   async mint(
     t: Test,
     candyGuard: PublicKey,
@@ -389,6 +774,81 @@ export class InitTransactions {
     };
   }
 
+  async mintWithSignedVoucher(
+    t: Test,
+    candyGuard: PublicKey,
+    candyMachine: PublicKey,
+    payer: Keypair,
+    mint: Keypair,
+    handler: PayerTransactionHandler,
+    connection: Connection,
+    voucher: TransactionInstruction,
+    remainingAccounts?: AccountMeta[] | null,
+    label?: string | null,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const { instructions } = await this.mintInstruction(
+      t,
+      candyGuard,
+      candyMachine,
+      payer,
+      mint,
+      handler,
+      connection,
+      remainingAccounts,
+      null,
+      label,
+    );
+
+    const tx = new Transaction().add(voucher, ...instructions);
+
+    return {
+      tx: handler.sendAndConfirmTransaction(
+        tx,
+        [payer, mint],
+        'tx: Candy Guard Mint (signed voucher)',
+      ),
+    };
+  }
+
+  async mintWithSigners(
+    t: Test,
+    candyGuard: PublicKey,
+    candyMachine: PublicKey,
+    payer: Keypair,
+    mint: Keypair,
+    handler: PayerTransactionHandler,
+    connection: Connection,
+    signers: Keypair[],
+    label?: string | null,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const { instructions } = await this.mintInstruction(
+      t,
+      candyGuard,
+      candyMachine,
+      payer,
+      mint,
+      handler,
+      connection,
+      signers.map((signer) => ({
+        pubkey: signer.publicKey,
+        isSigner: true,
+        isWritable: false,
+      })),
+      null,
+      label,
+    );
+
+    const tx = new Transaction().add(...instructions);
+
+    return {
+      tx: handler.sendAndConfirmTransaction(
+        tx,
+        [payer, mint, ...signers],
+        'tx: Candy Guard Mint (signers)',
+      ),
+    };
+  }
+
   async deploy(
     t: Test,
     guards: CandyGuardData,
@@ -288,3 +288,36 @@ test('Update: disable all', async (t) => {
 
   spok(t, candyGuardData, disabled);
 });
+
+test('Update: not allowed while the configuration is locked', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const { tx: transaction, candyGuard: address } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    payerPair,
+    fstTxHandler,
+  );
+  await transaction.assertSuccess(t);
+
+  const { tx: lockTransaction } = await API.lockConfig(t, address, null, payerPair, fstTxHandler);
+  await lockTransaction.assertSuccess(t);
+
+  const accountInfo = await connection.getAccountInfo(address);
+  const candyGuardData = deserialize(accountInfo!.data.subarray(DATA_OFFSET));
+  spok(t, candyGuardData.default.configLock?.until, spokSameBignum(new BN('9223372036854775807')));
+
+  const updateData = newCandyGuardData();
+  updateData.default.startDate = {
+    date: 1663965742,
+  };
+
+  const { tx: updateTransaction } = await API.update(
+    t,
+    address,
+    updateData,
+    payerPair,
+    fstTxHandler,
+  );
+  await updateTransaction.assertError(t, /Configuration is locked/i);
+});
@@ -0,0 +1,17 @@
+import { createHash } from 'crypto';
+
+const PROGRAM_DATA = 'Program data: ';
+
+/**
+ * Returns the data of the events with the specified name emitted in the
+ * transaction logs (without the 8-byte event discriminator).
+ */
+export function findEvents(logMessages: string[], name: string): Buffer[] {
+  const discriminator = createHash('sha256').update(`event:${name}`).digest().subarray(0, 8);
+
+  return logMessages
+    .filter((log) => log.startsWith(PROGRAM_DATA))
+    .map((log) => Buffer.from(log.slice(PROGRAM_DATA.length), 'base64'))
+    .filter((data) => data.subarray(0, 8).equals(discriminator))
+    .map((data) => data.subarray(8));
+}
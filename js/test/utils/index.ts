@@ -1,6 +1,7 @@
 export * from './asserts';
 export * from './constants';
 export * from './errors';
+export * from './events';
 export * from './helper';
 
 import { CandyMachineData } from '@metaplex-solarti/mpl-candy-machine-core';
@@ -17,6 +18,97 @@ export async function getCandyGuardPDA(programId: PublicKey, base: Keypair): Pro
   });
 }
 
+export async function getUpdateBufferPDA(
+  programId: PublicKey,
+  candyGuard: PublicKey,
+): Promise<PublicKey> {
+  return await PublicKey.findProgramAddress(
+    [Buffer.from('update_buffer'), candyGuard.toBuffer()],
+    programId,
+  ).then((result) => {
+    return result[0];
+  });
+}
+
+export async function getScheduledUpdatePDA(
+  programId: PublicKey,
+  candyGuard: PublicKey,
+): Promise<PublicKey> {
+  return await PublicKey.findProgramAddress(
+    [Buffer.from('scheduled_update'), candyGuard.toBuffer()],
+    programId,
+  ).then((result) => {
+    return result[0];
+  });
+}
+
+export async function getAuthorityProposalPDA(
+  programId: PublicKey,
+  candyGuard: PublicKey,
+): Promise<PublicKey> {
+  return await PublicKey.findProgramAddress(
+    [Buffer.from('authority_proposal'), candyGuard.toBuffer()],
+    programId,
+  ).then((result) => {
+    return result[0];
+  });
+}
+
+export async function getDelegatePDA(
+  programId: PublicKey,
+  candyGuard: PublicKey,
+  delegate: PublicKey,
+): Promise<PublicKey> {
+  return await PublicKey.findProgramAddress(
+    [Buffer.from('delegate'), candyGuard.toBuffer(), delegate.toBuffer()],
+    programId,
+  ).then((result) => {
+    return result[0];
+  });
+}
+
+export async function getMintReceiptPDA(
+  programId: PublicKey,
+  nftMint: PublicKey,
+): Promise<PublicKey> {
+  return await PublicKey.findProgramAddress(
+    [Buffer.from('mint_receipt'), nftMint.toBuffer()],
+    programId,
+  ).then((result) => {
+    return result[0];
+  });
+}
+
+export async function getOffenseCounterPDA(
+  programId: PublicKey,
+  payer: PublicKey,
+  candyGuard: PublicKey,
+): Promise<PublicKey> {
+  return await PublicKey.findProgramAddress(
+    [Buffer.from('bot_tax'), payer.toBuffer(), candyGuard.toBuffer()],
+    programId,
+  ).then((result) => {
+    return result[0];
+  });
+}
+
+export async function getVoucherNoncePDA(
+  programId: PublicKey,
+  candyGuard: PublicKey,
+  nonce: number,
+): Promise<PublicKey> {
+  return await PublicKey.findProgramAddress(
+    [
+      Buffer.from('signed_voucher'),
+      candyGuard.toBuffer(),
+      new BN(nonce).toArrayLike(Buffer, 'le', 8),
+    ],
+    programId,
+  ).then((result) => {
+    return result[0];
+  });
+}
+
 export function getCandyMachineSpace(data: CandyMachineData): number {
   if (data.configLineSettings == null) {
     return HIDDEN_SECTION;
@@ -32,3 +124,16 @@ export function getCandyMachineSpace(data: CandyMachineData): number {
     );
   }
 }
+
+export async function getNftUsageCounterPDA(
+  programId: PublicKey,
+  candyGuard: PublicKey,
+  nftMint: PublicKey,
+): Promise<PublicKey> {
+  return await PublicKey.findProgramAddress(
+    [Buffer.from('nft_usage_limit'), candyGuard.toBuffer(), nftMint.toBuffer()],
+    programId,
+  ).then((result) => {
+    return result[0];
+  });
+}
@@ -30,3 +30,24 @@ test('withdraw', async (t) => {
 
   t.true(updatedBalance > balance, 'balance after withdraw must be greater');
 });
+
+test('withdraw: not allowed while the configuration is locked', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const { tx: transaction, candyGuard: address } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    payerPair,
+    fstTxHandler,
+  );
+  await transaction.assertSuccess(t);
+
+  const { tx: lockTransaction } = await API.lockConfig(t, address, null, payerPair, fstTxHandler);
+  await lockTransaction.assertSuccess(t);
+
+  const { tx: withdrawTransaction } = await API.withdraw(t, address, payerPair, fstTxHandler);
+  await withdrawTransaction.assertError(t, /Configuration is locked/i);
+
+  const accountInfo = await connection.getAccountInfo(address);
+  t.true(accountInfo !== null, 'candy guard account must not be closed');
+});
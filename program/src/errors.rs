@@ -1,4 +1,4 @@
-use anchor_lang::error_code;
+use anchor_lang::{error::ERROR_CODE_OFFSET, error_code};
 
 #[error_code]
 pub enum CandyGuardError {
@@ -94,4 +94,116 @@ pub enum CandyGuardError {
     UnauthorizedProgramFound,
     #[msg("Exceeded the maximum number of programs in the additional list")]
     ExceededProgramListSize,
+    #[msg("Guard is only allowed in the default guard set")]
+    GuardNotAllowedInGroup,
+    #[msg("A scheduled update already exists")]
+    ScheduledUpdateAlreadyExists,
+    #[msg("Activation time must be in the future")]
+    InvalidActivationTime,
+    #[msg("Scheduled update is not active")]
+    ScheduledUpdateNotActive,
+    #[msg("Missing scheduled update account")]
+    MissingScheduledUpdate,
+    #[msg("Signer is not authorized to perform the action")]
+    DelegateNotAuthorized,
+    #[msg("Invalid delegate roles")]
+    InvalidDelegateRoles,
+    #[msg("Update not allowed for the delegate role")]
+    DelegateUpdateNotAllowed,
+    #[msg("Candy guard configuration is locked")]
+    ConfigLocked,
+    #[msg("Lock time must be in the future and cannot shorten an existing lock")]
+    InvalidLockTime,
+    #[msg("Configuration cannot be locked while an update is scheduled")]
+    PendingUpdateNotAllowed,
+    #[msg("Mint is paused")]
+    MintPaused,
+    #[msg("Route instruction is paused")]
+    RoutePaused,
+    #[msg("The mint receipt account already exists")]
+    MintReceiptAlreadyExists,
+    #[msg("Exceeded the maximum number of error codes in the exemption list")]
+    ExceededExemptionListSize,
+    #[msg("Exemption list contains an invalid error code")]
+    InvalidExemptionCode,
+    #[msg("Duplicated error code in the exemption list")]
+    DuplicatedExemptionCode,
+    #[msg("Transaction exceeded the maximum number of instructions")]
+    ExceededInstructionCount,
+    #[msg("A forbidden instruction was found in the transaction")]
+    ForbiddenInstructionFound,
+    #[msg("Mint must be the only candy guard instruction in the transaction")]
+    MultipleCandyGuardInstructions,
+    #[msg("Exceeded the maximum number of instructions in the forbidden list")]
+    ExceededInstructionListSize,
+    #[msg("Invalid instruction discriminator size")]
+    InvalidDiscriminatorSize,
+    #[msg("Invalid instructions sysvar data")]
+    InvalidInstructionsSysvar,
+    #[msg("Missing signed voucher")]
+    MissingVoucher,
+    #[msg("Signed voucher has expired")]
+    VoucherExpired,
+    #[msg("Signed voucher has already been used")]
+    VoucherAlreadyUsed,
+    #[msg("Exceeded the maximum number of signer keys")]
+    ExceededSignerListSize,
+    #[msg("Signer threshold must be between 1 and the number of signer keys")]
+    InvalidSignerThreshold,
+    #[msg("Duplicated key in the signer list")]
+    DuplicatedSignerKey,
+    #[msg("Non-transferable tokens cannot be used as payment")]
+    NonTransferableToken,
+    #[msg("Duplicated token account")]
+    DuplicatedTokenAccount,
+    #[msg("Missing mint argument")]
+    MissingMintArgument,
+    #[msg("Invalid stake record")]
+    InvalidStakeRecord,
+    #[msg("NFT has reached the maximum number of uses")]
+    NftUsageLimitReached,
+    #[msg("Collection list must contain between 1 and 5 collections")]
+    InvalidCollectionListSize,
+    #[msg("NFT first creator is not verified or does not match")]
+    InvalidNftCreator,
+    #[msg("Invalid programmable NFT account data")]
+    InvalidProgrammableMetadata,
+    #[msg("Invalid number of NFTs (must be at least 1)")]
+    InvalidNftAmount,
+    #[msg("Duplicated NFT mint")]
+    DuplicatedNftMint,
+    #[msg("Token standard not supported by the guard")]
+    InvalidTokenStandard,
+    #[msg("Token is not a print edition of the master edition")]
+    InvalidEdition,
+    #[msg("Invalid number of token payments")]
+    InvalidTokenPaymentListSize,
+    #[msg("Duplicated mint in the token payment list")]
+    DuplicatedPaymentMint,
+    #[msg("Authority proposal was not created by the current authority")]
+    StaleAuthorityProposal,
+    #[msg("Missing bot tax destination account")]
+    MissingBotTaxDestination,
+    #[msg("Missing bot tax offense counter account")]
+    MissingOffenseCounter,
+    #[msg("Invalid number of token accounts (must be at least 1)")]
+    InvalidTokenAccountCount,
+    #[msg("Invalid NFT usage limit (must be at least 1)")]
+    InvalidNftUsageLimit,
+    #[msg("Token is not an NFT (mint supply of 1 with a master edition)")]
+    InvalidNft,
+    #[msg("NFT update authority does not match")]
+    InvalidNftUpdateAuthority,
+    #[msg("Tokens with a transfer fee cannot be used as payment")]
+    TransferFeeNotSupported,
+}
+
+impl CandyGuardError {
+    /// Last error variant, used to validate error codes.
+    const LAST: CandyGuardError = CandyGuardError::TransferFeeNotSupported;
+
+    /// Returns whether the `code` corresponds to a `CandyGuardError` variant or not.
+    pub fn is_valid_code(code: u32) -> bool {
+        (ERROR_CODE_OFFSET..=u32::from(Self::LAST)).contains(&code)
+    }
 }
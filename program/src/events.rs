@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+
+use crate::state::GuardType;
+
+/// Amount charged by a payment guard during a mint.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct Payment {
+    /// Guard that charged the payment.
+    pub guard: GuardType,
+    /// Mint of the tokens (or NFT) charged; `None` for payments in SOL.
+    pub mint: Option<Pubkey>,
+    /// Amount charged (lamports or token amount).
+    pub amount: u64,
+}
+
+/// Kinds of changes to the candy guard configuration.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum UpdateKind {
+    Update,
+    CommitBuffer,
+    ScheduleUpdate,
+    CancelScheduledUpdate,
+    ApplyScheduledUpdate,
+    LockConfig,
+    Pause,
+    Resume,
+}
+
+/// Kinds of changes to the candy guard authority.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum AuthorityChangeKind {
+    Propose,
+    Accept,
+    CancelProposal,
+    Set,
+}
+
+/// Emitted on a successful mint.
+#[event]
+pub struct MintEvent {
+    pub candy_guard: Pubkey,
+    pub candy_machine: Pubkey,
+    pub label: Option<String>,
+    pub payer: Pubkey,
+    pub nft_mint: Pubkey,
+    pub payments: Vec<Payment>,
+}
+
+/// Emitted when the bot tax is charged.
+#[event]
+pub struct BotTaxEvent {
+    pub candy_guard: Pubkey,
+    pub candy_machine: Pubkey,
+    pub payer: Pubkey,
+    pub lamports: u64,
+    /// Code of the error that triggered the bot tax.
+    pub error_code: u32,
+}
+
+/// Emitted when an NFT is frozen by a freeze guard.
+#[event]
+pub struct FreezeEvent {
+    pub candy_guard: Pubkey,
+    pub candy_machine: Pubkey,
+    pub freeze_escrow: Pubkey,
+    pub nft_mint: Pubkey,
+    pub frozen_count: u64,
+}
+
+/// Emitted when an NFT is thawed by a freeze guard.
+#[event]
+pub struct ThawEvent {
+    pub candy_guard: Pubkey,
+    pub candy_machine: Pubkey,
+    pub freeze_escrow: Pubkey,
+    pub nft_mint: Pubkey,
+    pub owner: Pubkey,
+    pub frozen_count: u64,
+}
+
+/// Emitted when the thaw of a freeze escrow is enabled by the authority.
+#[event]
+pub struct EnableThawEvent {
+    pub candy_guard: Pubkey,
+    pub candy_machine: Pubkey,
+    pub freeze_escrow: Pubkey,
+    pub frozen_count: u64,
+}
+
+/// Emitted when the funds of a freeze escrow are unlocked.
+#[event]
+pub struct UnlockFundsEvent {
+    pub candy_guard: Pubkey,
+    pub candy_machine: Pubkey,
+    pub freeze_escrow: Pubkey,
+    pub destination: Pubkey,
+    /// Mint of the tokens unlocked; `None` for funds in SOL.
+    pub mint: Option<Pubkey>,
+    /// Amount unlocked (lamports, excluding the rent of the escrow account, or
+    /// token amount).
+    pub amount: u64,
+}
+
+/// Emitted when the candy guard configuration changes.
+#[event]
+pub struct UpdateEvent {
+    pub candy_guard: Pubkey,
+    pub authority: Pubkey,
+    pub kind: UpdateKind,
+}
+
+/// Emitted when a transfer of the candy guard authority is proposed, accepted
+/// or cancelled.
+#[event]
+pub struct AuthorityEvent {
+    pub candy_guard: Pubkey,
+    /// Authority of the candy guard before the change.
+    pub authority: Pubkey,
+    /// Proposed authority of the candy guard.
+    pub new_authority: Pubkey,
+    pub kind: AuthorityChangeKind,
+}
+
+/// Emitted when the roles of a delegate are set or revoked.
+#[event]
+pub struct DelegateEvent {
+    pub candy_guard: Pubkey,
+    pub authority: Pubkey,
+    pub delegate: Pubkey,
+    /// Bitmask of the roles assigned to the delegate; `0` when revoked.
+    pub roles: u8,
+}
@@ -6,7 +6,10 @@ use super::{
     program_gate::{verify_programs, DEFAULT_PROGRAMS},
     *,
 };
-use crate::{errors::CandyGuardError, state::GuardType};
+use crate::{
+    errors::CandyGuardError, events::BotTaxEvent, guards::BotTaxPolicy, state::GuardType,
+    utils::error_code,
+};
 
 /// Guard is used to:
 /// * charge a penalty for invalid transactions
@@ -14,7 +17,9 @@ use crate::{errors::CandyGuardError, state::GuardType};
 /// * verify that only authorized programs have instructions
 ///
 /// The `bot_tax` is applied to any error that occurs during the
-/// validation of the guards.
+/// validation of the guards. The destination of the tax, an escalating
+/// penalty and the errors exempted from the tax are configured by the
+/// `BotTaxPolicy` guard.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct BotTax {
     pub lamports: u64,
@@ -62,23 +67,46 @@ impl BotTax {
         &self,
         error: Error,
         ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        policy: Option<&BotTaxPolicy>,
     ) -> Result<()> {
         let bot_account = ctx.accounts.payer.to_account_info();
-        let payment_account = ctx.accounts.candy_machine.to_account_info();
         let system_program = ctx.accounts.system_program.to_account_info();
 
+        let (payment_account, lamports) = if let Some(policy) = policy {
+            // a missing destination account falls back to the candy machine account
+            // instead of failing, otherwise the tax could be avoided
+            (
+                policy
+                    .destination_account(ctx)
+                    .ok()
+                    .flatten()
+                    .unwrap_or_else(|| ctx.accounts.candy_machine.to_account_info()),
+                policy.escalated_lamports(ctx, self.lamports)?,
+            )
+        } else {
+            (ctx.accounts.candy_machine.to_account_info(), self.lamports)
+        };
+
         msg!(
             "{}, Candy Guard Botting is taxed at {:?} lamports",
             error.to_string(),
-            self.lamports
+            lamports
         );
 
-        let final_fee = self.lamports.min(bot_account.lamports());
+        let final_fee = lamports.min(bot_account.lamports());
         invoke(
             &system_instruction::transfer(bot_account.key, payment_account.key, final_fee),
             &[bot_account, payment_account, system_program],
         )?;
 
+        emit!(BotTaxEvent {
+            candy_guard: ctx.accounts.candy_guard.key(),
+            candy_machine: ctx.accounts.candy_machine.key(),
+            payer: ctx.accounts.payer.key(),
+            lamports: final_fee,
+            error_code: error_code(&error),
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,236 @@
+use std::collections::HashSet;
+
+use super::*;
+use crate::{
+    errors::CandyGuardError,
+    state::GuardType,
+    utils::{assert_owned_by, cmp_pubkeys, create_pda_account, error_code},
+};
+
+// Maximum number of error codes in the exemption list.
+const MAXIMUM_EXEMPTIONS: usize = 8;
+
+/// Guard that configures how the `BotTax` guard charges the tax:
+/// * the destination of the tax (defaults to the candy machine account)
+/// * an escalating penalty for repeated offenses from the same wallet
+/// * the error codes that are not subject to the tax
+///
+/// The guard has no effect when the `BotTax` guard is not enabled.
+///
+/// List of accounts required (at any position of the remaining accounts):
+///
+///   0. `[writable]` (optional) Destination of the tax, when a `destination` is specified.
+///   1. `[writable]` (optional) Offense counter PDA, when the `escalation` is enabled
+///      (seeds `["bot_tax", payer key, candy guard pubkey]`).
+///
+/// Missing accounts are taxed like any other error, charging the flat `lamports` of the
+/// `BotTax` guard to the candy machine account.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BotTaxPolicy {
+    /// Address to receive the tax (defaults to the candy machine account).
+    pub destination: Option<Pubkey>,
+    /// Escalating penalty for repeated offenses from the same wallet.
+    pub escalation: Option<BotTaxEscalation>,
+    /// Error codes that are not subject to the tax.
+    pub exemptions: Vec<u32>,
+}
+
+/// Escalating penalty configuration: each previous offense from the same wallet
+/// increases the tax by `increment` lamports, up to `max_lamports`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BotTaxEscalation {
+    pub increment: u64,
+    pub max_lamports: u64,
+}
+
+impl Guard for BotTaxPolicy {
+    fn size() -> usize {
+        1 + 32 // option + destination
+        + 1 + 8 + 8 // option + escalation (increment + max_lamports)
+        + 4 + (MAXIMUM_EXEMPTIONS * 4) // exemptions
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::BotTaxPolicy)
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(policy) = &data.default.bot_tax_policy {
+            policy.verify_exemptions()?;
+        }
+
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if let Some(policy) = &group.guards.bot_tax_policy {
+                    policy.verify_exemptions()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for BotTaxPolicy {
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        guard_set: &GuardSet,
+        _evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        // the accounts are required by the bot tax, so they must be present in every
+        // transaction and not only in the ones that are taxed
+        if guard_set.bot_tax.is_some() {
+            self.destination_account(ctx)?;
+            self.offense_counter(ctx)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl BotTaxPolicy {
+    /// Returns whether the error is exempted from the tax or not.
+    pub fn is_exempt(&self, error: &Error) -> bool {
+        self.exemptions.contains(&error_code(error))
+    }
+
+    /// Returns the account to receive the tax when a `destination` is specified. The
+    /// account must be present (and writable) in the remaining accounts.
+    pub fn destination_account<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+    ) -> Result<Option<AccountInfo<'info>>> {
+        if let Some(destination) = &self.destination {
+            let account = ctx
+                .remaining_accounts
+                .iter()
+                .find(|account| cmp_pubkeys(account.key, destination) && account.is_writable)
+                .ok_or(CandyGuardError::MissingBotTaxDestination)?;
+
+            Ok(Some(account.clone()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the tax to be charged taking into account the previous offenses of the
+    /// payer and records the current offense. The flat `lamports` are charged when the
+    /// offense counter is missing, so leaving it out does not avoid the tax.
+    pub fn escalated_lamports<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        lamports: u64,
+    ) -> Result<u64> {
+        let (escalation, counter, bump) =
+            match (&self.escalation, self.offense_counter(ctx).ok().flatten()) {
+                (Some(escalation), Some((counter, bump))) => (escalation, counter, bump),
+                _ => return Ok(lamports),
+            };
+
+        let payer = ctx.accounts.payer.key();
+        let candy_guard_key = ctx.accounts.candy_guard.key();
+
+        if counter.data_is_empty() {
+            let signer = [
+                OffenseCounter::PREFIX_SEED,
+                payer.as_ref(),
+                candy_guard_key.as_ref(),
+                &[bump],
+            ];
+
+            create_pda_account(
+                &ctx.accounts.payer.to_account_info(),
+                &counter,
+                OffenseCounter::SIZE,
+                &crate::ID,
+                &signer,
+            )?;
+        } else {
+            assert_owned_by(&counter, &crate::ID)?;
+        }
+
+        let mut account_data = counter.try_borrow_mut_data()?;
+        let mut offense_counter = OffenseCounter::try_from_slice(&account_data)?;
+
+        let lamports = lamports
+            .saturating_add(
+                escalation
+                    .increment
+                    .saturating_mul(offense_counter.count as u64),
+            )
+            .min(escalation.max_lamports.max(lamports));
+
+        offense_counter.count = offense_counter.count.saturating_add(1);
+        // saves the changes back to the pda
+        let data = &mut offense_counter.try_to_vec().unwrap();
+        account_data[0..data.len()].copy_from_slice(data);
+
+        Ok(lamports)
+    }
+
+    /// Returns the offense counter PDA (and its bump) when the `escalation` is enabled.
+    /// The account must be present (and writable) in the remaining accounts.
+    fn offense_counter<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+    ) -> Result<Option<(AccountInfo<'info>, u8)>> {
+        if self.escalation.is_none() {
+            return Ok(None);
+        }
+
+        let payer = ctx.accounts.payer.key();
+        let candy_guard_key = ctx.accounts.candy_guard.key();
+
+        let seeds = [
+            OffenseCounter::PREFIX_SEED,
+            payer.as_ref(),
+            candy_guard_key.as_ref(),
+        ];
+        let (pda, bump) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+        let counter = ctx
+            .remaining_accounts
+            .iter()
+            .find(|account| cmp_pubkeys(account.key, &pda) && account.is_writable)
+            .ok_or(CandyGuardError::MissingOffenseCounter)?;
+
+        Ok(Some((counter.clone(), bump)))
+    }
+
+    /// Checks that the exemption list only contains unique `CandyGuardError` codes.
+    fn verify_exemptions(&self) -> Result<()> {
+        if self.exemptions.len() > MAXIMUM_EXEMPTIONS {
+            return err!(CandyGuardError::ExceededExemptionListSize);
+        }
+
+        let mut codes = HashSet::with_capacity(self.exemptions.len());
+
+        for code in &self.exemptions {
+            if !CandyGuardError::is_valid_code(*code) {
+                return err!(CandyGuardError::InvalidExemptionCode);
+            }
+
+            if !codes.insert(code) {
+                return err!(CandyGuardError::DuplicatedExemptionCode);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// PDA to track the number of bot tax offenses of an individual address.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct OffenseCounter {
+    pub count: u32,
+}
+
+impl OffenseCounter {
+    /// Prefix used as seed.
+    pub const PREFIX_SEED: &'static [u8] = b"bot_tax";
+
+    /// Size of the account.
+    pub const SIZE: usize = 4; // count
+}
@@ -0,0 +1,73 @@
+use super::*;
+use crate::state::{GuardType, DATA_OFFSET};
+
+/// Guard that locks the candy guard configuration, either permanently or until a
+/// specified time. The guard is managed by the `lock_config` instruction and it is
+/// only allowed on the default guard set.
+///
+/// While the lock is active, the instructions that modify the configuration (e.g.,
+/// `update`) and the `unwrap` and `withdraw` instructions fail.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ConfigLock {
+    /// Timestamp until when the configuration is locked; `i64::MAX` represents
+    /// a permanent lock.
+    pub until: i64,
+}
+
+impl Guard for ConfigLock {
+    fn size() -> usize {
+        8 // until
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::ConfigLock)
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if group.guards.config_lock.is_some() {
+                    return err!(CandyGuardError::GuardNotAllowedInGroup);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for ConfigLock {
+    fn validate<'info>(
+        &self,
+        _ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        _evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        // nothing to validate: the lock does not affect the mint
+        Ok(())
+    }
+}
+
+impl ConfigLock {
+    /// Value of `until` representing a permanent lock.
+    pub const PERMANENT: i64 = i64::MAX;
+
+    /// Returns whether the configuration is locked or not.
+    pub fn is_locked(&self, current_timestamp: i64) -> bool {
+        current_timestamp < self.until
+    }
+
+    /// Checks that the configuration of the candy guard `account_info` is not locked.
+    pub fn assert_unlocked(account_info: &AccountInfo) -> Result<()> {
+        let (default, _) = GuardSet::from_data(&account_info.data.borrow()[DATA_OFFSET..])?;
+
+        if let Some(config_lock) = default.config_lock {
+            if config_lock.is_locked(Clock::get()?.unix_timestamp) {
+                return err!(CandyGuardError::ConfigLocked);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+use super::*;
+
+use mpl_token_metadata::{
+    instruction::burn_edition_nft,
+    pda::{find_edition_account, find_metadata_account},
+    state::{Edition, Metadata, TokenMetadataAccount, TokenStandard, EDITION_MARKER_BIT_SIZE},
+};
+use solana_program::{program::invoke, program_pack::Pack};
+use spl_token::state::Account as TokenAccount;
+
+use crate::{
+    programmable::master_edition_address,
+    state::GuardType,
+    utils::{
+        assert_is_token_account, assert_keys_equal, assert_owned_by, cmp_pubkeys, spl_token_burn,
+        TokenBurnParams,
+    },
+};
+
+/// Guard that requires a print edition of a specified master edition or an amount
+/// of a specified fungible asset (semi-fungible token) to be burned.
+///
+/// The type of token is determined by the mint of the token account: when it matches
+/// the `mint` of the guard, the token is a fungible asset; otherwise it must be a
+/// print edition of the master edition of the `mint`.
+///
+/// List of accounts required for print editions:
+///
+///   0. `[writeable]` Token account of the print edition.
+///   1. `[writeable]` Metadata account of the print edition.
+///   2. `[writeable]` Mint account of the print edition.
+///   3. `[writeable]` Edition account of the print edition.
+///   4. `[]` Mint account of the master edition.
+///   5. `[]` Token account of the master edition (any token account of the mint).
+///   6. `[writeable]` Master edition account.
+///   7. `[writeable]` Edition marker PDA of the print edition.
+///
+/// List of accounts required for fungible assets:
+///
+///   0. `[writeable]` Token account holding the required amount.
+///   1. `[]` Metadata account of the fungible asset.
+///   2. `[writeable]` Mint account of the fungible asset.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct EditionBurn {
+    /// Mint of the master edition (print editions) or of the fungible asset.
+    pub mint: Pubkey,
+    /// Amount of tokens to burn (only used for fungible assets, print editions are
+    /// always burned one at a time).
+    pub amount: u64,
+}
+
+impl Guard for EditionBurn {
+    fn size() -> usize {
+        32  // mint
+        + 8 // amount
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::EditionBurn)
+    }
+}
+
+impl Condition for EditionBurn {
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.account_cursor;
+        // validates that we received all required accounts
+        let token_account = try_get_account_info(ctx, index)?;
+        let metadata_account = try_get_account_info(ctx, index + 1)?;
+        let token_mint = try_get_account_info(ctx, index + 2)?;
+        evaluation_context.account_cursor += 3;
+
+        let account =
+            assert_is_token_account(token_account, ctx.accounts.payer.key, token_mint.key)?;
+
+        assert_keys_equal(
+            metadata_account.key,
+            &find_metadata_account(token_mint.key).0,
+        )?;
+        let metadata: Metadata = Metadata::from_account_info(metadata_account)?;
+
+        if cmp_pubkeys(token_mint.key, &self.mint) {
+            // fungible asset
+            if !matches!(metadata.token_standard, Some(TokenStandard::FungibleAsset)) {
+                return err!(CandyGuardError::InvalidTokenStandard);
+            }
+
+            if account.amount < self.amount {
+                return err!(CandyGuardError::NotEnoughTokens);
+            }
+        } else {
+            // print edition
+            if account.amount < 1 {
+                return err!(CandyGuardError::NotEnoughTokens);
+            }
+
+            let edition_account = try_get_account_info(ctx, index + 3)?;
+            let master_mint = try_get_account_info(ctx, index + 4)?;
+            let master_token_account = try_get_account_info(ctx, index + 5)?;
+            let master_edition = try_get_account_info(ctx, index + 6)?;
+            let edition_marker = try_get_account_info(ctx, index + 7)?;
+            evaluation_context.account_cursor += 5;
+
+            assert_keys_equal(master_mint.key, &self.mint)?;
+            assert_keys_equal(master_edition.key, &master_edition_address(&self.mint))?;
+
+            assert_owned_by(edition_account, &mpl_token_metadata::ID)?;
+            let edition: Edition = Edition::from_account_info(edition_account)?;
+
+            if !cmp_pubkeys(&edition.parent, master_edition.key) {
+                return err!(CandyGuardError::InvalidEdition);
+            }
+
+            let marker_number = edition.edition / EDITION_MARKER_BIT_SIZE;
+            assert_keys_equal(
+                edition_marker.key,
+                &find_edition_account(&self.mint, marker_number.to_string()).0,
+            )?;
+
+            assert_owned_by(master_token_account, &spl_token::ID)?;
+            let master_token = TokenAccount::unpack(&master_token_account.try_borrow_data()?)?;
+            assert_keys_equal(&master_token.mint, &self.mint)?;
+        }
+
+        evaluation_context
+            .indices
+            .insert("edition_burn_index", index);
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.indices["edition_burn_index"];
+        // the accounts have already being validated
+        let token_account = try_get_account_info(ctx, index)?;
+        let metadata_account = try_get_account_info(ctx, index + 1)?;
+        let token_mint = try_get_account_info(ctx, index + 2)?;
+
+        if cmp_pubkeys(token_mint.key, &self.mint) {
+            spl_token_burn(TokenBurnParams {
+                mint: token_mint.to_account_info(),
+                source: token_account.to_account_info(),
+                amount: self.amount,
+                authority: ctx.accounts.payer.to_account_info(),
+                authority_signer_seeds: None,
+                token_program: ctx.accounts.token_program.to_account_info(),
+            })?;
+        } else {
+            let edition_account = try_get_account_info(ctx, index + 3)?;
+            let master_mint = try_get_account_info(ctx, index + 4)?;
+            let master_token_account = try_get_account_info(ctx, index + 5)?;
+            let master_edition = try_get_account_info(ctx, index + 6)?;
+            let edition_marker = try_get_account_info(ctx, index + 7)?;
+
+            invoke(
+                &burn_edition_nft(
+                    mpl_token_metadata::ID,
+                    metadata_account.key(),
+                    ctx.accounts.payer.key(),
+                    token_mint.key(),
+                    master_mint.key(),
+                    token_account.key(),
+                    master_token_account.key(),
+                    master_edition.key(),
+                    edition_account.key(),
+                    edition_marker.key(),
+                    spl_token::ID,
+                ),
+                &[
+                    metadata_account.to_account_info(),
+                    ctx.accounts.payer.to_account_info(),
+                    token_mint.to_account_info(),
+                    master_mint.to_account_info(),
+                    token_account.to_account_info(),
+                    master_token_account.to_account_info(),
+                    master_edition.to_account_info(),
+                    edition_account.to_account_info(),
+                    edition_marker.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                ],
+            )?;
+        }
+
+        Ok(())
+    }
+}
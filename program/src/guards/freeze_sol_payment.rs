@@ -2,12 +2,16 @@ use super::*;
 
 use anchor_lang::AccountsClose;
 use mpl_candy_machine_core::CandyMachine;
-use mpl_token_metadata::instruction::{freeze_delegated_account, thaw_delegated_account};
+use mpl_token_metadata::{
+    instruction::{freeze_delegated_account, thaw_delegated_account},
+    pda::find_metadata_account,
+};
 use solana_program::{
     program::{invoke, invoke_signed},
     program_pack::Pack,
-    system_instruction, system_program,
+    system_instruction, system_program, sysvar,
 };
+use spl_associated_token_account::get_associated_token_address;
 use spl_token::{
     instruction::{approve, revoke},
     state::Account as TokenAccount,
@@ -15,12 +19,22 @@ use spl_token::{
 
 use crate::{
     errors::CandyGuardError,
-    state::GuardType,
+    events::{EnableThawEvent, FreezeEvent, ThawEvent, UnlockFundsEvent},
+    programmable::{
+        self, find_account, find_authorization_rules, is_locked, load_metadata, mints_programmable,
+        token_record_address, ProgrammableNft, ProgrammablePrograms,
+    },
+    state::{Delegate, DelegateRole, GuardType},
     utils::{assert_is_ata, assert_keys_equal, cmp_pubkeys},
 };
 
 /// Guard that charges an amount in SOL (lamports) for the mint with a freeze period.
 ///
+/// Programmable NFTs are locked (and unlocked on thaw) by the freeze PDA as their
+/// utility delegate, which requires the token record PDA of the NFT token account
+/// and, if the NFT has a rule set, the token-auth-rules program and rule set accounts
+/// to be included in the remaining accounts.
+///
 /// List of accounts required:
 ///
 ///   0. `[writable]` Freeze PDA to receive the funds (seeds `["freeze_escrow",
@@ -48,6 +62,7 @@ impl Guard for FreezeSolPayment {
     ///  * initialize
     ///  * thaw
     ///  * unlock funds
+    ///  * enable thaw
     fn instruction<'info>(
         ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
         route_context: RouteContext<'info>,
@@ -111,14 +126,27 @@ impl Guard for FreezeSolPayment {
             //
             //   0. `[writable]` Freeze PDA to receive the funds (seeds `["freeze_escrow",
             //                   destination pubkey, candy guard pubkey, candy machine pubkey]`).
-            //   1. `[signer]` Candy Guard authority.
+            //   1. `[signer]` Candy Guard authority (or delegate with the treasurer role).
             //   2. `[writable]` Address to receive the funds (must match the `destination` address
             //                   of the guard configuration).
             //   3. `[]` System program account.
+            //   4. `[]` (optional) Delegate PDA (seeds `["delegate", candy guard pubkey,
+            //                      delegate pubkey]`) when the signer is a delegate.
             FreezeInstruction::UnlockFunds => {
                 msg!("Instruction: Unlock Funds (FreezeSolPayment guard)");
                 unlock_funds(ctx, route_context, data)
             }
+            // Enables the thaw of all NFTs before the end of the freeze period.
+            //
+            // List of accounts required:
+            //
+            //   0. `[writable]` Freeze PDA to receive the funds (seeds `["freeze_escrow",
+            //                   destination pubkey, candy guard pubkey, candy machine pubkey]`).
+            //   1. `[signer]` Candy Guard authority.
+            FreezeInstruction::EnableThaw => {
+                msg!("Instruction: Enable Thaw (FreezeSolPayment guard)");
+                enable_thaw(ctx, route_context)
+            }
         }
     }
 }
@@ -156,7 +184,15 @@ impl Condition for FreezeSolPayment {
 
         let nft_ata = try_get_account_info(ctx, index + 1)?;
         evaluation_context.account_cursor += 1;
-        assert_is_ata(nft_ata, ctx.accounts.payer.key, ctx.accounts.nft_mint.key)?;
+        // the token account of programmable NFTs is created by the mint
+        if mints_programmable(&ctx.accounts.candy_machine) {
+            assert_keys_equal(
+                nft_ata.key,
+                &get_associated_token_address(ctx.accounts.payer.key, ctx.accounts.nft_mint.key),
+            )?;
+        } else {
+            assert_is_ata(nft_ata, ctx.accounts.payer.key, ctx.accounts.nft_mint.key)?;
+        }
 
         evaluation_context
             .indices
@@ -197,6 +233,12 @@ impl Condition for FreezeSolPayment {
             ],
         )?;
 
+        evaluation_context.payments.push(Payment {
+            guard: GuardType::FreezeSolPayment,
+            mint: None,
+            amount: self.lamports,
+        });
+
         Ok(())
     }
 
@@ -234,7 +276,8 @@ pub struct FreezeEscrow {
     pub first_mint_time: Option<i64>,
 
     /// The amount of time (in seconds) for the freeze. The NFTs will be
-    /// allowed to thaw after this.
+    /// allowed to thaw after this. Set to zero when the authority enables
+    /// the thaw early.
     pub freeze_period: i64,
 
     /// The destination address for the frozed fund to go to.
@@ -281,6 +324,15 @@ impl FreezeEscrow {
         self.authority = authority;
     }
 
+    /// Ends the freeze period at `current_timestamp`, which allows the thaw of all
+    /// NFTs without changing the layout of the account.
+    pub fn enable_thaw(&mut self, current_timestamp: i64) {
+        if self.first_mint_time.is_none() {
+            self.first_mint_time = Some(current_timestamp);
+        }
+        self.freeze_period = 0;
+    }
+
     pub fn is_thaw_allowed(&self, candy_machine: &CandyMachine, current_timestamp: i64) -> bool {
         if candy_machine.items_redeemed >= candy_machine.data.items_available {
             return true;
@@ -299,6 +351,7 @@ pub enum FreezeInstruction {
     Initialize,
     Thaw,
     UnlockFunds,
+    EnableThaw,
 }
 
 /// Helper function to freeze an nft.
@@ -340,42 +393,90 @@ pub fn freeze_nft<'info>(
     ];
 
     let nft_ata = try_get_account_info(ctx, account_index + 1)?;
+    let (_, programmable) = load_metadata(&ctx.accounts.nft_metadata)?;
+
+    if let Some(config) = programmable {
+        // pNFTs are frozen by the freeze PDA as their utility delegate
+        let accounts = ctx.remaining_accounts;
+        let token_record = find_account(
+            accounts,
+            &token_record_address(ctx.accounts.nft_mint.key, nft_ata.key),
+        )?;
 
-    let mut freeze_ix = freeze_delegated_account(
-        mpl_token_metadata::ID,
-        freeze_pda.key(),
-        nft_ata.key(),
-        ctx.accounts.nft_master_edition.key(),
-        ctx.accounts.nft_mint.key(),
-    );
+        let programs = ProgrammablePrograms {
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.instruction_sysvar_account.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            authorization_rules: find_authorization_rules(accounts, &config)?,
+        };
+        let nft = ProgrammableNft {
+            mint: ctx.accounts.nft_mint.to_account_info(),
+            metadata: ctx.accounts.nft_metadata.to_account_info(),
+            master_edition: ctx.accounts.nft_master_edition.to_account_info(),
+            token: nft_ata.to_account_info(),
+            token_record: token_record.to_account_info(),
+        };
+
+        programmable::delegate_utility(
+            &programs,
+            &nft,
+            &freeze_pda.to_account_info(),
+            &payer.to_account_info(),
+        )?;
+        programmable::lock(
+            &programs,
+            &nft,
+            &freeze_pda.to_account_info(),
+            &payer.to_account_info(),
+            &payer.to_account_info(),
+            &signer,
+        )?;
+    } else {
+        let mut freeze_ix = freeze_delegated_account(
+            mpl_token_metadata::ID,
+            freeze_pda.key(),
+            nft_ata.key(),
+            ctx.accounts.nft_master_edition.key(),
+            ctx.accounts.nft_mint.key(),
+        );
 
-    freeze_ix.accounts[0] = AccountMeta::new_readonly(freeze_pda.key(), true);
-
-    invoke(
-        &approve(
-            &spl_token::ID,
-            &nft_ata.key(),
-            &freeze_pda.key(),
-            &payer.key(),
-            &[],
-            1,
-        )?,
-        &[
-            nft_ata.to_account_info(),
-            freeze_pda.to_account_info(),
-            payer.to_account_info(),
-        ],
-    )?;
-    invoke_signed(
-        &freeze_ix,
-        &[
-            freeze_pda.to_account_info(),
-            nft_ata.to_account_info(),
-            ctx.accounts.nft_master_edition.to_account_info(),
-            ctx.accounts.nft_mint.to_account_info(),
-        ],
-        &[&signer],
-    )?;
+        freeze_ix.accounts[0] = AccountMeta::new_readonly(freeze_pda.key(), true);
+
+        invoke(
+            &approve(
+                &spl_token::ID,
+                &nft_ata.key(),
+                &freeze_pda.key(),
+                &payer.key(),
+                &[],
+                1,
+            )?,
+            &[
+                nft_ata.to_account_info(),
+                freeze_pda.to_account_info(),
+                payer.to_account_info(),
+            ],
+        )?;
+        invoke_signed(
+            &freeze_ix,
+            &[
+                freeze_pda.to_account_info(),
+                nft_ata.to_account_info(),
+                ctx.accounts.nft_master_edition.to_account_info(),
+                ctx.accounts.nft_mint.to_account_info(),
+            ],
+            &[&signer],
+        )?;
+    }
+
+    emit!(FreezeEvent {
+        candy_guard: *candy_guard_key,
+        candy_machine: *candy_machine_key,
+        freeze_escrow: freeze_pda.key(),
+        nft_mint: ctx.accounts.nft_mint.key(),
+        frozen_count: freeze_escrow.frozen_count,
+    });
 
     Ok(())
 }
@@ -525,42 +626,113 @@ pub fn thaw_nft<'info>(
         &[bump],
     ];
 
-    if nft_token_account.is_frozen() {
-        invoke_signed(
-            &thaw_delegated_account(
-                mpl_token_metadata::ID,
-                freeze_pda.key(),
-                nft_ata.key(),
-                nft_master_edition.key(),
-                nft_mint.key(),
-            ),
-            &[
-                freeze_pda.to_account_info(),
-                nft_ata.to_account_info(),
-                nft_master_edition.to_account_info(),
-                nft_mint.to_account_info(),
-                token_program.to_account_info(),
-                token_metadata_program.to_account_info(),
-            ],
-            &[&signer],
-        )?;
-        freeze_escrow.frozen_count = freeze_escrow.frozen_count.saturating_sub(1);
-    } else {
-        msg!("Token account is not frozen");
-    }
+    // pNFTs are identified by their metadata account, which is only required
+    // (as a remaining account) to thaw a pNFT
+    let metadata_address = find_metadata_account(nft_mint.key).0;
+    let programmable = match ctx
+        .remaining_accounts
+        .iter()
+        .find(|account| cmp_pubkeys(account.key, &metadata_address))
+    {
+        Some(nft_metadata) => load_metadata(nft_metadata)?
+            .1
+            .map(|config| (nft_metadata, config)),
+        None => None,
+    };
 
-    if cmp_pubkeys(&payer.key(), &nft_owner.key()) {
-        msg!("Revoking authority");
-        invoke(
-            &revoke(&spl_token::ID, &nft_ata.key(), &payer.key(), &[])?,
-            &[nft_ata.to_account_info(), payer.to_account_info()],
-        )?;
+    if let Some((nft_metadata, config)) = programmable {
+        let accounts = ctx.remaining_accounts;
+        let token_record =
+            find_account(accounts, &token_record_address(nft_mint.key, nft_ata.key))?;
+
+        let programs = ProgrammablePrograms {
+            token_metadata_program: token_metadata_program.to_account_info(),
+            system_program: find_account(accounts, &system_program::ID)?.to_account_info(),
+            sysvar_instructions: find_account(accounts, &sysvar::instructions::ID)?
+                .to_account_info(),
+            spl_token_program: token_program.to_account_info(),
+            authorization_rules: find_authorization_rules(accounts, &config)?,
+        };
+        let nft = ProgrammableNft {
+            mint: nft_mint.to_account_info(),
+            metadata: nft_metadata.to_account_info(),
+            master_edition: nft_master_edition.to_account_info(),
+            token: nft_ata.to_account_info(),
+            token_record: token_record.to_account_info(),
+        };
+
+        if is_locked(token_record)? {
+            programmable::unlock(
+                &programs,
+                &nft,
+                &freeze_pda.to_account_info(),
+                &nft_owner.to_account_info(),
+                &payer.to_account_info(),
+                &signer,
+            )?;
+            freeze_escrow.frozen_count = freeze_escrow.frozen_count.saturating_sub(1);
+        } else {
+            msg!("Token is not locked");
+        }
+
+        if cmp_pubkeys(&payer.key(), &nft_owner.key()) {
+            msg!("Revoking authority");
+            programmable::revoke_utility(
+                &programs,
+                &nft,
+                &freeze_pda.to_account_info(),
+                &payer.to_account_info(),
+            )?;
+        } else {
+            msg!("Token account owner is not signer, authority not revoked");
+        }
     } else {
-        msg!("Token account owner is not signer, authority not revoked");
+        if nft_token_account.is_frozen() {
+            invoke_signed(
+                &thaw_delegated_account(
+                    mpl_token_metadata::ID,
+                    freeze_pda.key(),
+                    nft_ata.key(),
+                    nft_master_edition.key(),
+                    nft_mint.key(),
+                ),
+                &[
+                    freeze_pda.to_account_info(),
+                    nft_ata.to_account_info(),
+                    nft_master_edition.to_account_info(),
+                    nft_mint.to_account_info(),
+                    token_program.to_account_info(),
+                    token_metadata_program.to_account_info(),
+                ],
+                &[&signer],
+            )?;
+            freeze_escrow.frozen_count = freeze_escrow.frozen_count.saturating_sub(1);
+        } else {
+            msg!("Token account is not frozen");
+        }
+
+        if cmp_pubkeys(&payer.key(), &nft_owner.key()) {
+            msg!("Revoking authority");
+            invoke(
+                &revoke(&spl_token::ID, &nft_ata.key(), &payer.key(), &[])?,
+                &[nft_ata.to_account_info(), payer.to_account_info()],
+            )?;
+        } else {
+            msg!("Token account owner is not signer, authority not revoked");
+        }
     }
     // save the account state
     freeze_escrow.exit(&crate::ID)?;
 
+    emit!(ThawEvent {
+        candy_guard: *candy_guard_key,
+        candy_machine: *candy_machine_key,
+        freeze_escrow: freeze_pda.key(),
+        nft_mint: nft_mint.key(),
+        owner: nft_owner.key(),
+        frozen_count: freeze_escrow.frozen_count,
+    });
+
     Ok(())
 }
 
@@ -589,14 +761,17 @@ fn unlock_funds<'info>(
     let authority = try_get_account_info(ctx, 1)?;
 
     // if the candy guard account is present, we check the authority against
-    // the candy guard authority; otherwise we use the freeze escrow authority
-    let authority_check = if let Some(candy_guard) = route_context.candy_guard {
-        candy_guard.authority
-    } else {
-        freeze_escrow.authority
-    };
-
-    if !(cmp_pubkeys(authority.key, &authority_check) && authority.is_signer) {
+    // the candy guard authority (or a delegate with the treasurer role);
+    // otherwise we use the freeze escrow authority
+    if let Some(candy_guard) = route_context.candy_guard {
+        Delegate::assert_authority_or_role(
+            candy_guard_key,
+            &candy_guard.authority,
+            authority,
+            DelegateRole::Treasurer,
+            ctx.remaining_accounts,
+        )?;
+    } else if !(cmp_pubkeys(authority.key, &freeze_escrow.authority) && authority.is_signer) {
         return err!(CandyGuardError::MissingRequiredSignature);
     }
 
@@ -609,7 +784,140 @@ fn unlock_funds<'info>(
     // funds should go to the destination account
     assert_keys_equal(destination_address.key, &freeze_escrow.destination)?;
 
+    // the rent of the escrow account is not part of the unlocked funds
+    let rent = Rent::get()?.minimum_balance(freeze_pda.data_len());
+    let amount = freeze_pda.lamports().saturating_sub(rent);
     freeze_escrow.close(destination_address.to_account_info())?;
 
+    emit!(UnlockFundsEvent {
+        candy_guard: *candy_guard_key,
+        candy_machine: *candy_machine_key,
+        freeze_escrow: freeze_pda.key(),
+        destination: destination_address.key(),
+        mint: None,
+        amount,
+    });
+
     Ok(())
 }
+
+/// Helper function to enable the thaw of all NFTs before the end of the freeze period.
+pub fn enable_thaw<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+    route_context: RouteContext,
+) -> Result<()> {
+    let candy_guard_key = &ctx.accounts.candy_guard.key();
+    let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+    let freeze_pda = try_get_account_info(ctx, 0)?;
+    let mut freeze_escrow: Account<FreezeEscrow> = Account::try_from(freeze_pda)?;
+
+    let seeds = [
+        FreezeEscrow::PREFIX_SEED,
+        freeze_escrow.destination.as_ref(),
+        candy_guard_key.as_ref(),
+        candy_machine_key.as_ref(),
+    ];
+    let (pda, _) = Pubkey::find_program_address(&seeds, &crate::ID);
+    assert_keys_equal(freeze_pda.key, &pda)?;
+
+    // authority must the a signer
+    let authority = try_get_account_info(ctx, 1)?;
+
+    // if the candy guard account is present, we check the authority against
+    // the candy guard authority; otherwise we use the freeze escrow authority
+    let expected = if let Some(candy_guard) = route_context.candy_guard {
+        candy_guard.authority
+    } else {
+        freeze_escrow.authority
+    };
+
+    if !(cmp_pubkeys(authority.key, &expected) && authority.is_signer) {
+        return err!(CandyGuardError::MissingRequiredSignature);
+    }
+
+    freeze_escrow.enable_thaw(Clock::get()?.unix_timestamp);
+    freeze_escrow.exit(&crate::ID)?;
+
+    emit!(EnableThawEvent {
+        candy_guard: *candy_guard_key,
+        candy_machine: *candy_machine_key,
+        freeze_escrow: freeze_pda.key(),
+        frozen_count: freeze_escrow.frozen_count,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn escrow(first_mint_time: Option<i64>, freeze_period: i64) -> FreezeEscrow {
+        FreezeEscrow {
+            first_mint_time,
+            freeze_period,
+            ..Default::default()
+        }
+    }
+
+    fn candy_machine(items_redeemed: u64, items_available: u64) -> CandyMachine {
+        let mut candy_machine = CandyMachine {
+            items_redeemed,
+            ..Default::default()
+        };
+        candy_machine.data.items_available = items_available;
+        candy_machine
+    }
+
+    #[test]
+    fn freeze_escrow_size() {
+        let escrow = escrow(Some(0), FreezeEscrow::MAX_FREEZE_TIME);
+        // discriminator + serialized fields
+        assert_eq!(FreezeEscrow::SIZE, 8 + escrow.try_to_vec().unwrap().len());
+    }
+
+    #[test]
+    fn thaw_allowed_after_freeze_period() {
+        let candy_machine = candy_machine(1, 10);
+        let escrow = escrow(Some(100), 50);
+
+        assert!(!escrow.is_thaw_allowed(&candy_machine, 149));
+        assert!(escrow.is_thaw_allowed(&candy_machine, 150));
+    }
+
+    #[test]
+    fn thaw_allowed_when_sold_out() {
+        let candy_machine = candy_machine(10, 10);
+        let escrow = escrow(None, FreezeEscrow::MAX_FREEZE_TIME);
+
+        assert!(escrow.is_thaw_allowed(&candy_machine, 0));
+    }
+
+    #[test]
+    fn thaw_allowed_with_enabled_thaw() {
+        let candy_machine = candy_machine(1, 10);
+        let mut escrow = escrow(Some(100), FreezeEscrow::MAX_FREEZE_TIME);
+        assert!(!escrow.is_thaw_allowed(&candy_machine, 101));
+
+        escrow.enable_thaw(101);
+
+        assert_eq!(escrow.freeze_period, 0);
+        // the time of the first mint is kept
+        assert_eq!(escrow.first_mint_time, Some(100));
+        assert!(escrow.is_thaw_allowed(&candy_machine, 101));
+    }
+
+    #[test]
+    fn thaw_allowed_with_enabled_thaw_before_first_mint() {
+        let candy_machine = candy_machine(0, 10);
+        let mut escrow = escrow(None, FreezeEscrow::MAX_FREEZE_TIME);
+        assert!(!escrow.is_thaw_allowed(&candy_machine, 100));
+
+        escrow.enable_thaw(100);
+
+        assert_eq!(escrow.freeze_period, 0);
+        assert_eq!(escrow.first_mint_time, Some(100));
+        assert!(escrow.is_thaw_allowed(&candy_machine, 100));
+    }
+}
@@ -12,8 +12,10 @@ use spl_token::{instruction::close_account, state::Account as TokenAccount};
 
 use crate::{
     errors::CandyGuardError,
-    guards::freeze_sol_payment::{initialize_freeze, thaw_nft},
-    state::GuardType,
+    events::UnlockFundsEvent,
+    guards::freeze_sol_payment::{enable_thaw, initialize_freeze, thaw_nft},
+    programmable::mints_programmable,
+    state::{Delegate, DelegateRole, GuardType},
     utils::{
         assert_is_ata, assert_keys_equal, assert_owned_by, cmp_pubkeys, spl_token_transfer,
         TokenTransferParams,
@@ -54,6 +56,7 @@ impl Guard for FreezeTokenPayment {
     ///  * initialize
     ///  * thaw
     ///  * unlock funds
+    ///  * enable thaw
     fn instruction<'info>(
         ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
         route_context: RouteContext<'info>,
@@ -158,17 +161,30 @@ impl Guard for FreezeTokenPayment {
             //
             //   0. `[writable]` Freeze PDA (seeds `["freeze_escrow", destination_ata pubkey, candy guard pubkey,
             //                   candy machine pubkey]`).
-            //   1. `[signer]` Candy Guard authority.
+            //   1. `[signer]` Candy Guard authority (or delegate with the treasurer role).
             //   2. `[writable]` Associate token account of the Freeze PDA (seeds `[freeze PDA pubkey, token
             //                   program pubkey, nft mint pubkey]`).
             //   3. `[writable]` Address to receive the funds (must match the `destination_ata` address
             //                   of the guard configuration).
             //   4. `[]` Token program account.
             //   5. `[]` System program account.
+            //   6. `[]` (optional) Delegate PDA (seeds `["delegate", candy guard pubkey,
+            //                      delegate pubkey]`) when the signer is a delegate.
             FreezeInstruction::UnlockFunds => {
                 msg!("Instruction: Unlock Funds (FreezeTokenPayment guard)");
                 unlock_funds(ctx, route_context)
             }
+            // Enables the thaw of all NFTs before the end of the freeze period.
+            //
+            // List of accounts required:
+            //
+            //   0. `[writable]` Freeze PDA (seeds `["freeze_escrow", destination_ata pubkey, candy guard pubkey,
+            //                   candy machine pubkey]`).
+            //   1. `[signer]` Candy Guard authority.
+            FreezeInstruction::EnableThaw => {
+                msg!("Instruction: Enable Thaw (FreezeTokenPayment guard)");
+                enable_thaw(ctx, route_context)
+            }
         }
     }
 }
@@ -206,7 +222,15 @@ impl Condition for FreezeTokenPayment {
 
         let nft_ata = try_get_account_info(ctx, index + 1)?;
         evaluation_context.account_cursor += 1;
-        assert_is_ata(nft_ata, ctx.accounts.payer.key, ctx.accounts.nft_mint.key)?;
+        // the token account of programmable NFTs is created by the mint
+        if mints_programmable(&ctx.accounts.candy_machine) {
+            assert_keys_equal(
+                nft_ata.key,
+                &get_associated_token_address(ctx.accounts.payer.key, ctx.accounts.nft_mint.key),
+            )?;
+        } else {
+            assert_is_ata(nft_ata, ctx.accounts.payer.key, ctx.accounts.nft_mint.key)?;
+        }
 
         let token_account_info = try_get_account_info(ctx, index + 2)?;
         // validate freeze_pda ata
@@ -250,6 +274,12 @@ impl Condition for FreezeTokenPayment {
             amount: self.amount,
         })?;
 
+        evaluation_context.payments.push(Payment {
+            guard: GuardType::FreezeTokenPayment,
+            mint: Some(self.mint),
+            amount: self.amount,
+        });
+
         Ok(())
     }
 
@@ -293,14 +323,17 @@ fn unlock_funds<'info>(
     let authority = try_get_account_info(ctx, 1)?;
 
     // if the candy guard account is present, we check the authority against
-    // the candy guard authority; otherwise we use the freeze escrow authority
-    let authority_check = if let Some(candy_guard) = route_context.candy_guard {
-        candy_guard.authority
-    } else {
-        freeze_escrow.authority
-    };
-
-    if !(cmp_pubkeys(authority.key, &authority_check) && authority.is_signer) {
+    // the candy guard authority (or a delegate with the treasurer role);
+    // otherwise we use the freeze escrow authority
+    if let Some(candy_guard) = route_context.candy_guard {
+        Delegate::assert_authority_or_role(
+            candy_guard_key,
+            &candy_guard.authority,
+            authority,
+            DelegateRole::Treasurer,
+            ctx.remaining_accounts,
+        )?;
+    } else if !(cmp_pubkeys(authority.key, &freeze_escrow.authority) && authority.is_signer) {
         return err!(CandyGuardError::MissingRequiredSignature);
     }
 
@@ -361,5 +394,14 @@ fn unlock_funds<'info>(
     // the rent for the freeze escrow goes back to the authority
     freeze_escrow.close(authority.to_account_info())?;
 
+    emit!(UnlockFundsEvent {
+        candy_guard: *candy_guard_key,
+        candy_machine: *candy_machine_key,
+        freeze_escrow: freeze_pda.key(),
+        destination: destination_ata_account.key(),
+        mint: Some(freeze_ata_account.mint),
+        amount: freeze_ata_account.amount,
+    });
+
     Ok(())
 }
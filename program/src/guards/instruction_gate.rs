@@ -0,0 +1,137 @@
+use super::*;
+use crate::{
+    errors::CandyGuardError,
+    state::GuardType,
+    utils::{cmp_pubkeys, InstructionsSysvarReader, SysvarInstruction},
+};
+
+// Maximum number of forbidden instructions.
+const MAXIMUM_SIZE: usize = 5;
+
+// Maximum size of an instruction discriminator.
+const MAXIMUM_DISCRIMINATOR_SIZE: usize = 8;
+
+/// Guard that inspects the instructions of the mint transaction. It can:
+/// * restrict the number of instructions in the transaction
+/// * forbid specific instructions (identified by their discriminator) of any program
+/// * require that the mint is the only candy guard instruction in the transaction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct InstructionGate {
+    /// Maximum number of instructions in the transaction (`0` for no limit).
+    pub max_instructions: u16,
+    /// Indicates whether the mint must be the only candy guard instruction or not.
+    pub single_mint: bool,
+    /// List of forbidden instructions.
+    pub forbidden: Vec<ForbiddenInstruction>,
+}
+
+/// Instruction identified by the program id and the discriminator, which is
+/// matched against the start of the instruction data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ForbiddenInstruction {
+    pub program_id: Pubkey,
+    pub discriminator: Vec<u8>,
+}
+
+impl Guard for InstructionGate {
+    fn size() -> usize {
+        2   // max_instructions
+        + 1 // single_mint
+        + 4 + MAXIMUM_SIZE * (32 + 4 + MAXIMUM_DISCRIMINATOR_SIZE) // forbidden
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::InstructionGate)
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(instruction_gate) = &data.default.instruction_gate {
+            instruction_gate.verify_forbidden()?;
+        }
+
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if let Some(instruction_gate) = &group.guards.instruction_gate {
+                    instruction_gate.verify_forbidden()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for InstructionGate {
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        _evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let ix_sysvar_account = &ctx.accounts.instruction_sysvar_account;
+        let sysvar_data = ix_sysvar_account.data.borrow();
+        let reader = InstructionsSysvarReader::new(&sysvar_data)?;
+
+        // determines the total number of instructions in the transaction
+        let num_instructions = reader.num_instructions();
+
+        if self.max_instructions > 0 && num_instructions > self.max_instructions {
+            msg!(
+                "Transaction had {} instructions (maximum {})",
+                num_instructions,
+                self.max_instructions
+            );
+            return err!(CandyGuardError::ExceededInstructionCount);
+        }
+
+        let mut candy_guard_instructions = 0;
+
+        for instruction in reader.instructions() {
+            let SysvarInstruction { program_id, data } = instruction?;
+
+            if cmp_pubkeys(&program_id, &crate::ID) {
+                candy_guard_instructions += 1;
+            }
+
+            for instruction in &self.forbidden {
+                if cmp_pubkeys(&program_id, &instruction.program_id)
+                    && data.starts_with(&instruction.discriminator)
+                {
+                    msg!(
+                        "Transaction had a forbidden ix with program id {}",
+                        program_id
+                    );
+                    return err!(CandyGuardError::ForbiddenInstructionFound);
+                }
+            }
+        }
+
+        // the mint instruction is always present
+        if self.single_mint && candy_guard_instructions > 1 {
+            return err!(CandyGuardError::MultipleCandyGuardInstructions);
+        }
+
+        Ok(())
+    }
+}
+
+impl InstructionGate {
+    /// Checks that the list of forbidden instructions is valid.
+    fn verify_forbidden(&self) -> Result<()> {
+        if self.forbidden.len() > MAXIMUM_SIZE {
+            return err!(CandyGuardError::ExceededInstructionListSize);
+        }
+
+        for instruction in &self.forbidden {
+            // an empty discriminator would match every instruction of the program
+            if instruction.discriminator.is_empty()
+                || instruction.discriminator.len() > MAXIMUM_DISCRIMINATOR_SIZE
+            {
+                return err!(CandyGuardError::InvalidDiscriminatorSize);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use solana_program::{program::invoke_signed, system_instruction};
+
+use super::*;
+use crate::{
+    events::Payment,
+    state::{GuardType, MAX_LABEL_SIZE},
+    utils::assert_keys_equal,
+};
+
+/// Guard that writes a receipt for each mint. The receipt records the candy guard,
+/// the group label, the payer, the timestamp and the payments charged, providing an
+/// on-chain proof of which group (phase) an NFT was minted in.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` Mint receipt PDA (seeds `["mint_receipt", nft mint
+///           pubkey]`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MintReceipt {}
+
+impl Guard for MintReceipt {
+    fn size() -> usize {
+        0
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::MintReceipt)
+    }
+}
+
+impl Condition for MintReceipt {
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let receipt = try_get_account_info(ctx, evaluation_context.account_cursor)?;
+        evaluation_context
+            .indices
+            .insert("mint_receipt_index", evaluation_context.account_cursor);
+        evaluation_context.account_cursor += 1;
+
+        let (pda, _) = Receipt::address(ctx.accounts.nft_mint.key);
+        assert_keys_equal(receipt.key, &pda)?;
+
+        if !receipt.data_is_empty() {
+            return err!(CandyGuardError::MintReceiptAlreadyExists);
+        }
+
+        Ok(())
+    }
+
+    fn post_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let receipt_info =
+            try_get_account_info(ctx, evaluation_context.indices["mint_receipt_index"])?;
+
+        let nft_mint = ctx.accounts.nft_mint.key();
+        let (_, bump) = Receipt::address(&nft_mint);
+        let signer = [Receipt::PREFIX_SEED, nft_mint.as_ref(), &[bump]];
+
+        // all payments have been charged at this point (pre actions)
+        let size = Receipt::size(evaluation_context.payments.len());
+        let rent = Rent::get()?;
+
+        invoke_signed(
+            &system_instruction::create_account(
+                ctx.accounts.payer.key,
+                receipt_info.key,
+                rent.minimum_balance(size),
+                size as u64,
+                &crate::ID,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                receipt_info.to_account_info(),
+            ],
+            &[&signer],
+        )?;
+
+        // the receipt did not exist before this mint (checked in validate), so
+        // there is no discriminator to verify
+        let mut receipt: Account<Receipt> = Account::try_from_unchecked(receipt_info)?;
+        receipt.nft_mint = nft_mint;
+        receipt.candy_guard = ctx.accounts.candy_guard.key();
+        receipt.candy_machine = ctx.accounts.candy_machine.key();
+        receipt.label = evaluation_context.label.clone();
+        receipt.payer = ctx.accounts.payer.key();
+        receipt.timestamp = Clock::get()?.unix_timestamp;
+        receipt.payments = evaluation_context.payments.clone();
+        receipt.exit(&crate::ID)
+    }
+}
+
+/// PDA to store the receipt of a mint.
+#[account]
+#[derive(Default, Debug)]
+pub struct Receipt {
+    /// Mint address of the NFT.
+    pub nft_mint: Pubkey,
+    /// Candy guard address used in the mint.
+    pub candy_guard: Pubkey,
+    /// Candy machine address used in the mint.
+    pub candy_machine: Pubkey,
+    /// Label of the group used in the mint.
+    pub label: Option<String>,
+    /// Address that paid for the mint.
+    pub payer: Pubkey,
+    /// Timestamp of the mint.
+    pub timestamp: i64,
+    /// Payments charged by the guards.
+    pub payments: Vec<Payment>,
+}
+
+impl Receipt {
+    /// Prefix used as seed.
+    pub const PREFIX_SEED: &'static [u8] = b"mint_receipt";
+
+    /// Maximum size of a payment entry.
+    const PAYMENT_SIZE: usize = 1 // guard
+        + 1 + 32 // mint (option)
+        + 8; // amount
+
+    /// Returns the account size required to store a receipt with the specified
+    /// number of payments.
+    pub fn size(payments: usize) -> usize {
+        8 // discriminator
+        + 32 // nft mint
+        + 32 // candy guard
+        + 32 // candy machine
+        + 1 + 4 + MAX_LABEL_SIZE // label (option)
+        + 32 // payer
+        + 8 // timestamp
+        + 4 + payments * Self::PAYMENT_SIZE // payments
+    }
+
+    /// Returns the PDA address of the receipt for the specified NFT mint.
+    pub fn address(nft_mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::PREFIX_SEED, nft_mint.as_ref()], &crate::ID)
+    }
+}
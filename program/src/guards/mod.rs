@@ -4,6 +4,7 @@ pub use anchor_lang::prelude::*;
 
 pub use crate::{errors::CandyGuardError, instructions::mint::*, state::GuardSet};
 use crate::{
+    events::Payment,
     instructions::{Route, RouteContext},
     state::CandyGuardData,
 };
@@ -11,18 +12,33 @@ use crate::{
 pub use address_gate::AddressGate;
 pub use allow_list::AllowList;
 pub use bot_tax::BotTax;
+pub use bot_tax_policy::{BotTaxEscalation, BotTaxPolicy, OffenseCounter};
+pub use config_lock::ConfigLock;
+pub use edition_burn::EditionBurn;
 pub use end_date::EndDate;
 pub use freeze_sol_payment::{FreezeEscrow, FreezeInstruction, FreezeSolPayment};
 pub use freeze_token_payment::FreezeTokenPayment;
 pub use gatekeeper::Gatekeeper;
+pub use instruction_gate::{ForbiddenInstruction, InstructionGate};
 pub use mint_limit::{MintCounter, MintLimit};
+pub use mint_receipt::{MintReceipt, Receipt};
+pub use multi_token_payment::{MultiTokenPayment, TokenAmount};
 pub use nft_burn::NftBurn;
 pub use nft_gate::NftGate;
+pub use nft_match_burn::NftMatchBurn;
+pub use nft_match_gate::{NftMatchGate, NftMatcher};
+pub use nft_match_payment::NftMatchPayment;
 pub use nft_payment::NftPayment;
+pub use nft_usage_limit::{NftUsageCounter, NftUsageLimit};
+pub use pause::Pause;
+pub use pending_update::PendingUpdate;
 pub use program_gate::ProgramGate;
 pub use redeemed_amount::RedeemedAmount;
+pub use signed_voucher::SignedVoucher;
 pub use sol_payment::SolPayment;
+pub use stake_gate::{StakeGate, StakedAsset};
 pub use start_date::StartDate;
+pub use third_party_multisig::ThirdPartyMultisig;
 pub use third_party_signer::ThirdPartySigner;
 pub use token_burn::TokenBurn;
 pub use token_gate::TokenGate;
@@ -31,18 +47,33 @@ pub use token_payment::TokenPayment;
 mod address_gate;
 mod allow_list;
 mod bot_tax;
+mod bot_tax_policy;
+mod config_lock;
+mod edition_burn;
 mod end_date;
 mod freeze_sol_payment;
 mod freeze_token_payment;
 mod gatekeeper;
+mod instruction_gate;
 mod mint_limit;
+mod mint_receipt;
+mod multi_token_payment;
 mod nft_burn;
 mod nft_gate;
+mod nft_match_burn;
+mod nft_match_gate;
+mod nft_match_payment;
 mod nft_payment;
+mod nft_usage_limit;
+mod pause;
+mod pending_update;
 mod program_gate;
 mod redeemed_amount;
+mod signed_voucher;
 mod sol_payment;
+mod stake_gate;
 mod start_date;
+mod third_party_multisig;
 mod third_party_signer;
 mod token_burn;
 mod token_gate;
@@ -166,6 +197,13 @@ pub struct EvaluationContext<'a> {
 
     /// Convenience mapping of remaining account indices.
     pub indices: BTreeMap<&'a str, usize>,
+
+    /// Payments charged by the guards. Payment guards should add the amount charged
+    /// in the `pre_actions` step, which is reported in the mint event.
+    pub payments: Vec<Payment>,
+
+    /// The label of the group used in the mint.
+    pub label: Option<String>,
 }
 
 /// Utility function to try to get the account from the remaining accounts
@@ -0,0 +1,231 @@
+use super::*;
+
+use crate::{
+    errors::CandyGuardError,
+    state::GuardType,
+    utils::{
+        assert_is_any_token_account, assert_keys_equal, associated_token_address, cmp_pubkeys,
+        create_associated_token_account_idempotent, get_mint_decimals, mint_has_extension,
+        spl_token_2022, spl_token_transfer, spl_token_transfer_checked, TokenTransferParams,
+        NON_TRANSFERABLE_EXTENSION, TRANSFER_FEE_CONFIG_EXTENSION,
+    },
+};
+
+/// Guard that charges amounts in specified spl-tokens (one for each mint) as payment
+/// for the mint, transferred to the associated token accounts of destination wallets.
+///
+/// The mints can belong to either the spl-token or Token-2022 program. Token-2022
+/// mints with a transfer fee cannot be used as payment, since the destination would
+/// receive less than the configured amount; neither can non-transferable tokens.
+/// The associated token accounts of the destination wallets are created if they do
+/// not exist, with the rent paid by the payer.
+///
+/// List of accounts required (repeated for each payment):
+///
+///   0. `[writable]` Token account holding the required amount (any token
+///      account owned by the payer).
+///   1. `[]` Destination wallet to receive the tokens.
+///   2. `[writable]` Associated token account of the destination wallet (seeds
+///      `[destination pubkey, token program pubkey, mint pubkey]`).
+///   3. `[]` Token mint account.
+///   4. `[]` Token program account (spl-token or Token-2022).
+///
+/// followed by:
+///
+///   0. `[]` spl-associate-token program ID.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MultiTokenPayment {
+    /// Payments charged for the mint (one for each mint).
+    pub payments: Vec<TokenAmount>,
+}
+
+/// Amount of a token to be paid to a destination wallet.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TokenAmount {
+    pub amount: u64,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+}
+
+impl TokenAmount {
+    pub const SIZE: usize = 8 // amount
+        + 32 // mint
+        + 32; // destination
+}
+
+impl MultiTokenPayment {
+    /// Maximum number of payments.
+    pub const MAXIMUM_PAYMENTS: usize = 5;
+
+    /// Number of accounts required for each payment.
+    const ACCOUNTS_PER_PAYMENT: usize = 5;
+
+    fn verify_settings(&self) -> Result<()> {
+        if self.payments.is_empty() || self.payments.len() > Self::MAXIMUM_PAYMENTS {
+            return err!(CandyGuardError::InvalidTokenPaymentListSize);
+        }
+
+        for (i, payment) in self.payments.iter().enumerate() {
+            if self.payments[..i]
+                .iter()
+                .any(|other| other.mint == payment.mint)
+            {
+                return err!(CandyGuardError::DuplicatedPaymentMint);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Guard for MultiTokenPayment {
+    fn size() -> usize {
+        4 + (Self::MAXIMUM_PAYMENTS * TokenAmount::SIZE) // payments
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::MultiTokenPayment)
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(multi_token_payment) = &data.default.multi_token_payment {
+            multi_token_payment.verify_settings()?;
+        }
+
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if let Some(multi_token_payment) = &group.guards.multi_token_payment {
+                    multi_token_payment.verify_settings()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for MultiTokenPayment {
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.account_cursor;
+
+        for (i, payment) in self.payments.iter().enumerate() {
+            let offset = index + i * Self::ACCOUNTS_PER_PAYMENT;
+            // validates that we received all required accounts
+            let token_account_info = try_get_account_info(ctx, offset)?;
+            let destination = try_get_account_info(ctx, offset + 1)?;
+            let destination_ata = try_get_account_info(ctx, offset + 2)?;
+            let mint = try_get_account_info(ctx, offset + 3)?;
+            let token_program = try_get_account_info(ctx, offset + 4)?;
+
+            assert_keys_equal(destination.key, &payment.destination)?;
+            assert_keys_equal(mint.key, &payment.mint)?;
+
+            let token_account = assert_is_any_token_account(
+                token_account_info,
+                &ctx.accounts.payer.key(),
+                &payment.mint,
+            )?;
+
+            if token_account.amount < payment.amount {
+                return err!(CandyGuardError::NotEnoughTokens);
+            }
+
+            // the token program must match the program of the token account
+            assert_keys_equal(token_program.key, token_account_info.owner)?;
+
+            assert_keys_equal(
+                destination_ata.key,
+                &associated_token_address(destination.key, mint.key, token_program.key),
+            )?;
+
+            if cmp_pubkeys(token_program.key, &spl_token_2022::ID) {
+                if mint_has_extension(mint, NON_TRANSFERABLE_EXTENSION)? {
+                    return err!(CandyGuardError::NonTransferableToken);
+                }
+                // the fee can be changed by the fee authority at any time, so mints
+                // with the extension are rejected even if the current fee is zero
+                if mint_has_extension(mint, TRANSFER_FEE_CONFIG_EXTENSION)? {
+                    return err!(CandyGuardError::TransferFeeNotSupported);
+                }
+            }
+        }
+
+        let offset = index + self.payments.len() * Self::ACCOUNTS_PER_PAYMENT;
+        let ata_program = try_get_account_info(ctx, offset)?;
+        assert_keys_equal(ata_program.key, &spl_associated_token_account::ID)?;
+
+        evaluation_context.account_cursor += offset + 1 - index;
+
+        evaluation_context
+            .indices
+            .insert("multi_token_payment_index", index);
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.indices["multi_token_payment_index"];
+
+        for (i, payment) in self.payments.iter().enumerate() {
+            let offset = index + i * Self::ACCOUNTS_PER_PAYMENT;
+            // the accounts have already been validated
+            let token_account_info = try_get_account_info(ctx, offset)?;
+            let destination = try_get_account_info(ctx, offset + 1)?;
+            let destination_ata = try_get_account_info(ctx, offset + 2)?;
+            let mint = try_get_account_info(ctx, offset + 3)?;
+            let token_program = try_get_account_info(ctx, offset + 4)?;
+
+            // creates the ATA to receive the tokens (if it does not exist)
+
+            create_associated_token_account_idempotent(
+                ctx.accounts.payer.to_account_info(),
+                destination_ata.to_account_info(),
+                destination.to_account_info(),
+                mint.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                token_program.to_account_info(),
+            )?;
+
+            // transfers the tokens
+
+            let params = TokenTransferParams {
+                source: token_account_info.to_account_info(),
+                destination: destination_ata.to_account_info(),
+                authority: ctx.accounts.payer.to_account_info(),
+                authority_signer_seeds: &[],
+                token_program: token_program.to_account_info(),
+                amount: payment.amount,
+            };
+
+            if cmp_pubkeys(token_program.key, &spl_token_2022::ID) {
+                spl_token_transfer_checked(
+                    params,
+                    mint.to_account_info(),
+                    get_mint_decimals(mint)?,
+                )?;
+            } else {
+                spl_token_transfer(params)?;
+            }
+
+            evaluation_context.payments.push(Payment {
+                guard: GuardType::MultiTokenPayment,
+                mint: Some(payment.mint),
+                amount: payment.amount,
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -1,15 +1,23 @@
 use super::*;
 
-use mpl_token_metadata::{
-    instruction::burn_nft,
-    state::{Metadata, TokenMetadataAccount},
-};
+use mpl_token_metadata::instruction::burn_nft;
 use solana_program::program::invoke;
 
-use crate::{state::GuardType, utils::assert_keys_equal};
+use crate::{
+    programmable::{
+        self, find_account, load_metadata, token_record_address, ProgrammableNft,
+        ProgrammablePrograms,
+    },
+    state::GuardType,
+    utils::assert_keys_equal,
+};
 
 /// Guard that requires another NFT (token) from a specific collection to be burned.
 ///
+/// Programmable NFTs are burned using the token-metadata `Burn` instruction, which
+/// also requires the token record PDA of the NFT token account to be included in
+/// the remaining accounts.
+///
 /// List of accounts required:
 ///
 ///   0. `[writeable]` Token account of the NFT.
@@ -46,7 +54,7 @@ impl Condition for NftBurn {
         let nft_metadata = try_get_account_info(ctx, index + 1)?;
         evaluation_context.account_cursor += 2;
 
-        NftGate::verify_collection(
+        let metadata = NftGate::verify_collection(
             nft_account,
             nft_metadata,
             &self.required_collection,
@@ -58,9 +66,7 @@ impl Condition for NftBurn {
         let _nft_mint_collection_metadata = try_get_account_info(ctx, index + 4)?;
         evaluation_context.account_cursor += 3;
 
-        let metadata: Metadata = Metadata::from_account_info(nft_metadata)?;
         // validates the account information
-        assert_keys_equal(nft_metadata.owner, &mpl_token_metadata::id())?;
         assert_keys_equal(&metadata.mint, nft_mint_account.key)?;
 
         evaluation_context.indices.insert("nft_burn_index", index);
@@ -75,38 +81,81 @@ impl Condition for NftBurn {
         _guard_set: &GuardSet,
         evaluation_context: &mut EvaluationContext,
     ) -> Result<()> {
-        let index = evaluation_context.indices["nft_burn_index"];
-        let nft_account = try_get_account_info(ctx, index)?;
+        burn(ctx, evaluation_context.indices["nft_burn_index"])
+    }
+}
 
-        let nft_metadata = try_get_account_info(ctx, index + 1)?;
-        let nft_edition = try_get_account_info(ctx, index + 2)?;
-        let nft_mint_account = try_get_account_info(ctx, index + 3)?;
-        let nft_mint_collection_metadata = try_get_account_info(ctx, index + 4)?;
-
-        let burn_nft_infos = vec![
-            nft_metadata.to_account_info(),
-            ctx.accounts.payer.to_account_info(),
-            nft_mint_account.to_account_info(),
-            nft_account.to_account_info(),
-            nft_edition.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
-            nft_mint_collection_metadata.to_account_info(),
-        ];
-
-        invoke(
-            &burn_nft(
-                mpl_token_metadata::ID,
-                nft_metadata.key(),
-                ctx.accounts.payer.key(),
-                nft_mint_account.key(),
-                nft_account.key(),
-                nft_edition.key(),
-                ::spl_token::ID,
-                Some(nft_mint_collection_metadata.key()),
-            ),
-            burn_nft_infos.as_slice(),
+/// Burns the NFT which accounts start at the specified `index`.
+pub(crate) fn burn<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+    index: usize,
+) -> Result<()> {
+    let nft_account = try_get_account_info(ctx, index)?;
+
+    let nft_metadata = try_get_account_info(ctx, index + 1)?;
+    let nft_edition = try_get_account_info(ctx, index + 2)?;
+    let nft_mint_account = try_get_account_info(ctx, index + 3)?;
+    let nft_mint_collection_metadata = try_get_account_info(ctx, index + 4)?;
+
+    let (metadata, programmable) = load_metadata(nft_metadata)?;
+    // the collection metadata is only required for NFTs with a verified collection
+    let collection_metadata = match metadata.collection {
+        Some(collection) if collection.verified => Some(nft_mint_collection_metadata),
+        _ => None,
+    };
+
+    if programmable.is_some() {
+        let token_record = find_account(
+            ctx.remaining_accounts,
+            &token_record_address(nft_mint_account.key, nft_account.key),
         )?;
 
-        Ok(())
+        return programmable::burn(
+            &ProgrammablePrograms {
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                sysvar_instructions: ctx.accounts.instruction_sysvar_account.to_account_info(),
+                spl_token_program: ctx.accounts.token_program.to_account_info(),
+                authorization_rules: None,
+            },
+            &ProgrammableNft {
+                mint: nft_mint_account.to_account_info(),
+                metadata: nft_metadata.to_account_info(),
+                master_edition: nft_edition.to_account_info(),
+                token: nft_account.to_account_info(),
+                token_record: token_record.to_account_info(),
+            },
+            &ctx.accounts.payer.to_account_info(),
+            collection_metadata,
+        );
     }
+
+    let mut burn_nft_infos = vec![
+        nft_metadata.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        nft_mint_account.to_account_info(),
+        nft_account.to_account_info(),
+        nft_edition.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+    ];
+
+    if let Some(collection_metadata) = collection_metadata {
+        burn_nft_infos.push(collection_metadata.to_account_info());
+    }
+
+    invoke(
+        &burn_nft(
+            mpl_token_metadata::ID,
+            nft_metadata.key(),
+            ctx.accounts.payer.key(),
+            nft_mint_account.key(),
+            nft_account.key(),
+            nft_edition.key(),
+            ::spl_token::ID,
+            collection_metadata.map(|account| account.key()),
+        ),
+        burn_nft_infos.as_slice(),
+    )?;
+
+    Ok(())
 }
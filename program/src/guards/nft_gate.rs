@@ -1,10 +1,11 @@
 use super::*;
 use crate::{
     errors::CandyGuardError,
+    programmable::load_metadata,
     state::GuardType,
     utils::{assert_is_token_account, assert_keys_equal},
 };
-use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+use mpl_token_metadata::state::Metadata;
 
 /// Guard that restricts the transaction to holders of a specified collection.
 ///
@@ -46,22 +47,27 @@ impl Condition for NftGate {
             nft_metadata,
             &self.required_collection,
             ctx.accounts.payer.key,
-        )
+        )?;
+
+        Ok(())
     }
 }
 
 impl NftGate {
+    /// Checks that the `owner` holds an NFT of the verified `collection`, returning
+    /// the metadata of the NFT.
     pub fn verify_collection(
         nft_account: &AccountInfo,
         nft_metadata: &AccountInfo,
         collection: &Pubkey,
         owner: &Pubkey,
-    ) -> Result<()> {
-        let metadata: Metadata = Metadata::from_account_info(nft_metadata)?;
+    ) -> Result<Metadata> {
+        // programmable NFTs are also supported
+        let (metadata, _) = load_metadata(nft_metadata)?;
         // validates the metadata information
         assert_keys_equal(nft_metadata.owner, &mpl_token_metadata::id())?;
 
-        match metadata.collection {
+        match &metadata.collection {
             Some(c) if c.verified && c.key == *collection => Ok(()),
             _ => Err(CandyGuardError::InvalidNftCollection),
         }?;
@@ -72,6 +78,6 @@ impl NftGate {
             return err!(CandyGuardError::MissingNft);
         }
 
-        Ok(())
+        Ok(metadata)
     }
 }
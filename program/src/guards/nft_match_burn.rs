@@ -0,0 +1,124 @@
+use super::*;
+
+use crate::{guards::nft_burn::burn, state::GuardType};
+
+/// Guard that requires `amount` NFTs (tokens) matching the `required` criteria (see
+/// [`NftMatcher`]) to be burned.
+///
+/// List of accounts required (repeated for each NFT to burn):
+///
+///   0. `[writeable]` Token account of the NFT.
+///   1. `[writeable]` Metadata account of the NFT.
+///   2. `[writeable]` Master Edition account of the NFT.
+///   3. `[writeable]` Mint account of the NFT.
+///   4. `[writeable]` Collection metadata account of the NFT (ignored when the NFT
+///      does not have a verified collection).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct NftMatchBurn {
+    /// Criteria to match the NFT.
+    pub required: NftMatcher,
+    /// Number of NFTs to burn.
+    pub amount: u8,
+}
+
+impl NftMatchBurn {
+    /// Number of accounts required for each NFT.
+    const ACCOUNTS_PER_NFT: usize = 5;
+
+    fn verify_settings(&self) -> Result<()> {
+        self.required.verify()?;
+
+        if self.amount == 0 {
+            return err!(CandyGuardError::InvalidNftAmount);
+        }
+
+        Ok(())
+    }
+}
+
+impl Guard for NftMatchBurn {
+    fn size() -> usize {
+        NftMatcher::size() // required
+        + 1 // amount
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::NftMatchBurn)
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(nft_match_burn) = &data.default.nft_match_burn {
+            nft_match_burn.verify_settings()?;
+        }
+
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if let Some(nft_match_burn) = &group.guards.nft_match_burn {
+                    nft_match_burn.verify_settings()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for NftMatchBurn {
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.account_cursor;
+        let mut mints = Vec::with_capacity(self.amount as usize);
+
+        for i in 0..self.amount as usize {
+            let offset = index + i * Self::ACCOUNTS_PER_NFT;
+            // validates that we received all required accounts
+            let nft_account = try_get_account_info(ctx, offset)?;
+            let nft_metadata = try_get_account_info(ctx, offset + 1)?;
+            let nft_edition = try_get_account_info(ctx, offset + 2)?;
+            let nft_mint_account = try_get_account_info(ctx, offset + 3)?;
+            let _nft_mint_collection_metadata = try_get_account_info(ctx, offset + 4)?;
+
+            NftMatchGate::verify_nft(
+                nft_account,
+                nft_metadata,
+                nft_mint_account,
+                nft_edition,
+                &self.required,
+                ctx.accounts.payer.key,
+            )?;
+
+            if mints.contains(nft_mint_account.key) {
+                return err!(CandyGuardError::DuplicatedNftMint);
+            }
+            mints.push(*nft_mint_account.key);
+        }
+
+        evaluation_context.account_cursor += self.amount as usize * Self::ACCOUNTS_PER_NFT;
+        evaluation_context
+            .indices
+            .insert("nft_match_burn_index", index);
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.indices["nft_match_burn_index"];
+
+        for i in 0..self.amount as usize {
+            burn(ctx, index + i * Self::ACCOUNTS_PER_NFT)?;
+        }
+
+        Ok(())
+    }
+}
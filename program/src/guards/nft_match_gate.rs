@@ -0,0 +1,191 @@
+use super::*;
+use crate::{
+    errors::CandyGuardError,
+    programmable::{load_metadata, master_edition_address},
+    state::GuardType,
+    utils::{
+        assert_initialized, assert_is_token_account, assert_keys_equal, assert_owned_by,
+        cmp_pubkeys,
+    },
+};
+use mpl_token_metadata::state::{MasterEditionV2, Metadata, TokenMetadataAccount};
+use spl_token::state::Mint as SplMint;
+
+// Maximum number of collections in the matcher list.
+const MAXIMUM_COLLECTIONS: usize = 5;
+
+/// Guard that restricts the transaction to holders of an NFT matching the
+/// `required` criteria (see [`NftMatcher`]).
+///
+/// List of accounts required:
+///
+///   0. `[]` Token account of the NFT.
+///   1. `[]` Metadata account of the NFT.
+///   2. `[]` Mint account of the NFT.
+///   3. `[]` Master Edition account of the NFT.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct NftMatchGate {
+    /// Criteria to match the NFT.
+    pub required: NftMatcher,
+}
+
+impl Guard for NftMatchGate {
+    fn size() -> usize {
+        NftMatcher::size() // required
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::NftMatchGate)
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(nft_match_gate) = &data.default.nft_match_gate {
+            nft_match_gate.required.verify()?;
+        }
+
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if let Some(nft_match_gate) = &group.guards.nft_match_gate {
+                    nft_match_gate.required.verify()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for NftMatchGate {
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.account_cursor;
+        // validates that we received all required accounts
+        let nft_account = try_get_account_info(ctx, index)?;
+        let nft_metadata = try_get_account_info(ctx, index + 1)?;
+        let nft_mint = try_get_account_info(ctx, index + 2)?;
+        let nft_master_edition = try_get_account_info(ctx, index + 3)?;
+        evaluation_context.account_cursor += 4;
+
+        Self::verify_nft(
+            nft_account,
+            nft_metadata,
+            nft_mint,
+            nft_master_edition,
+            &self.required,
+            ctx.accounts.payer.key,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl NftMatchGate {
+    /// Checks that the `owner` holds an NFT matching the `required` criteria, returning
+    /// the metadata of the NFT.
+    ///
+    /// The token must be an NFT: its mint must have a supply of 1 and it must have a
+    /// master edition, so fungible and semi-fungible tokens with the same metadata
+    /// criteria are rejected.
+    pub fn verify_nft(
+        nft_account: &AccountInfo,
+        nft_metadata: &AccountInfo,
+        nft_mint: &AccountInfo,
+        nft_master_edition: &AccountInfo,
+        required: &NftMatcher,
+        owner: &Pubkey,
+    ) -> Result<Metadata> {
+        // programmable NFTs are also supported
+        let (metadata, _) = load_metadata(nft_metadata)?;
+        // validates the metadata information
+        assert_keys_equal(nft_metadata.owner, &mpl_token_metadata::id())?;
+
+        required.assert_matches(&metadata)?;
+
+        // validates that the token is an NFT
+        assert_keys_equal(nft_mint.key, &metadata.mint)?;
+        assert_owned_by(nft_mint, &spl_token::ID)?;
+
+        if assert_initialized::<SplMint>(nft_mint)?.supply != 1 {
+            return err!(CandyGuardError::InvalidNft);
+        }
+
+        assert_keys_equal(
+            nft_master_edition.key,
+            &master_edition_address(nft_mint.key),
+        )?;
+        assert_owned_by(nft_master_edition, &mpl_token_metadata::id())?;
+        // fails when the account is not a master edition (e.g., a print edition)
+        MasterEditionV2::from_account_info(nft_master_edition)
+            .map_err(|_| error!(CandyGuardError::InvalidNft))?;
+
+        let account = assert_is_token_account(nft_account, owner, &metadata.mint)?;
+
+        if account.amount < 1 {
+            return err!(CandyGuardError::MissingNft);
+        }
+
+        Ok(metadata)
+    }
+}
+
+/// Criteria to match the NFTs accepted by the NFT match guards.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum NftMatcher {
+    /// NFT of any of the collections in the list (the collection must be verified).
+    Collections(Vec<Pubkey>),
+    /// NFT with the specified address as the first creator (the creator must be verified).
+    FirstCreator(Pubkey),
+    /// NFT with the specified update authority. The update authority is not a verified
+    /// field: it does not need to sign when the metadata is created, so any NFT can set
+    /// it to any address.
+    UpdateAuthority(Pubkey),
+}
+
+impl NftMatcher {
+    pub fn size() -> usize {
+        1 // variant
+        + 4 + (MAXIMUM_COLLECTIONS * 32) // largest variant (collections)
+    }
+
+    /// Validates the matcher settings.
+    pub fn verify(&self) -> Result<()> {
+        if let NftMatcher::Collections(collections) = self {
+            if collections.is_empty() || collections.len() > MAXIMUM_COLLECTIONS {
+                return err!(CandyGuardError::InvalidCollectionListSize);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the NFT `metadata` matches the criteria.
+    pub fn assert_matches(&self, metadata: &Metadata) -> Result<()> {
+        match self {
+            NftMatcher::Collections(collections) => match &metadata.collection {
+                Some(c) if c.verified && collections.contains(&c.key) => Ok(()),
+                _ => err!(CandyGuardError::InvalidNftCollection),
+            },
+            NftMatcher::FirstCreator(creator) => match metadata
+                .data
+                .creators
+                .as_ref()
+                .and_then(|creators| creators.first())
+            {
+                Some(c) if c.verified && c.address == *creator => Ok(()),
+                _ => err!(CandyGuardError::InvalidNftCreator),
+            },
+            NftMatcher::UpdateAuthority(update_authority) => {
+                if cmp_pubkeys(&metadata.update_authority, update_authority) {
+                    Ok(())
+                } else {
+                    err!(CandyGuardError::InvalidNftUpdateAuthority)
+                }
+            }
+        }
+    }
+}
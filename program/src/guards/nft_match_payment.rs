@@ -0,0 +1,140 @@
+use super::*;
+use crate::{
+    guards::nft_payment::{assert_destination, transfer},
+    state::GuardType,
+};
+
+/// Guard that charges `amount` NFTs (tokens) matching the `required` criteria (see
+/// [`NftMatcher`]) as payment for the mint.
+///
+/// List of accounts required (repeated for each NFT to transfer):
+///
+///   0. `[writeable]` Token account of the NFT.
+///   1. `[writeable]` Metadata account of the NFT.
+///   2. `[]` Mint account of the NFT.
+///   3. `[]` Account to receive the NFT.
+///   4. `[writeable]` Destination PDA key (seeds [destination pubkey, token program id, nft mint pubkey]).
+///   5. `[]` spl-associate-token program ID.
+///   6. `[]` Master Edition account of the NFT.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct NftMatchPayment {
+    /// Criteria to match the NFT.
+    pub required: NftMatcher,
+    pub destination: Pubkey,
+    /// Number of NFTs to transfer.
+    pub amount: u8,
+}
+
+impl NftMatchPayment {
+    /// Number of accounts required for each NFT.
+    const ACCOUNTS_PER_NFT: usize = 7;
+
+    fn verify_settings(&self) -> Result<()> {
+        self.required.verify()?;
+
+        if self.amount == 0 {
+            return err!(CandyGuardError::InvalidNftAmount);
+        }
+
+        Ok(())
+    }
+}
+
+impl Guard for NftMatchPayment {
+    fn size() -> usize {
+        NftMatcher::size() // required
+        + 32 // destination
+        + 1 // amount
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::NftMatchPayment)
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(nft_match_payment) = &data.default.nft_match_payment {
+            nft_match_payment.verify_settings()?;
+        }
+
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if let Some(nft_match_payment) = &group.guards.nft_match_payment {
+                    nft_match_payment.verify_settings()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for NftMatchPayment {
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.account_cursor;
+        let mut mints = Vec::with_capacity(self.amount as usize);
+
+        for i in 0..self.amount as usize {
+            let offset = index + i * Self::ACCOUNTS_PER_NFT;
+            // validates that we received all required accounts
+
+            let nft_account = try_get_account_info(ctx, offset)?;
+            let nft_metadata = try_get_account_info(ctx, offset + 1)?;
+            let nft_mint = try_get_account_info(ctx, offset + 2)?;
+            let destination = try_get_account_info(ctx, offset + 3)?;
+            let destination_ata = try_get_account_info(ctx, offset + 4)?;
+            let _atoken_program = try_get_account_info(ctx, offset + 5)?;
+            let nft_master_edition = try_get_account_info(ctx, offset + 6)?;
+
+            NftMatchGate::verify_nft(
+                nft_account,
+                nft_metadata,
+                nft_mint,
+                nft_master_edition,
+                &self.required,
+                ctx.accounts.payer.key,
+            )?;
+
+            if mints.contains(nft_mint.key) {
+                return err!(CandyGuardError::DuplicatedNftMint);
+            }
+            mints.push(*nft_mint.key);
+
+            assert_destination(destination, destination_ata, nft_mint, &self.destination)?;
+        }
+
+        evaluation_context.account_cursor += self.amount as usize * Self::ACCOUNTS_PER_NFT;
+        evaluation_context
+            .indices
+            .insert("nft_match_payment_index", index);
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.indices["nft_match_payment_index"];
+
+        for i in 0..self.amount as usize {
+            let nft_mint = transfer(ctx, index + i * Self::ACCOUNTS_PER_NFT, &self.destination)?;
+
+            evaluation_context.payments.push(Payment {
+                guard: GuardType::NftMatchPayment,
+                mint: Some(nft_mint),
+                amount: 1,
+            });
+        }
+
+        Ok(())
+    }
+}
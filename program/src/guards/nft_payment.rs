@@ -1,15 +1,24 @@
 use super::*;
 use crate::{
+    programmable::{
+        self, find_account, find_authorization_rules, load_metadata, master_edition_address,
+        token_record_address, ProgrammableNft, ProgrammablePrograms, TransferDestination,
+    },
     state::GuardType,
     utils::{assert_keys_equal, spl_token_transfer, TokenTransferParams},
 };
-use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
 use solana_program::program::invoke;
 use spl_associated_token_account::instruction::create_associated_token_account;
 
 /// Guard that charges another NFT (token) from a specific collection as payment
 /// for the mint.
 ///
+/// Programmable NFTs are transferred using the token-metadata `Transfer` instruction,
+/// which also requires the master edition of the NFT, the token record PDAs of the
+/// source and destination token accounts and, if the NFT has a rule set, the
+/// token-auth-rules program and rule set accounts to be included in the remaining
+/// accounts.
+///
 /// List of accounts required:
 ///
 ///   0. `[writeable]` Token account of the NFT.
@@ -52,14 +61,13 @@ impl Condition for NftPayment {
         let nft_mint = try_get_account_info(ctx, index + 2)?;
         evaluation_context.account_cursor += 3;
 
-        NftGate::verify_collection(
+        let metadata = NftGate::verify_collection(
             nft_account,
             nft_metadata,
             &self.required_collection,
             ctx.accounts.payer.key,
         )?;
 
-        let metadata: Metadata = Metadata::from_account_info(nft_metadata)?;
         assert_keys_equal(&metadata.mint, nft_mint.key)?;
 
         let destination = try_get_account_info(ctx, index + 3)?;
@@ -67,18 +75,7 @@ impl Condition for NftPayment {
         let _atoken_program = try_get_account_info(ctx, index + 5)?;
         evaluation_context.account_cursor += 3;
 
-        assert_keys_equal(destination.key, &self.destination)?;
-
-        let (ata, _) = Pubkey::find_program_address(
-            &[
-                destination.key.as_ref(),
-                spl_token::ID.as_ref(),
-                nft_mint.key.as_ref(),
-            ],
-            &spl_associated_token_account::ID,
-        );
-
-        assert_keys_equal(destination_ata.key, &ata)?;
+        assert_destination(destination, destination_ata, nft_mint, &self.destination)?;
 
         evaluation_context
             .indices
@@ -95,17 +92,99 @@ impl Condition for NftPayment {
         evaluation_context: &mut EvaluationContext,
     ) -> Result<()> {
         let index = evaluation_context.indices["nft_payment_index"];
-        let nft_account = try_get_account_info(ctx, index)?;
-        let nft_mint = try_get_account_info(ctx, index + 2)?;
-        let destination = try_get_account_info(ctx, index + 3)?;
-        let destination_ata = try_get_account_info(ctx, index + 4)?;
+        let nft_mint = transfer(ctx, index, &self.destination)?;
 
+        evaluation_context.payments.push(Payment {
+            guard: GuardType::NftPayment,
+            mint: Some(nft_mint),
+            amount: 1,
+        });
+
+        Ok(())
+    }
+}
+
+/// Checks that the `destination` account matches the `destination_wallet` and that the
+/// `destination_ata` is its associated token account of the NFT mint.
+pub(crate) fn assert_destination(
+    destination: &AccountInfo,
+    destination_ata: &AccountInfo,
+    nft_mint: &AccountInfo,
+    destination_wallet: &Pubkey,
+) -> Result<()> {
+    assert_keys_equal(destination.key, destination_wallet)?;
+
+    let (ata, _) = Pubkey::find_program_address(
+        &[
+            destination.key.as_ref(),
+            spl_token::ID.as_ref(),
+            nft_mint.key.as_ref(),
+        ],
+        &spl_associated_token_account::ID,
+    );
+
+    assert_keys_equal(destination_ata.key, &ata)
+}
+
+/// Transfers the NFT which accounts start at the specified `index` to the `destination_wallet`.
+/// Returns the mint of the NFT.
+pub(crate) fn transfer<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+    index: usize,
+    destination_wallet: &Pubkey,
+) -> Result<Pubkey> {
+    let nft_account = try_get_account_info(ctx, index)?;
+    let nft_mint = try_get_account_info(ctx, index + 2)?;
+    let destination = try_get_account_info(ctx, index + 3)?;
+    let destination_ata = try_get_account_info(ctx, index + 4)?;
+
+    let nft_metadata = try_get_account_info(ctx, index + 1)?;
+    let (_, programmable) = load_metadata(nft_metadata)?;
+
+    if let Some(config) = programmable {
+        let accounts = ctx.remaining_accounts;
+        let master_edition = find_account(accounts, &master_edition_address(nft_mint.key))?;
+        let token_record = find_account(
+            accounts,
+            &token_record_address(nft_mint.key, nft_account.key),
+        )?;
+        let destination_token_record = find_account(
+            accounts,
+            &token_record_address(nft_mint.key, destination_ata.key),
+        )?;
+        let spl_ata_program = try_get_account_info(ctx, index + 5)?;
+
+        // the destination token account is created by the transfer
+        programmable::transfer(
+            &ProgrammablePrograms {
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                sysvar_instructions: ctx.accounts.instruction_sysvar_account.to_account_info(),
+                spl_token_program: ctx.accounts.token_program.to_account_info(),
+                authorization_rules: find_authorization_rules(accounts, &config)?,
+            },
+            &ProgrammableNft {
+                mint: nft_mint.to_account_info(),
+                metadata: nft_metadata.to_account_info(),
+                master_edition: master_edition.to_account_info(),
+                token: nft_account.to_account_info(),
+                token_record: token_record.to_account_info(),
+            },
+            &ctx.accounts.payer.to_account_info(),
+            &TransferDestination {
+                owner: destination.to_account_info(),
+                token: destination_ata.to_account_info(),
+                token_record: destination_token_record.to_account_info(),
+                spl_ata_program: spl_ata_program.to_account_info(),
+            },
+        )?;
+    } else {
         // creates the ATA to receive the NFT
 
         invoke(
             &create_associated_token_account(
                 ctx.accounts.payer.key,
-                &self.destination,
+                destination_wallet,
                 nft_mint.key,
             ),
             &[
@@ -128,7 +207,7 @@ impl Condition for NftPayment {
             // fixed to always require 1 NFT
             amount: 1,
         })?;
-
-        Ok(())
     }
+
+    Ok(nft_mint.key())
 }
@@ -0,0 +1,184 @@
+use solana_program::{program::invoke_signed, system_instruction};
+
+use super::*;
+use crate::{
+    errors::CandyGuardError,
+    state::GuardType,
+    utils::{assert_keys_equal, assert_owned_by},
+};
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+
+/// Guard that restricts the transaction to holders of an NFT of a specified
+/// collection, where each NFT can only be used to mint `limit` times. The number
+/// of uses is tracked by a PDA for each NFT mint, independently of the wallet
+/// holding the NFT.
+///
+/// List of accounts required:
+///
+///   0. `[]` Token account of the NFT.
+///   1. `[]` Metadata account of the NFT.
+///   2. `[writable]` NFT usage counter PDA (seeds `["nft_usage_limit", candy guard
+///      pubkey, nft mint pubkey]`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct NftUsageLimit {
+    pub required_collection: Pubkey,
+    /// Maximum number of mints for each NFT of the collection.
+    pub limit: u16,
+}
+
+impl Guard for NftUsageLimit {
+    fn size() -> usize {
+        32  // required_collection
+        + 2 // limit
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::NftUsageLimit)
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(nft_usage_limit) = &data.default.nft_usage_limit {
+            nft_usage_limit.verify_limit()?;
+        }
+
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if let Some(nft_usage_limit) = &group.guards.nft_usage_limit {
+                    nft_usage_limit.verify_limit()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for NftUsageLimit {
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.account_cursor;
+        // validates that we received all required accounts
+        let nft_account = try_get_account_info(ctx, index)?;
+        let nft_metadata = try_get_account_info(ctx, index + 1)?;
+        let counter = try_get_account_info(ctx, index + 2)?;
+        evaluation_context.account_cursor += 3;
+
+        let metadata = NftGate::verify_collection(
+            nft_account,
+            nft_metadata,
+            &self.required_collection,
+            ctx.accounts.payer.key,
+        )?;
+
+        let (pda, _) = NftUsageCounter::address(&ctx.accounts.candy_guard.key(), &metadata.mint);
+        assert_keys_equal(counter.key, &pda)?;
+
+        let count = if counter.data_is_empty() {
+            0
+        } else {
+            // check the owner of the account
+            assert_owned_by(counter, &crate::ID)?;
+            NftUsageCounter::try_from_slice(&counter.data.borrow())?.count
+        };
+
+        if count >= self.limit {
+            return err!(CandyGuardError::NftUsageLimitReached);
+        }
+
+        evaluation_context
+            .indices
+            .insert("nft_usage_limit_index", index);
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.indices["nft_usage_limit_index"];
+        // the accounts have already been validated
+        let nft_metadata = try_get_account_info(ctx, index + 1)?;
+        let counter = try_get_account_info(ctx, index + 2)?;
+
+        if counter.data_is_empty() {
+            let nft_mint = Metadata::from_account_info(nft_metadata)?.mint;
+            let candy_guard_key = ctx.accounts.candy_guard.key();
+            let (pda, bump) = NftUsageCounter::address(&candy_guard_key, &nft_mint);
+
+            let rent = Rent::get()?;
+            let signer = [
+                NftUsageCounter::PREFIX_SEED,
+                candy_guard_key.as_ref(),
+                nft_mint.as_ref(),
+                &[bump],
+            ];
+
+            invoke_signed(
+                &system_instruction::create_account(
+                    ctx.accounts.payer.key,
+                    &pda,
+                    rent.minimum_balance(NftUsageCounter::SIZE),
+                    NftUsageCounter::SIZE as u64,
+                    &crate::ID,
+                ),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    counter.to_account_info(),
+                ],
+                &[&signer],
+            )?;
+        }
+
+        let mut account_data = counter.try_borrow_mut_data()?;
+        let mut usage_counter = NftUsageCounter::try_from_slice(&account_data)?;
+        usage_counter.count += 1;
+        // saves the changes back to the pda
+        let data = &mut usage_counter.try_to_vec().unwrap();
+        account_data[0..data.len()].copy_from_slice(data);
+
+        Ok(())
+    }
+}
+
+impl NftUsageLimit {
+    fn verify_limit(&self) -> Result<()> {
+        // a limit of 0 would block every NFT of the collection
+        if self.limit == 0 {
+            return err!(CandyGuardError::InvalidNftUsageLimit);
+        }
+
+        Ok(())
+    }
+}
+
+/// PDA to track the number of mints unlocked by an individual NFT.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct NftUsageCounter {
+    pub count: u16,
+}
+
+impl NftUsageCounter {
+    /// Prefix used as seed.
+    pub const PREFIX_SEED: &'static [u8] = b"nft_usage_limit";
+
+    /// Size of the account.
+    pub const SIZE: usize = 2; // count
+
+    /// Returns the PDA address of the usage counter of the NFT for the specified
+    /// candy guard.
+    pub fn address(candy_guard: &Pubkey, nft_mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::PREFIX_SEED, candy_guard.as_ref(), nft_mint.as_ref()],
+            &crate::ID,
+        )
+    }
+}
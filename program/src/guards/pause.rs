@@ -0,0 +1,77 @@
+use super::*;
+use crate::state::GuardType;
+
+/// Guard that pauses the mint across all groups and, optionally, the route
+/// instructions of selected guards. The guard is managed by the `pause` and `resume`
+/// instructions and it is only allowed on the default guard set.
+///
+/// The pause is checked before any other guard is validated, therefore no bot tax is
+/// charged while the mint is paused.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct Pause {
+    /// Code representing the reason of the pause (`0` when not specified).
+    pub reason: u32,
+    /// Bitmask of the guard types (see `GuardType::as_mask`) whose route instructions
+    /// are also paused.
+    pub routes: u64,
+}
+
+impl Guard for Pause {
+    fn size() -> usize {
+        4   // reason
+        + 8 // routes
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::Pause)
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if group.guards.pause.is_some() {
+                    return err!(CandyGuardError::GuardNotAllowedInGroup);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for Pause {
+    fn validate<'info>(
+        &self,
+        _ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        _evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        // the pause is checked by the mint instruction before any guard validation
+        Ok(())
+    }
+}
+
+impl Pause {
+    /// Checks that the mint is not paused.
+    pub fn assert_mint_allowed(default: &GuardSet) -> Result<()> {
+        if let Some(pause) = &default.pause {
+            msg!("Mint paused (reason code {})", pause.reason);
+            return err!(CandyGuardError::MintPaused);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the route instruction of the specified guard type is not paused.
+    pub fn assert_route_allowed(default: &GuardSet, guard_type: GuardType) -> Result<()> {
+        if let Some(pause) = &default.pause {
+            if pause.routes & GuardType::as_mask(guard_type) > 0 {
+                msg!("Route paused (reason code {})", pause.reason);
+                return err!(CandyGuardError::RoutePaused);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use super::*;
+use crate::state::GuardType;
+
+/// Guard that signals that a configuration change is scheduled. The guard is managed
+/// by the `schedule_update` instruction and it is only allowed on the default guard set.
+///
+/// Once the `activation` time is reached, the `mint` and `route` instructions use the
+/// configuration stored in the scheduled update PDA (seeds `["scheduled_update",
+/// candy guard pubkey]`), which must be included in the list of remaining accounts.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PendingUpdate {
+    pub activation: i64,
+}
+
+impl Guard for PendingUpdate {
+    fn size() -> usize {
+        8 // activation
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::PendingUpdate)
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if group.guards.pending_update.is_some() {
+                    return err!(CandyGuardError::GuardNotAllowedInGroup);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for PendingUpdate {
+    fn validate<'info>(
+        &self,
+        _ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        _evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        // nothing to validate: the scheduled configuration is resolved when the active
+        // guard set is loaded
+        Ok(())
+    }
+}
+
+impl PendingUpdate {
+    /// Returns whether the scheduled update is active or not.
+    pub fn is_active(&self, current_timestamp: i64) -> bool {
+        current_timestamp >= self.activation
+    }
+}
@@ -1,10 +1,11 @@
-use solana_program::{
-    serialize_utils::{read_pubkey, read_u16},
-    system_program,
-};
+use solana_program::system_program;
 
 use super::*;
-use crate::{errors::CandyGuardError, state::GuardType, utils::cmp_pubkeys};
+use crate::{
+    errors::CandyGuardError,
+    state::GuardType,
+    utils::{cmp_pubkeys, InstructionsSysvarReader},
+};
 
 // Default list of authorized programs.
 pub static DEFAULT_PROGRAMS: &[Pubkey] = &[
@@ -77,22 +78,10 @@ impl Condition for ProgramGate {
 
 pub fn verify_programs(sysvar: AccountInfo, programs: &[Pubkey]) -> Result<()> {
     let sysvar_data = sysvar.data.borrow();
+    let reader = InstructionsSysvarReader::new(&sysvar_data)?;
 
-    let mut index = 0;
-    // determines the total number of instructions in the transaction
-    let num_instructions =
-        read_u16(&mut index, &sysvar_data).map_err(|_| ProgramError::InvalidAccountData)?;
-
-    'outer: for index in 0..num_instructions {
-        let mut offset = 2 + (index * 2) as usize;
-
-        // offset for the number of accounts
-        offset = read_u16(&mut offset, &sysvar_data).unwrap() as usize;
-        let num_accounts = read_u16(&mut offset, &sysvar_data).unwrap();
-
-        // offset for the program id
-        offset += (num_accounts as usize) * (1 + 32);
-        let program_id = read_pubkey(&mut offset, &sysvar_data).unwrap();
+    'outer: for instruction in reader.instructions() {
+        let program_id = instruction?.program_id;
 
         for program in programs {
             if cmp_pubkeys(&program_id, program) {
@@ -0,0 +1,235 @@
+use solana_program::ed25519_program;
+
+use super::*;
+use crate::{
+    errors::CandyGuardError,
+    state::{GuardType, MAX_LABEL_SIZE},
+    utils::{
+        assert_keys_equal, cmp_pubkeys, create_pda_account, fixed_length_string,
+        InstructionsSysvarReader,
+    },
+};
+
+// Size of the voucher message: payer + candy guard + label + nonce + expiry.
+const MESSAGE_SIZE: usize = 32 + 32 + MAX_LABEL_SIZE + 8 + 8;
+
+// Size of the signature offsets of the ed25519 instruction.
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+
+// Offset of the first signature offsets of the ed25519 instruction (after the
+// number of signatures and padding bytes).
+const SIGNATURE_OFFSETS_START: usize = 2;
+
+/// Guard that requires a voucher signed off-chain by the `signer_key`. The voucher
+/// is a message with the following layout:
+///
+///   - `[u8; 32]` payer pubkey
+///   - `[u8; 32]` candy guard pubkey
+///   - `[u8; 6]` group label (padded with `\0`; all zeros for the default guard set)
+///   - `u64` nonce (little-endian)
+///   - `i64` expiry timestamp (little-endian)
+///
+/// The signature is verified by an ed25519 program instruction in the same
+/// transaction, which must include the signature, public key and message in its
+/// own instruction data. A nonce PDA is created on mint to prevent the voucher
+/// from being used again.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` Voucher nonce PDA. The PDA is derived using the seed
+///      `["signed_voucher", candy guard pubkey, nonce (u64 little-endian)]`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SignedVoucher {
+    pub signer_key: Pubkey,
+}
+
+/// Voucher information extracted from the signed message.
+struct Voucher {
+    nonce: u64,
+    expiry: i64,
+}
+
+impl Guard for SignedVoucher {
+    fn size() -> usize {
+        32 // signer_key
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::SignedVoucher)
+    }
+}
+
+impl Condition for SignedVoucher {
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let voucher = self.find_voucher(ctx, &evaluation_context.label)?;
+
+        if Clock::get()?.unix_timestamp > voucher.expiry {
+            return err!(CandyGuardError::VoucherExpired);
+        }
+
+        let nonce_account = try_get_account_info(ctx, evaluation_context.account_cursor)?;
+        evaluation_context
+            .indices
+            .insert("signed_voucher_index", evaluation_context.account_cursor);
+        evaluation_context.account_cursor += 1;
+
+        let (pda, _) = Self::nonce_address(&ctx.accounts.candy_guard.key(), voucher.nonce);
+        assert_keys_equal(nonce_account.key, &pda)?;
+
+        if !nonce_account.data_is_empty() {
+            return err!(CandyGuardError::VoucherAlreadyUsed);
+        }
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let nonce_account =
+            try_get_account_info(ctx, evaluation_context.indices["signed_voucher_index"])?;
+        let voucher = self.find_voucher(ctx, &evaluation_context.label)?;
+
+        let candy_guard_key = ctx.accounts.candy_guard.key();
+        let nonce = voucher.nonce.to_le_bytes();
+        let (pda, bump) = Self::nonce_address(&candy_guard_key, voucher.nonce);
+        assert_keys_equal(nonce_account.key, &pda)?;
+
+        // a replayed voucher has its nonce account already initialized
+        if !nonce_account.data_is_empty() {
+            return err!(CandyGuardError::VoucherAlreadyUsed);
+        }
+
+        let signer = [
+            Self::PREFIX_SEED,
+            candy_guard_key.as_ref(),
+            nonce.as_ref(),
+            &[bump],
+        ];
+
+        // marks the nonce as used; the account is created even if the address already
+        // holds lamports, so sending lamports to it cannot block the voucher
+        create_pda_account(
+            &ctx.accounts.payer.to_account_info(),
+            &nonce_account.to_account_info(),
+            1,
+            &crate::ID,
+            &signer,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl SignedVoucher {
+    /// Prefix used as seed.
+    pub const PREFIX_SEED: &'static [u8] = b"signed_voucher";
+
+    /// Returns the PDA address of the nonce for the specified candy guard.
+    pub fn nonce_address(candy_guard: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                Self::PREFIX_SEED,
+                candy_guard.as_ref(),
+                nonce.to_le_bytes().as_ref(),
+            ],
+            &crate::ID,
+        )
+    }
+
+    /// Looks for an ed25519 program instruction in the transaction with a voucher
+    /// signed by the `signer_key` for the payer, candy guard and group label.
+    fn find_voucher<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        label: &Option<String>,
+    ) -> Result<Voucher> {
+        let label = fixed_length_string(label.clone().unwrap_or_default(), MAX_LABEL_SIZE)?;
+
+        let mut expected = Vec::with_capacity(32 + 32 + MAX_LABEL_SIZE);
+        expected.extend_from_slice(ctx.accounts.payer.key.as_ref());
+        expected.extend_from_slice(ctx.accounts.candy_guard.key().as_ref());
+        expected.extend_from_slice(label.as_bytes());
+
+        let ix_sysvar_account = &ctx.accounts.instruction_sysvar_account;
+        let sysvar_data = ix_sysvar_account.data.borrow();
+        let reader = InstructionsSysvarReader::new(&sysvar_data)?;
+
+        for instruction in reader.instructions() {
+            let instruction = instruction?;
+
+            if !cmp_pubkeys(&instruction.program_id, &ed25519_program::ID) {
+                continue;
+            }
+
+            if let Some((public_key, message)) = parse_ed25519_instruction(instruction.data) {
+                if cmp_pubkeys(&public_key, &self.signer_key)
+                    && message.len() == MESSAGE_SIZE
+                    && message.starts_with(&expected)
+                {
+                    let offset = expected.len();
+
+                    let mut nonce = [0u8; 8];
+                    nonce.copy_from_slice(&message[offset..offset + 8]);
+                    let mut expiry = [0u8; 8];
+                    expiry.copy_from_slice(&message[offset + 8..offset + 16]);
+
+                    return Ok(Voucher {
+                        nonce: u64::from_le_bytes(nonce),
+                        expiry: i64::from_le_bytes(expiry),
+                    });
+                }
+            }
+        }
+
+        err!(CandyGuardError::MissingVoucher)
+    }
+}
+
+/// Returns the public key and message of an ed25519 program instruction with a single
+/// signature. The signature, public key and message must be part of the instruction data,
+/// since the precompile only verifies the data referenced by the offsets.
+fn parse_ed25519_instruction(data: &[u8]) -> Option<(Pubkey, &[u8])> {
+    let read_u16 = |offset: usize| -> Option<u16> {
+        let bytes = data.get(offset..offset + 2)?;
+        Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+    };
+
+    // number of signatures
+    if *data.first()? != 1 {
+        return None;
+    }
+
+    let offsets = SIGNATURE_OFFSETS_START;
+    data.get(offsets..offsets + SIGNATURE_OFFSETS_SIZE)?;
+
+    let signature_instruction_index = read_u16(offsets + 2)?;
+    let public_key_offset = read_u16(offsets + 4)? as usize;
+    let public_key_instruction_index = read_u16(offsets + 6)?;
+    let message_offset = read_u16(offsets + 8)? as usize;
+    let message_size = read_u16(offsets + 10)? as usize;
+    let message_instruction_index = read_u16(offsets + 12)?;
+
+    // u16::MAX represents the current instruction
+    if signature_instruction_index != u16::MAX
+        || public_key_instruction_index != u16::MAX
+        || message_instruction_index != u16::MAX
+    {
+        return None;
+    }
+
+    let public_key = data.get(public_key_offset..public_key_offset + 32)?;
+    let message = data.get(message_offset..message_offset + message_size)?;
+
+    Some((Pubkey::new(public_key), message))
+}
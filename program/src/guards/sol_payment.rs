@@ -80,6 +80,12 @@ impl Condition for SolPayment {
             ],
         )?;
 
+        evaluation_context.payments.push(Payment {
+            guard: GuardType::SolPayment,
+            mint: None,
+            amount: self.lamports,
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,389 @@
+use super::*;
+use crate::{
+    errors::CandyGuardError,
+    guards::NftGate,
+    programmable::load_metadata,
+    state::GuardType,
+    utils::{assert_is_any_token_account, assert_keys_equal, cmp_pubkeys},
+};
+
+// Maximum size of the stake record discriminator.
+const MAXIMUM_DISCRIMINATOR_SIZE: usize = 8;
+
+/// Guard that restricts access to addresses that hold a specified asset (see
+/// [`StakedAsset`]), either in their wallet or staked through a staking program.
+///
+/// The staked assets are read from stake records owned by the `stake_program`. A
+/// stake record must start with the `discriminator` and contain:
+/// * the pubkey of the payer at `owner_offset`
+/// * the key identifying the staked asset at `stake_key_offset` (the `stake_key` for
+///   tokens or the mint of the staked NFT for collections)
+/// * the staked amount (`u64` little-endian) at the `amount_offset` (tokens only)
+///
+/// The number of token accounts and stake records is specified by two `u8` values
+/// in the mint arguments, and the sum of the wallet balances and staked amounts is
+/// compared against the required amount. For collections, each NFT counts as 1.
+///
+/// List of accounts required:
+///
+///   0. `[]` Token account of the payer (repeated for each token account).
+///   1. `[]` (optional) Metadata account of the NFT (collections only).
+///   2. `[]` Stake record of the payer (repeated for each stake record).
+///   3. `[]` (optional) Metadata account of the staked NFT (collections only).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct StakeGate {
+    /// Asset held in the wallet or staked.
+    pub asset: StakedAsset,
+    /// Required amount of the asset (wallet balance and staked amount combined).
+    pub amount: u64,
+    /// Program that owns the stake records.
+    pub stake_program: Pubkey,
+    /// Discriminator of the stake records (up to 8 bytes).
+    pub discriminator: Vec<u8>,
+    /// Offset of the owner pubkey in the stake record.
+    pub owner_offset: u16,
+    /// Offset of the key identifying the staked asset in the stake record.
+    pub stake_key_offset: u16,
+}
+
+/// Asset accepted by the stake gate.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum StakedAsset {
+    /// Tokens of the `mint`, identified by the `stake_key` in the stake records.
+    Token {
+        mint: Pubkey,
+        stake_key: Pubkey,
+        amount_offset: u16,
+    },
+    /// NFTs of the `collection` (the collection must be verified).
+    Collection { collection: Pubkey },
+}
+
+impl Guard for StakeGate {
+    fn size() -> usize {
+        1 + 32 + 32 + 2 // asset (largest variant: token)
+        + 8 // amount
+        + 32 // stake_program
+        + 4 + MAXIMUM_DISCRIMINATOR_SIZE // discriminator
+        + 2 // owner_offset
+        + 2 // stake_key_offset
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::StakeGate)
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(stake_gate) = &data.default.stake_gate {
+            stake_gate.verify_discriminator()?;
+        }
+
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if let Some(stake_gate) = &group.guards.stake_gate {
+                    stake_gate.verify_discriminator()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for StakeGate {
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        // number of token accounts and stake records
+        let cursor = evaluation_context.args_cursor;
+        let counts = mint_args
+            .get(cursor..cursor + 2)
+            .ok_or(CandyGuardError::MissingMintArgument)?;
+        let token_accounts = counts[0] as usize;
+        let stake_records = counts[1] as usize;
+        // consumes the arguments
+        evaluation_context.args_cursor += 2;
+
+        if token_accounts + stake_records == 0 {
+            return err!(CandyGuardError::InvalidTokenAccountCount);
+        }
+
+        let start = evaluation_context.account_cursor;
+        let payer = ctx.accounts.payer.key();
+        let mut total: u64 = 0;
+
+        // NFTs of a collection are followed by their metadata account
+        let accounts_per_asset = match self.asset {
+            StakedAsset::Token { .. } => 1,
+            StakedAsset::Collection { .. } => 2,
+        };
+        let end = start + (token_accounts + stake_records) * accounts_per_asset;
+
+        for index in (start..end).step_by(accounts_per_asset) {
+            let account = try_get_account_info(ctx, index)?;
+            let metadata = if accounts_per_asset > 1 {
+                Some(try_get_account_info(ctx, index + 1)?)
+            } else {
+                None
+            };
+            evaluation_context.account_cursor += accounts_per_asset;
+
+            // the same account cannot be counted more than once
+            if ctx.remaining_accounts[start..index].iter().any(|other| {
+                ctx.remaining_accounts[index..index + accounts_per_asset]
+                    .iter()
+                    .any(|account| cmp_pubkeys(other.key, account.key))
+            }) {
+                return err!(CandyGuardError::DuplicatedTokenAccount);
+            }
+
+            let staked = index >= start + token_accounts * accounts_per_asset;
+
+            let amount = match (&self.asset, metadata) {
+                (StakedAsset::Token { mint, .. }, _) if !staked => {
+                    assert_is_any_token_account(account, &payer, mint)?.amount
+                }
+                (StakedAsset::Collection { collection }, Some(metadata)) if !staked => {
+                    NftGate::verify_collection(account, metadata, collection, &payer)?;
+                    1
+                }
+                (_, metadata) => self.staked_amount(account, metadata, &payer)?,
+            };
+
+            total = total.saturating_add(amount);
+        }
+
+        if total < self.amount {
+            return err!(CandyGuardError::NotEnoughTokens);
+        }
+
+        Ok(())
+    }
+}
+
+impl StakeGate {
+    fn verify_discriminator(&self) -> Result<()> {
+        // an empty discriminator would accept any account of the staking program
+        if self.discriminator.is_empty() || self.discriminator.len() > MAXIMUM_DISCRIMINATOR_SIZE {
+            return err!(CandyGuardError::InvalidDiscriminatorSize);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the amount staked by the `owner` in the specified stake record. For
+    /// collections, the `metadata` of the staked NFT must be specified.
+    fn staked_amount(
+        &self,
+        stake_record: &AccountInfo,
+        metadata: Option<&AccountInfo>,
+        owner: &Pubkey,
+    ) -> Result<u64> {
+        if !cmp_pubkeys(stake_record.owner, &self.stake_program) {
+            return err!(CandyGuardError::IncorrectOwner);
+        }
+
+        let data = stake_record.try_borrow_data()?;
+
+        let read = |offset: u16, length: usize| -> Result<&[u8]> {
+            let offset = offset as usize;
+            data.get(offset..offset + length)
+                .ok_or_else(|| error!(CandyGuardError::InvalidStakeRecord))
+        };
+
+        if !data.starts_with(&self.discriminator) {
+            return err!(CandyGuardError::InvalidStakeRecord);
+        }
+
+        if !cmp_pubkeys(&Pubkey::new(read(self.owner_offset, 32)?), owner) {
+            return err!(CandyGuardError::IncorrectOwner);
+        }
+
+        let stake_key = Pubkey::new(read(self.stake_key_offset, 32)?);
+
+        match (&self.asset, metadata) {
+            (
+                StakedAsset::Token {
+                    stake_key: expected,
+                    amount_offset,
+                    ..
+                },
+                _,
+            ) => {
+                if !cmp_pubkeys(&stake_key, expected) {
+                    return err!(CandyGuardError::InvalidStakeRecord);
+                }
+
+                let mut amount = [0u8; 8];
+                amount.copy_from_slice(read(*amount_offset, 8)?);
+
+                Ok(u64::from_le_bytes(amount))
+            }
+            (StakedAsset::Collection { collection }, Some(nft_metadata)) => {
+                // programmable NFTs are also supported
+                let (metadata, _) = load_metadata(nft_metadata)?;
+                // validates the metadata information
+                assert_keys_equal(nft_metadata.owner, &mpl_token_metadata::id())?;
+
+                // the stake record must refer to the NFT of the metadata
+                if !cmp_pubkeys(&stake_key, &metadata.mint) {
+                    return err!(CandyGuardError::InvalidStakeRecord);
+                }
+
+                match metadata.collection {
+                    Some(c) if c.verified && c.key == *collection => Ok(1),
+                    _ => err!(CandyGuardError::InvalidNftCollection),
+                }
+            }
+            (StakedAsset::Collection { .. }, None) => {
+                err!(CandyGuardError::MissingRemainingAccount)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mpl_token_metadata::state::{
+        Collection, CollectionDetails, Data, Key, Uses, MAX_METADATA_LEN,
+    };
+
+    use super::*;
+
+    // token standard of programmable NFTs
+    const PROGRAMMABLE_NON_FUNGIBLE: u8 = 4;
+
+    const DISCRIMINATOR: [u8; 8] = [1; 8];
+
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+    }
+
+    impl TestAccount {
+        fn new(owner: Pubkey, data: Vec<u8>) -> Self {
+            Self {
+                key: Pubkey::new_unique(),
+                owner,
+                lamports: 1,
+                data,
+            }
+        }
+
+        /// Metadata account of a programmable NFT of the `collection`.
+        fn programmable_metadata(mint: &Pubkey, collection: Collection) -> Self {
+            let mut data = Vec::with_capacity(MAX_METADATA_LEN);
+            Key::MetadataV1.serialize(&mut data).unwrap();
+            Pubkey::new_unique().serialize(&mut data).unwrap(); // update authority
+            mint.serialize(&mut data).unwrap();
+            Data {
+                name: "NFT".to_string(),
+                symbol: "NFT".to_string(),
+                uri: "https://example.com".to_string(),
+                seller_fee_basis_points: 500,
+                creators: None,
+            }
+            .serialize(&mut data)
+            .unwrap();
+            false.serialize(&mut data).unwrap(); // primary sale happened
+            true.serialize(&mut data).unwrap(); // is mutable
+            Some(255u8).serialize(&mut data).unwrap(); // edition nonce
+            Some(PROGRAMMABLE_NON_FUNGIBLE)
+                .serialize(&mut data)
+                .unwrap();
+            Some(collection).serialize(&mut data).unwrap();
+            Option::<Uses>::None.serialize(&mut data).unwrap();
+            Option::<CollectionDetails>::None
+                .serialize(&mut data)
+                .unwrap();
+            Some((0u8, Option::<Pubkey>::None))
+                .serialize(&mut data)
+                .unwrap();
+            data.resize(MAX_METADATA_LEN, 0);
+
+            Self::new(mpl_token_metadata::ID, data)
+        }
+
+        /// Stake record of the `owner` for the staked asset identified by `stake_key`.
+        fn stake_record(stake_program: Pubkey, owner: &Pubkey, stake_key: &Pubkey) -> Self {
+            let mut data = DISCRIMINATOR.to_vec();
+            data.extend_from_slice(owner.as_ref());
+            data.extend_from_slice(stake_key.as_ref());
+
+            Self::new(stake_program, data)
+        }
+
+        fn info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(
+                &self.key,
+                false,
+                false,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                false,
+                0,
+            )
+        }
+    }
+
+    fn stake_gate(collection: Pubkey) -> StakeGate {
+        StakeGate {
+            asset: StakedAsset::Collection { collection },
+            amount: 1,
+            stake_program: Pubkey::new_unique(),
+            discriminator: DISCRIMINATOR.to_vec(),
+            owner_offset: 8,
+            stake_key_offset: 40,
+        }
+    }
+
+    #[test]
+    fn staked_programmable_nft() {
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let collection = Pubkey::new_unique();
+        let stake_gate = stake_gate(collection);
+
+        let mut record = TestAccount::stake_record(stake_gate.stake_program, &owner, &mint);
+        let mut metadata = TestAccount::programmable_metadata(
+            &mint,
+            Collection {
+                verified: true,
+                key: collection,
+            },
+        );
+
+        let amount = stake_gate
+            .staked_amount(&record.info(), Some(&metadata.info()), &owner)
+            .unwrap();
+        assert_eq!(amount, 1);
+    }
+
+    #[test]
+    fn staked_programmable_nft_of_another_collection() {
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let stake_gate = stake_gate(Pubkey::new_unique());
+
+        let mut record = TestAccount::stake_record(stake_gate.stake_program, &owner, &mint);
+        let mut metadata = TestAccount::programmable_metadata(
+            &mint,
+            Collection {
+                verified: true,
+                key: Pubkey::new_unique(),
+            },
+        );
+
+        let error = stake_gate
+            .staked_amount(&record.info(), Some(&metadata.info()), &owner)
+            .unwrap_err();
+        assert_eq!(error, CandyGuardError::InvalidNftCollection.into());
+    }
+}
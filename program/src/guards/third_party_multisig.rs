@@ -0,0 +1,106 @@
+use super::*;
+use crate::{state::GuardType, utils::cmp_pubkeys};
+
+/// Maximum number of signer keys.
+pub const MAXIMUM_SIGNERS: usize = 5;
+
+/// Guard that requires a threshold of specified signers to validate the transaction.
+///
+/// The signers can be any `threshold` of the keys in the `signer_keys` list, which
+/// allows redundant signing services and the rotation of keys without interrupting
+/// the mint.
+///
+/// List of accounts required:
+///
+///   0. `[signer]` Signer of the transaction (repeated for each of the `threshold`
+///      signers required).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ThirdPartyMultisig {
+    pub signer_keys: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+impl Guard for ThirdPartyMultisig {
+    fn size() -> usize {
+        4 + (MAXIMUM_SIGNERS * 32) // signer_keys
+        + 1 // threshold
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::ThirdPartyMultisig)
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(third_party_multisig) = &data.default.third_party_multisig {
+            third_party_multisig.verify_signers()?;
+        }
+
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if let Some(third_party_multisig) = &group.guards.third_party_multisig {
+                    third_party_multisig.verify_signers()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for ThirdPartyMultisig {
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let mut signed: Vec<&Pubkey> = Vec::with_capacity(self.threshold as usize);
+
+        for _ in 0..self.threshold {
+            let signer_index = evaluation_context.account_cursor;
+            evaluation_context.account_cursor += 1;
+            let signer_account = try_get_account_info(ctx, signer_index)?;
+
+            let is_authorized = self
+                .signer_keys
+                .iter()
+                .any(|key| cmp_pubkeys(signer_account.key, key));
+            // each signer can only count once towards the threshold
+            let is_duplicated = signed
+                .iter()
+                .any(|key| cmp_pubkeys(signer_account.key, key));
+
+            if !(is_authorized && signer_account.is_signer) || is_duplicated {
+                return err!(CandyGuardError::MissingRequiredSignature);
+            }
+
+            signed.push(signer_account.key);
+        }
+
+        Ok(())
+    }
+}
+
+impl ThirdPartyMultisig {
+    fn verify_signers(&self) -> Result<()> {
+        if self.signer_keys.len() > MAXIMUM_SIGNERS {
+            return err!(CandyGuardError::ExceededSignerListSize);
+        }
+
+        if self.threshold == 0 || self.threshold as usize > self.signer_keys.len() {
+            return err!(CandyGuardError::InvalidSignerThreshold);
+        }
+
+        for (index, key) in self.signer_keys.iter().enumerate() {
+            if self.signer_keys[..index]
+                .iter()
+                .any(|other| cmp_pubkeys(key, other))
+            {
+                return err!(CandyGuardError::DuplicatedSignerKey);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -3,12 +3,15 @@ use super::*;
 use crate::{state::GuardType, utils::*};
 
 /// Guard that requires addresses that hold an amount of a specified spl-token
-/// and burns them.
+/// and burns them (the mint can belong to either the spl-token or Token-2022
+/// program).
 ///
 /// List of accounts required:
 ///
-///   0. `[writable]` Token account holding the required amount.
+///   0. `[writable]` Token account holding the required amount (any token
+///      account owned by the payer).
 ///   1. `[writable]` Token mint account.
+///   2. `[]` Token-2022 program account (only required for Token-2022 mints).
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct TokenBurn {
     pub amount: u64,
@@ -40,7 +43,8 @@ impl Condition for TokenBurn {
         // consumes the gate token account
         evaluation_context.account_cursor += 1;
 
-        let account = assert_is_ata(token_gate_account, &ctx.accounts.payer.key(), &self.mint)?;
+        let account =
+            assert_is_any_token_account(token_gate_account, &ctx.accounts.payer.key(), &self.mint)?;
 
         if account.amount >= self.amount {
             let token_gate_mint = try_get_account_info(ctx, token_gate_index + 1)?;
@@ -49,6 +53,14 @@ impl Condition for TokenBurn {
 
             // is the mint account the one expected?
             assert_keys_equal(&token_gate_mint.key(), &self.mint)?;
+
+            if cmp_pubkeys(token_gate_account.owner, &spl_token_2022::ID) {
+                let token_program = try_get_account_info(ctx, token_gate_index + 2)?;
+                // consumes the token program account
+                evaluation_context.account_cursor += 1;
+
+                assert_keys_equal(token_program.key, &spl_token_2022::ID)?;
+            }
         } else {
             return err!(CandyGuardError::NotEnoughTokens);
         }
@@ -72,13 +84,19 @@ impl Condition for TokenBurn {
         let token_gate_account = try_get_account_info(ctx, token_gate_index)?;
         let token_gate_mint = try_get_account_info(ctx, token_gate_index + 1)?;
 
+        let token_program = if cmp_pubkeys(token_gate_account.owner, &spl_token_2022::ID) {
+            try_get_account_info(ctx, token_gate_index + 2)?.to_account_info()
+        } else {
+            ctx.accounts.token_program.to_account_info()
+        };
+
         spl_token_burn(TokenBurnParams {
             mint: token_gate_mint.to_account_info(),
             source: token_gate_account.to_account_info(),
             amount: self.amount,
             authority: ctx.accounts.payer.to_account_info(),
             authority_signer_seeds: None,
-            token_program: ctx.accounts.token_program.to_account_info(),
+            token_program,
         })?;
 
         Ok(())
@@ -2,11 +2,18 @@ use super::*;
 
 use crate::{state::GuardType, utils::*};
 
-/// Guard that restricts access to addresses that hold the specified spl-token.
+/// Guard that restricts access to addresses that hold the specified spl-token
+/// (the mint can belong to either the spl-token or Token-2022 program).
 ///
 /// List of accounts required:
 ///
-///   0. `[]` Token account holding the required amount.
+///   0. `[]` Token account holding the required amount (any token account owned
+///      by the payer; repeated for each token account).
+///
+/// The balance can be split across several token accounts of the payer. The number
+/// of token accounts (at least 1) is specified by a `u8` in the mint arguments and the
+/// sum of their balances is compared against the required amount. When the argument
+/// is not present, a single token account is expected.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct TokenGate {
     pub amount: u64,
@@ -28,19 +35,50 @@ impl Condition for TokenGate {
     fn validate<'info>(
         &self,
         ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
-        _mint_args: &[u8],
+        mint_args: &[u8],
         _guard_set: &GuardSet,
         evaluation_context: &mut EvaluationContext,
     ) -> Result<()> {
-        // retrieves the (potential) token gate account
+        // number of token accounts holding the balance
+        let count = if let Some(count) = mint_args.get(evaluation_context.args_cursor) {
+            // consumes the argument
+            evaluation_context.args_cursor += 1;
+            *count as usize
+        } else {
+            1
+        };
+
+        if count == 0 {
+            return err!(CandyGuardError::InvalidTokenAccountCount);
+        }
+
         let token_gate_index = evaluation_context.account_cursor;
-        let token_gate_account = try_get_account_info(ctx, token_gate_index)?;
-        // consumes the gate token account
-        evaluation_context.account_cursor += 1;
+        let mut total: u64 = 0;
 
-        let account = assert_is_ata(token_gate_account, &ctx.accounts.payer.key(), &self.mint)?;
+        for index in token_gate_index..token_gate_index + count {
+            // retrieves the (potential) token gate account
+            let token_gate_account = try_get_account_info(ctx, index)?;
+            // consumes the gate token account
+            evaluation_context.account_cursor += 1;
+
+            // the same account cannot be counted more than once
+            if ctx.remaining_accounts[token_gate_index..index]
+                .iter()
+                .any(|account| cmp_pubkeys(account.key, token_gate_account.key))
+            {
+                return err!(CandyGuardError::DuplicatedTokenAccount);
+            }
+
+            let account = assert_is_any_token_account(
+                token_gate_account,
+                &ctx.accounts.payer.key(),
+                &self.mint,
+            )?;
+
+            total = total.saturating_add(account.amount);
+        }
 
-        if account.amount < self.amount {
+        if total < self.amount {
             return err!(CandyGuardError::NotEnoughTokens);
         }
 
@@ -3,15 +3,26 @@ use super::*;
 use crate::{
     errors::CandyGuardError,
     state::GuardType,
-    utils::{assert_is_ata, assert_keys_equal, spl_token_transfer, TokenTransferParams},
+    utils::{
+        assert_is_any_token_account, assert_keys_equal, cmp_pubkeys, get_mint_decimals,
+        mint_has_extension, spl_token_2022, spl_token_transfer, spl_token_transfer_checked,
+        TokenTransferParams, NON_TRANSFERABLE_EXTENSION,
+    },
 };
 
 /// Guard that charges an amount in a specified spl-token as payment for the mint.
 ///
+/// The mint can belong to either the spl-token or Token-2022 program. Token-2022
+/// payments use a checked transfer, so any transfer fee is withheld from the amount
+/// received by the destination; non-transferable tokens cannot be used as payment.
+///
 /// List of accounts required:
 ///
-///   0. `[writable]` Token account holding the required amount.
+///   0. `[writable]` Token account holding the required amount (any token
+///      account owned by the payer).
 ///   1. `[writable]` Address of the ATA to receive the tokens.
+///   2. `[]` Token mint account (only required for Token-2022 mints).
+///   3. `[]` Token-2022 program account (only required for Token-2022 mints).
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct TokenPayment {
     pub amount: u64,
@@ -48,12 +59,25 @@ impl Condition for TokenPayment {
         assert_keys_equal(destination_ata.key, &self.destination_ata)?;
 
         let token_account =
-            assert_is_ata(token_account_info, &ctx.accounts.payer.key(), &self.mint)?;
+            assert_is_any_token_account(token_account_info, &ctx.accounts.payer.key(), &self.mint)?;
 
         if token_account.amount < self.amount {
             return err!(CandyGuardError::NotEnoughTokens);
         }
 
+        if cmp_pubkeys(token_account_info.owner, &spl_token_2022::ID) {
+            let mint = try_get_account_info(ctx, token_account_index + 2)?;
+            let token_program = try_get_account_info(ctx, token_account_index + 3)?;
+            evaluation_context.account_cursor += 2;
+
+            assert_keys_equal(mint.key, &self.mint)?;
+            assert_keys_equal(token_program.key, &spl_token_2022::ID)?;
+
+            if mint_has_extension(mint, NON_TRANSFERABLE_EXTENSION)? {
+                return err!(CandyGuardError::NonTransferableToken);
+            }
+        }
+
         evaluation_context
             .indices
             .insert("token_payment_index", token_account_index);
@@ -73,14 +97,38 @@ impl Condition for TokenPayment {
         let token_account_info = try_get_account_info(ctx, index)?;
         let destination_ata = try_get_account_info(ctx, index + 1)?;
 
-        spl_token_transfer(TokenTransferParams {
-            source: token_account_info.to_account_info(),
-            destination: destination_ata.to_account_info(),
-            authority: ctx.accounts.payer.to_account_info(),
-            authority_signer_seeds: &[],
-            token_program: ctx.accounts.token_program.to_account_info(),
+        if cmp_pubkeys(token_account_info.owner, &spl_token_2022::ID) {
+            let mint = try_get_account_info(ctx, index + 2)?;
+            let token_program = try_get_account_info(ctx, index + 3)?;
+
+            spl_token_transfer_checked(
+                TokenTransferParams {
+                    source: token_account_info.to_account_info(),
+                    destination: destination_ata.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                    authority_signer_seeds: &[],
+                    token_program: token_program.to_account_info(),
+                    amount: self.amount,
+                },
+                mint.to_account_info(),
+                get_mint_decimals(mint)?,
+            )?;
+        } else {
+            spl_token_transfer(TokenTransferParams {
+                source: token_account_info.to_account_info(),
+                destination: destination_ata.to_account_info(),
+                authority: ctx.accounts.payer.to_account_info(),
+                authority_signer_seeds: &[],
+                token_program: ctx.accounts.token_program.to_account_info(),
+                amount: self.amount,
+            })?;
+        }
+
+        evaluation_context.payments.push(Payment {
+            guard: GuardType::TokenPayment,
+            mint: Some(self.mint),
             amount: self.amount,
-        })?;
+        });
 
         Ok(())
     }
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CandyGuardError,
+    events::{AuthorityChangeKind, AuthorityEvent},
+    state::{AuthorityProposal, CandyGuard, AUTHORITY_PROPOSAL_SEED},
+};
+
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let candy_guard = &mut ctx.accounts.candy_guard;
+
+    candy_guard.authority = ctx.accounts.new_authority.key();
+
+    emit!(AuthorityEvent {
+        candy_guard: candy_guard.key(),
+        authority: ctx.accounts.authority.key(),
+        new_authority: candy_guard.authority,
+        kind: AuthorityChangeKind::Accept,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(mut, has_one = authority)]
+    pub candy_guard: Account<'info, CandyGuard>,
+    #[account(
+        mut,
+        close = authority,
+        has_one = candy_guard,
+        has_one = new_authority,
+        constraint = proposal.proposed_by == candy_guard.authority
+            @ CandyGuardError::StaleAuthorityProposal,
+        seeds = [AUTHORITY_PROPOSAL_SEED, candy_guard.key().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, AuthorityProposal>,
+    /// CHECK: current authority of the candy guard (receives the rent of the proposal)
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+    pub new_authority: Signer<'info>,
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CandyGuardError,
+    events::{UpdateEvent, UpdateKind},
+    guards::ConfigLock,
+    state::{
+        CandyGuard, CandyGuardData, GuardSet, ScheduledUpdate, DATA_OFFSET, SCHEDULED_DATA_OFFSET,
+        SCHEDULED_UPDATE_SEED, SEED,
+    },
+};
+
+/// Applies an active scheduled update to the candy guard configuration. After the
+/// activation time the scheduled configuration is already in use by the `mint` and
+/// `route` instructions; applying it makes the change permanent, removing the need
+/// for the scheduled update account.
+pub fn apply_scheduled_update(ctx: Context<ApplyScheduledUpdate>) -> Result<()> {
+    let account_info = ctx.accounts.candy_guard.to_account_info();
+    ConfigLock::assert_unlocked(&account_info)?;
+
+    let current = CandyGuardData::load(&account_info.data.borrow()[DATA_OFFSET..])?;
+    let managed = current.default.clone();
+
+    match &current.default.pending_update {
+        Some(pending_update) if pending_update.is_active(Clock::get()?.unix_timestamp) => (),
+        _ => return err!(CandyGuardError::ScheduledUpdateNotActive),
+    }
+
+    let scheduled_update = &ctx.accounts.scheduled_update;
+    let scheduled_update_info = scheduled_update.to_account_info();
+    let scheduled_data = &scheduled_update_info.data.borrow()[SCHEDULED_DATA_OFFSET..];
+
+    let mut data = if let Some(label) = &scheduled_update.label {
+        let (guards, _) = GuardSet::from_data(scheduled_data)?;
+        let mut data = current;
+        data.replace_group(label.to_string(), guards)?;
+        data
+    } else {
+        CandyGuardData::load(scheduled_data)?
+    };
+
+    // the config lock and pause guards are managed by their own instructions
+    data.default.copy_managed_guards(&managed);
+    // the pending update has been applied
+    data.default.pending_update = None;
+    // validates guard settings
+    data.verify()?;
+
+    data.save_to_account(
+        &account_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(UpdateEvent {
+        candy_guard: ctx.accounts.candy_guard.key(),
+        authority: ctx.accounts.authority.key(),
+        kind: UpdateKind::ApplyScheduledUpdate,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApplyScheduledUpdate<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    #[account(
+        mut,
+        close = authority,
+        has_one = candy_guard,
+        seeds = [SCHEDULED_UPDATE_SEED, candy_guard.key().as_ref()],
+        bump
+    )]
+    pub scheduled_update: Account<'info, ScheduledUpdate>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    // Payer for the account resizing.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
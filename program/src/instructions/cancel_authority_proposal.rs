@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{AuthorityChangeKind, AuthorityEvent},
+    state::{AuthorityProposal, CandyGuard, AUTHORITY_PROPOSAL_SEED},
+};
+
+pub fn cancel_authority_proposal<'info>(
+    ctx: Context<CancelAuthorityProposal<'info>>,
+) -> Result<()> {
+    emit!(AuthorityEvent {
+        candy_guard: ctx.accounts.candy_guard.key(),
+        authority: ctx.accounts.authority.key(),
+        new_authority: ctx.accounts.proposal.new_authority,
+        kind: AuthorityChangeKind::CancelProposal,
+    });
+
+    Ok(())
+}
+
+/// Cancel a pending authority transfer, withdrawing the rent SOL of the proposal.
+#[derive(Accounts)]
+pub struct CancelAuthorityProposal<'info> {
+    #[account(has_one = authority)]
+    pub candy_guard: Account<'info, CandyGuard>,
+    #[account(
+        mut,
+        close = authority,
+        has_one = candy_guard,
+        seeds = [AUTHORITY_PROPOSAL_SEED, candy_guard.key().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, AuthorityProposal>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{UpdateEvent, UpdateKind},
+    state::{
+        CandyGuard, CandyGuardData, ScheduledUpdate, DATA_OFFSET, SCHEDULED_UPDATE_SEED, SEED,
+    },
+};
+
+pub fn cancel_scheduled_update(ctx: Context<CancelScheduledUpdate>) -> Result<()> {
+    let account_info = ctx.accounts.candy_guard.to_account_info();
+    let mut data = CandyGuardData::load(&account_info.data.borrow()[DATA_OFFSET..])?;
+
+    // removes the pending update from the candy guard configuration
+    data.default.pending_update = None;
+
+    data.save_to_account(
+        &account_info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(UpdateEvent {
+        candy_guard: ctx.accounts.candy_guard.key(),
+        authority: ctx.accounts.authority.key(),
+        kind: UpdateKind::CancelScheduledUpdate,
+    });
+
+    Ok(())
+}
+
+/// Cancel a scheduled update, withdrawing the rent SOL from the scheduled update account.
+#[derive(Accounts)]
+pub struct CancelScheduledUpdate<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    #[account(
+        mut,
+        close = authority,
+        has_one = candy_guard,
+        seeds = [SCHEDULED_UPDATE_SEED, candy_guard.key().as_ref()],
+        bump
+    )]
+    pub scheduled_update: Account<'info, ScheduledUpdate>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{CandyGuard, UpdateBuffer, BUFFER_SEED};
+
+pub fn close_buffer<'info>(_ctx: Context<CloseBuffer<'info>>) -> Result<()> {
+    Ok(())
+}
+
+/// Discard a staged buffer, withdrawing its rent SOL.
+#[derive(Accounts)]
+pub struct CloseBuffer<'info> {
+    #[account(has_one = authority)]
+    pub candy_guard: Account<'info, CandyGuard>,
+    #[account(
+        mut,
+        close = authority,
+        has_one = candy_guard,
+        seeds = [BUFFER_SEED, candy_guard.key().as_ref()],
+        bump
+    )]
+    pub buffer: Account<'info, UpdateBuffer>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
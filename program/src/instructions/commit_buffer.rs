@@ -0,0 +1,91 @@
+use anchor_lang::{prelude::*, AccountsClose};
+use solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+
+use crate::{
+    events::{UpdateEvent, UpdateKind},
+    guards::ConfigLock,
+    state::{
+        CandyGuard, CandyGuardData, GuardSet, UpdateBuffer, BUFFER_DATA_OFFSET, BUFFER_SEED,
+        DATA_OFFSET, SEED,
+    },
+    utils::resize_account,
+};
+
+/// Commits the data staged in the buffer to the candy guard account. When the
+/// new configuration requires the account to grow by more than the realloc limit,
+/// each call grows the account by the maximum permitted increase and the instruction
+/// must be called again until the account reaches the required size.
+pub fn commit_buffer(ctx: Context<CommitBuffer>) -> Result<()> {
+    let account_info = ctx.accounts.candy_guard.to_account_info();
+    ConfigLock::assert_unlocked(&account_info)?;
+
+    let buffer_info = ctx.accounts.buffer.to_account_info();
+    // deserializes the candy guard data
+    let mut data = CandyGuardData::load(&buffer_info.data.borrow()[BUFFER_DATA_OFFSET..])?;
+
+    // the pending update, config lock and pause guards are managed by their own
+    // instructions, so the current values are preserved
+    let (current, _) = GuardSet::from_data(&account_info.data.borrow()[DATA_OFFSET..])?;
+    data.default.copy_managed_guards(&current);
+
+    // validates guard settings
+    data.verify()?;
+    let target_size = data.account_size();
+    let step_size = target_size.min(account_info.data_len() + MAX_PERMITTED_DATA_INCREASE);
+
+    resize_account(
+        &account_info,
+        step_size,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    if step_size < target_size {
+        msg!(
+            "Account requires {} more bytes, commit must be called again",
+            target_size - step_size
+        );
+        return Ok(());
+    }
+
+    // save the guards information to the account data and stores
+    // the updated feature flag
+    let mut account_data = account_info.data.borrow_mut();
+    data.save(&mut account_data[DATA_OFFSET..])?;
+
+    // the buffer is no longer needed
+    ctx.accounts
+        .buffer
+        .close(ctx.accounts.payer.to_account_info())?;
+
+    emit!(UpdateEvent {
+        candy_guard: ctx.accounts.candy_guard.key(),
+        authority: ctx.accounts.authority.key(),
+        kind: UpdateKind::CommitBuffer,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CommitBuffer<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    #[account(
+        mut,
+        has_one = candy_guard,
+        seeds = [BUFFER_SEED, candy_guard.key().as_ref()],
+        bump
+    )]
+    pub buffer: Account<'info, UpdateBuffer>,
+    pub authority: Signer<'info>,
+    // Payer for the account resizing (receives the rent of the buffer account).
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -4,7 +4,10 @@ use crate::state::{CandyGuard, CandyGuardData, DATA_OFFSET, SEED};
 
 pub fn initialize(ctx: Context<Initialize>, data: Vec<u8>) -> Result<()> {
     // deserializes the candy guard data
-    let data = CandyGuardData::load(&data)?;
+    let mut data = CandyGuardData::load(&data)?;
+    // the pending update, config lock and pause guards are managed by their own
+    // instructions
+    data.default.clear_managed_guards();
     // validates guard settings
     data.verify()?;
 
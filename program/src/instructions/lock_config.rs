@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CandyGuardError,
+    events::{UpdateEvent, UpdateKind},
+    guards::ConfigLock,
+    state::{CandyGuard, CandyGuardData, DATA_OFFSET, SEED},
+};
+
+pub fn lock_config(ctx: Context<LockConfig>, until: Option<i64>) -> Result<()> {
+    let account_info = ctx.accounts.candy_guard.to_account_info();
+    let mut data = CandyGuardData::load(&account_info.data.borrow()[DATA_OFFSET..])?;
+
+    // the scheduled update would modify the configuration after the lock
+    if data.default.pending_update.is_some() {
+        return err!(CandyGuardError::PendingUpdateNotAllowed);
+    }
+
+    let until = until.unwrap_or(ConfigLock::PERMANENT);
+
+    if until <= Clock::get()?.unix_timestamp {
+        return err!(CandyGuardError::InvalidLockTime);
+    }
+
+    // an existing lock can only be extended
+    if let Some(config_lock) = &data.default.config_lock {
+        if until < config_lock.until {
+            return err!(CandyGuardError::InvalidLockTime);
+        }
+    }
+
+    data.default.config_lock = Some(ConfigLock { until });
+
+    data.save_to_account(
+        &account_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(UpdateEvent {
+        candy_guard: ctx.accounts.candy_guard.key(),
+        authority: ctx.accounts.authority.key(),
+        kind: UpdateKind::LockConfig,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct LockConfig<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    pub authority: Signer<'info>,
+    // Payer for the account resizing.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,12 +1,17 @@
 use std::collections::BTreeMap;
 
-use anchor_lang::{prelude::*, solana_program::sysvar};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program::invoke_signed, sysvar},
+};
 
 use mpl_candy_machine_core::CandyMachine;
 
 use crate::{
-    guards::{CandyGuardError, EvaluationContext},
-    state::{CandyGuard, CandyGuardData, GuardSet, DATA_OFFSET, SEED},
+    events::MintEvent,
+    guards::{try_get_account_info, CandyGuardError, EvaluationContext, Pause},
+    programmable::{mints_programmable, mpl_token_auth_rules},
+    state::{CandyGuard, GuardSet, ScheduledUpdate, DATA_OFFSET, SEED},
     utils::cmp_pubkeys,
 };
 
@@ -18,15 +23,23 @@ pub fn mint<'info>(
     let candy_guard = &ctx.accounts.candy_guard;
     let account_info = &candy_guard.to_account_info();
     let account_data = account_info.data.borrow();
-    // loads the active guard set
-    let guard_set = match CandyGuardData::active_set(&account_data[DATA_OFFSET..], label) {
+    let (default, _) = GuardSet::from_data(&account_data[DATA_OFFSET..])?;
+
+    // the mint is not allowed while paused (not subject to bot tax)
+    Pause::assert_mint_allowed(&default)?;
+
+    let group_label = label.clone();
+    // loads the active guard set (taking into account any scheduled update)
+    let guard_set = match ScheduledUpdate::active_set(
+        &candy_guard.key(),
+        &account_data[DATA_OFFSET..],
+        label,
+        ctx.remaining_accounts,
+    ) {
         Ok(guard_set) => guard_set,
-        Err(error) => {
-            // load the default guard set to look for the bot_tax since errors only occur
-            // when trying to load guard set groups
-            let (default, _) = GuardSet::from_data(&account_data[DATA_OFFSET..])?;
-            return process_error(&ctx, &default, error);
-        }
+        // use the default guard set to look for the bot_tax since errors only occur
+        // when trying to load guard set groups
+        Err(error) => return process_error(&ctx, &default, error),
     };
 
     let conditions = guard_set.enabled_conditions();
@@ -36,6 +49,8 @@ pub fn mint<'info>(
         account_cursor: 0,
         args_cursor: 0,
         indices: BTreeMap::new(),
+        payments: Vec::new(),
+        label: group_label,
     };
 
     // validates the required transaction data
@@ -61,12 +76,25 @@ pub fn mint<'info>(
         condition.pre_actions(&ctx, &mint_args, &guard_set, &mut evaluation_context)?;
     }
 
-    cpi_mint(&ctx)?;
+    if mints_programmable(&ctx.accounts.candy_machine) {
+        cpi_mint_programmable(&ctx, evaluation_context.account_cursor)?;
+    } else {
+        cpi_mint(&ctx)?;
+    }
 
     for condition in &conditions {
         condition.post_actions(&ctx, &mint_args, &guard_set, &mut evaluation_context)?;
     }
 
+    emit!(MintEvent {
+        candy_guard: candy_guard.key(),
+        candy_machine: ctx.accounts.candy_machine.key(),
+        label: evaluation_context.label,
+        payer: ctx.accounts.payer.key(),
+        nft_mint: ctx.accounts.nft_mint.key(),
+        payments: evaluation_context.payments,
+    });
+
     Ok(())
 }
 
@@ -76,11 +104,15 @@ fn process_error<'info>(
     guard_set: &GuardSet,
     error: Error,
 ) -> Result<()> {
-    if let Some(bot_tax) = &guard_set.bot_tax {
-        bot_tax.punish_bots(error, ctx)?;
-        Ok(())
-    } else {
-        Err(error)
+    let policy = guard_set.bot_tax_policy.as_ref();
+
+    match &guard_set.bot_tax {
+        // exempted errors fail the transaction without charging the tax
+        Some(bot_tax) if !policy.is_some_and(|policy| policy.is_exempt(&error)) => {
+            bot_tax.punish_bots(error, ctx, policy)?;
+            Ok(())
+        }
+        _ => Err(error),
     }
 }
 
@@ -142,6 +174,120 @@ fn cpi_mint<'info>(ctx: &Context<'_, '_, '_, 'info, Mint<'info>>) -> Result<()>
     mpl_candy_machine_core::cpi::mint(cpi_ctx)
 }
 
+// Discriminator of the candy machine `mint_v2` instruction.
+const MINT_V2_DISCRIMINATOR: [u8; 8] = [120, 121, 23, 146, 173, 110, 199, 205];
+
+/// Send a programmable NFT mint transaction to the candy machine (`mint_v2`).
+///
+/// The additional accounts are expected in the remaining accounts, after the
+/// accounts of the guards (starting at `account_index`):
+///
+///   0. `[writable]` Token account of the NFT (seeds `[payer pubkey, token program
+///           pubkey, nft mint pubkey]`).
+///   1. `[writable]` Token record PDA of the token account.
+///   2. `[]` Associate token account program.
+///   3. `[]` Token-auth-rules program (optional).
+///   4. `[]` Rule set account (optional, required with the token-auth-rules program).
+///
+/// In this case, the `collection_authority_record` account is the collection delegate
+/// record of the candy machine authority PDA.
+fn cpi_mint_programmable<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+    account_index: usize,
+) -> Result<()> {
+    let candy_guard = &ctx.accounts.candy_guard;
+    // PDA signer for the transaction
+    let seeds = [SEED, &candy_guard.base.to_bytes(), &[candy_guard.bump]];
+
+    let token = try_get_account_info(ctx, account_index)?;
+    let token_record = try_get_account_info(ctx, account_index + 1)?;
+    let spl_ata_program = try_get_account_info(ctx, account_index + 2)?;
+
+    // the authorization rules accounts are only present when the candy machine
+    // has a rule set
+    let authorization_rules = match ctx.remaining_accounts.get(account_index + 3) {
+        Some(program) if cmp_pubkeys(program.key, &mpl_token_auth_rules::ID) => {
+            Some((program, try_get_account_info(ctx, account_index + 4)?))
+        }
+        _ => None,
+    };
+
+    let candy_machine_program = ctx.accounts.candy_machine_program.to_account_info();
+    let payer = ctx.accounts.payer.to_account_info();
+
+    let mut infos = vec![
+        ctx.accounts.candy_machine.to_account_info(),
+        ctx.accounts.candy_machine_authority_pda.to_account_info(),
+        candy_guard.to_account_info(),
+        payer.clone(),
+        // nft owner
+        payer,
+        ctx.accounts.nft_mint.to_account_info(),
+        ctx.accounts.nft_mint_authority.to_account_info(),
+        ctx.accounts.nft_metadata.to_account_info(),
+        ctx.accounts.nft_master_edition.to_account_info(),
+        token.to_account_info(),
+        token_record.to_account_info(),
+        ctx.accounts.collection_authority_record.to_account_info(),
+        ctx.accounts.collection_mint.to_account_info(),
+        ctx.accounts.collection_metadata.to_account_info(),
+        ctx.accounts.collection_master_edition.to_account_info(),
+        ctx.accounts.collection_update_authority.to_account_info(),
+        ctx.accounts.token_metadata_program.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        spl_ata_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.instruction_sysvar_account.to_account_info(),
+        ctx.accounts.recent_slothashes.to_account_info(),
+    ];
+
+    let mut accounts = vec![
+        AccountMeta::new(ctx.accounts.candy_machine.key(), false),
+        AccountMeta::new(ctx.accounts.candy_machine_authority_pda.key(), false),
+        AccountMeta::new_readonly(candy_guard.key(), true),
+        AccountMeta::new(ctx.accounts.payer.key(), true),
+        AccountMeta::new_readonly(ctx.accounts.payer.key(), false),
+        AccountMeta::new(ctx.accounts.nft_mint.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.nft_mint_authority.key(), true),
+        AccountMeta::new(ctx.accounts.nft_metadata.key(), false),
+        AccountMeta::new(ctx.accounts.nft_master_edition.key(), false),
+        AccountMeta::new(token.key(), false),
+        AccountMeta::new(token_record.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.collection_authority_record.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.collection_mint.key(), false),
+        AccountMeta::new(ctx.accounts.collection_metadata.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.collection_master_edition.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.collection_update_authority.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.token_metadata_program.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.token_program.key(), false),
+        AccountMeta::new_readonly(spl_ata_program.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.instruction_sysvar_account.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.recent_slothashes.key(), false),
+    ];
+
+    // optional accounts that are not present are represented by the candy
+    // machine program id
+    if let Some((program, rule_set)) = authorization_rules {
+        infos.push(program.to_account_info());
+        infos.push(rule_set.to_account_info());
+        accounts.push(AccountMeta::new_readonly(program.key(), false));
+        accounts.push(AccountMeta::new_readonly(rule_set.key(), false));
+    } else {
+        accounts.push(AccountMeta::new_readonly(mpl_candy_machine_core::ID, false));
+        accounts.push(AccountMeta::new_readonly(mpl_candy_machine_core::ID, false));
+    }
+    infos.push(candy_machine_program);
+
+    let instruction = Instruction {
+        program_id: mpl_candy_machine_core::ID,
+        accounts,
+        data: MINT_V2_DISCRIMINATOR.to_vec(),
+    };
+
+    invoke_signed(&instruction, &infos, &[&seeds]).map_err(|error| error.into())
+}
+
 #[derive(Debug, Clone)]
 pub struct Token;
 
@@ -208,3 +354,17 @@ pub struct Mint<'info> {
     #[account(address = sysvar::instructions::id())]
     pub instruction_sysvar_account: UncheckedAccount<'info>,
 }
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::solana_program::hash::hash;
+
+    use super::*;
+
+    #[test]
+    fn mint_v2_discriminator() {
+        // anchor instruction discriminator: first 8 bytes of sha256("global:<name>")
+        let discriminator = hash(b"global:mint_v2");
+        assert_eq!(MINT_V2_DISCRIMINATOR, discriminator.to_bytes()[..8]);
+    }
+}
@@ -1,17 +1,43 @@
+pub use accept_authority::*;
+pub use apply_scheduled_update::*;
+pub use cancel_authority_proposal::*;
+pub use cancel_scheduled_update::*;
+pub use close_buffer::*;
+pub use commit_buffer::*;
 pub use initialize::*;
+pub use lock_config::*;
 pub use mint::*;
+pub use pause::*;
+pub use propose_authority::*;
+pub use revoke_delegate::*;
 pub use route::*;
+pub use schedule_update::*;
 pub use set_authority::*;
+pub use set_delegate::*;
 pub use unwrap::*;
 pub use update::*;
 pub use withdraw::*;
 pub use wrap::*;
+pub use write_buffer::*;
 
+pub mod accept_authority;
+pub mod apply_scheduled_update;
+pub mod cancel_authority_proposal;
+pub mod cancel_scheduled_update;
+pub mod close_buffer;
+pub mod commit_buffer;
 pub mod initialize;
+pub mod lock_config;
 pub mod mint;
+pub mod pause;
+pub mod propose_authority;
+pub mod revoke_delegate;
 pub mod route;
+pub mod schedule_update;
 pub mod set_authority;
+pub mod set_delegate;
 pub mod unwrap;
 pub mod update;
 pub mod withdraw;
 pub mod wrap;
+pub mod write_buffer;
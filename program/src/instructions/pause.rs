@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{UpdateEvent, UpdateKind},
+    guards::Pause,
+    state::{CandyGuard, CandyGuardData, Delegate, DelegateRole, GuardType, DATA_OFFSET, SEED},
+};
+
+pub fn pause<'info>(
+    ctx: Context<'_, '_, '_, 'info, PauseMint<'info>>,
+    reason: Option<u32>,
+    routes: Vec<GuardType>,
+) -> Result<()> {
+    // only the authority or a delegate with the updater role can pause
+    Delegate::assert_authority_or_role(
+        &ctx.accounts.candy_guard.key(),
+        &ctx.accounts.candy_guard.authority,
+        &ctx.accounts.authority.to_account_info(),
+        DelegateRole::Updater,
+        ctx.remaining_accounts,
+    )?;
+
+    let account_info = ctx.accounts.candy_guard.to_account_info();
+    let mut data = CandyGuardData::load(&account_info.data.borrow()[DATA_OFFSET..])?;
+
+    // pausing an already paused candy guard replaces the reason and routes
+    data.default.pause = Some(Pause {
+        reason: reason.unwrap_or_default(),
+        routes: routes
+            .into_iter()
+            .fold(0, |mask, guard_type| mask | GuardType::as_mask(guard_type)),
+    });
+
+    data.save_to_account(
+        &account_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(UpdateEvent {
+        candy_guard: ctx.accounts.candy_guard.key(),
+        authority: ctx.accounts.authority.key(),
+        kind: UpdateKind::Pause,
+    });
+
+    Ok(())
+}
+
+pub fn resume<'info>(ctx: Context<'_, '_, '_, 'info, PauseMint<'info>>) -> Result<()> {
+    // only the authority or a delegate with the updater role can resume
+    Delegate::assert_authority_or_role(
+        &ctx.accounts.candy_guard.key(),
+        &ctx.accounts.candy_guard.authority,
+        &ctx.accounts.authority.to_account_info(),
+        DelegateRole::Updater,
+        ctx.remaining_accounts,
+    )?;
+
+    let account_info = ctx.accounts.candy_guard.to_account_info();
+    let mut data = CandyGuardData::load(&account_info.data.borrow()[DATA_OFFSET..])?;
+
+    data.default.pause = None;
+
+    data.save_to_account(
+        &account_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(UpdateEvent {
+        candy_guard: ctx.accounts.candy_guard.key(),
+        authority: ctx.accounts.authority.key(),
+        kind: UpdateKind::Resume,
+    });
+
+    Ok(())
+}
+
+/// Accounts to pause and resume the mint. The pause is allowed even when the
+/// configuration is locked.
+#[derive(Accounts)]
+pub struct PauseMint<'info> {
+    #[account(
+        mut,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    // Candy guard authority or a delegate with the updater role (the delegate
+    // record must be provided as a remaining account).
+    pub authority: Signer<'info>,
+    // Payer for the account resizing (receives the rent when the account shrinks).
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
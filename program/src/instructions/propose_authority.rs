@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{AuthorityChangeKind, AuthorityEvent},
+    state::{AuthorityProposal, CandyGuard, AUTHORITY_PROPOSAL_SEED},
+};
+
+pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+
+    proposal.candy_guard = ctx.accounts.candy_guard.key();
+    proposal.new_authority = new_authority;
+    proposal.proposed_by = ctx.accounts.authority.key();
+
+    emit!(AuthorityEvent {
+        candy_guard: proposal.candy_guard,
+        authority: proposal.proposed_by,
+        new_authority,
+        kind: AuthorityChangeKind::Propose,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(has_one = authority)]
+    pub candy_guard: Account<'info, CandyGuard>,
+    #[account(
+        init,
+        payer = authority,
+        space = AuthorityProposal::SIZE,
+        seeds = [AUTHORITY_PROPOSAL_SEED, candy_guard.key().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, AuthorityProposal>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
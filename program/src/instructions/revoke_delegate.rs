@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::DelegateEvent,
+    state::{CandyGuard, Delegate, DELEGATE_SEED},
+};
+
+pub fn revoke_delegate<'info>(ctx: Context<RevokeDelegate<'info>>) -> Result<()> {
+    emit!(DelegateEvent {
+        candy_guard: ctx.accounts.candy_guard.key(),
+        authority: ctx.accounts.authority.key(),
+        delegate: ctx.accounts.delegate_record.delegate,
+        roles: 0,
+    });
+
+    Ok(())
+}
+
+/// Remove all roles of a delegate, withdrawing the rent SOL of the delegate record.
+#[derive(Accounts)]
+pub struct RevokeDelegate<'info> {
+    #[account(has_one = authority)]
+    pub candy_guard: Account<'info, CandyGuard>,
+    #[account(
+        mut,
+        close = authority,
+        has_one = candy_guard,
+        seeds = [DELEGATE_SEED, candy_guard.key().as_ref(), delegate_record.delegate.as_ref()],
+        bump
+    )]
+    pub delegate_record: Account<'info, Delegate>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
@@ -2,7 +2,8 @@ use anchor_lang::prelude::*;
 use mpl_candy_machine_core::CandyMachine;
 
 use crate::{
-    state::{CandyGuard, CandyGuardData, GuardSet, GuardType, DATA_OFFSET, SEED},
+    guards::Pause,
+    state::{CandyGuard, GuardSet, GuardType, ScheduledUpdate, DATA_OFFSET, SEED},
     utils::assert_keys_equal,
 };
 
@@ -47,8 +48,18 @@ pub fn route<'info>(
     let guard_set = if let Some(account) = &candy_guard_account {
         let account_info = account.to_account_info();
         let data = account_info.data.borrow();
-        // loads the active guard set
-        let guard_set = CandyGuardData::active_set(&data[DATA_OFFSET..], label)?;
+
+        // checks whether the route instruction is paused or not
+        let (default, _) = GuardSet::from_data(&data[DATA_OFFSET..])?;
+        Pause::assert_route_allowed(&default, args.guard.clone())?;
+
+        // loads the active guard set (taking into account any scheduled update)
+        let guard_set = ScheduledUpdate::active_set(
+            &account.key(),
+            &data[DATA_OFFSET..],
+            label,
+            ctx.remaining_accounts,
+        )?;
 
         Some(guard_set)
     } else {
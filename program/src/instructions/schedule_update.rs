@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use solana_program::{program::invoke_signed, system_instruction};
+
+use crate::{
+    errors::CandyGuardError,
+    events::{UpdateEvent, UpdateKind},
+    guards::{ConfigLock, PendingUpdate},
+    state::{
+        CandyGuard, CandyGuardData, GuardSet, ScheduledUpdate, DATA_OFFSET, SCHEDULED_DATA_OFFSET,
+        SCHEDULED_UPDATE_SEED, SEED,
+    },
+};
+
+pub fn schedule_update(
+    ctx: Context<ScheduleUpdate>,
+    activation: i64,
+    label: Option<String>,
+    data: Vec<u8>,
+) -> Result<()> {
+    if activation <= Clock::get()?.unix_timestamp {
+        return err!(CandyGuardError::InvalidActivationTime);
+    }
+
+    let account_info = ctx.accounts.candy_guard.to_account_info();
+    ConfigLock::assert_unlocked(&account_info)?;
+
+    let mut current = CandyGuardData::load(&account_info.data.borrow()[DATA_OFFSET..])?;
+
+    let scheduled_update_info = ctx.accounts.scheduled_update.to_account_info();
+
+    if current.default.pending_update.is_some() || !scheduled_update_info.data_is_empty() {
+        return err!(CandyGuardError::ScheduledUpdateAlreadyExists);
+    }
+
+    // validates the scheduled configuration
+
+    let scheduled_data = if let Some(label) = label.clone() {
+        let (guards, _) = GuardSet::from_data(&data)?;
+        // sanity check: the bytes read must match the data size
+        if guards.size() != data.len() {
+            return err!(CandyGuardError::DeserializationError);
+        }
+        // the group replacement must result in a valid configuration
+        let mut scheduled = current.clone();
+        scheduled.replace_group(label, guards.clone())?;
+        scheduled.verify()?;
+
+        let mut scheduled_data = vec![0; guards.size()];
+        guards.to_data(&mut scheduled_data)?;
+        scheduled_data
+    } else {
+        let mut scheduled = CandyGuardData::load(&data)?;
+        // the managed guards are not part of the scheduled configuration
+        scheduled.default.clear_managed_guards();
+        scheduled.verify()?;
+
+        let mut scheduled_data = vec![0; scheduled.size()];
+        scheduled.save(&mut scheduled_data)?;
+        scheduled_data
+    };
+
+    // creates the scheduled update PDA
+
+    let candy_guard_key = ctx.accounts.candy_guard.key();
+    let size = SCHEDULED_DATA_OFFSET + scheduled_data.len();
+
+    let signer = [
+        SCHEDULED_UPDATE_SEED,
+        candy_guard_key.as_ref(),
+        &[*ctx.bumps.get("scheduled_update").unwrap()],
+    ];
+    let rent = Rent::get()?;
+
+    invoke_signed(
+        &system_instruction::create_account(
+            &ctx.accounts.payer.key(),
+            &scheduled_update_info.key(),
+            rent.minimum_balance(size),
+            size as u64,
+            &crate::ID,
+        ),
+        &[
+            ctx.accounts.payer.to_account_info(),
+            scheduled_update_info.clone(),
+        ],
+        &[&signer],
+    )?;
+
+    // the account was only checked to be empty, so the header is written without
+    // expecting a discriminator
+    let mut scheduled_update: Account<ScheduledUpdate> =
+        Account::try_from_unchecked(&scheduled_update_info)?;
+    scheduled_update.candy_guard = candy_guard_key;
+    scheduled_update.activation = activation;
+    scheduled_update.label = ScheduledUpdate::padded_label(label)?;
+    scheduled_update.exit(&crate::ID)?;
+
+    scheduled_update_info.data.borrow_mut()[SCHEDULED_DATA_OFFSET..]
+        .copy_from_slice(&scheduled_data);
+
+    // signals the pending update on the candy guard configuration
+
+    current.default.pending_update = Some(PendingUpdate { activation });
+
+    current.save_to_account(
+        &account_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(UpdateEvent {
+        candy_guard: ctx.accounts.candy_guard.key(),
+        authority: ctx.accounts.authority.key(),
+        kind: UpdateKind::ScheduleUpdate,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(activation: i64, label: Option<String>, data: Vec<u8>)]
+pub struct ScheduleUpdate<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    /// CHECK: account constraints checked in instruction
+    #[account(
+        mut,
+        seeds = [SCHEDULED_UPDATE_SEED, candy_guard.key().as_ref()],
+        bump
+    )]
+    pub scheduled_update: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    // Payer for the scheduled update account and the candy guard account resizing.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,12 +1,22 @@
 use anchor_lang::prelude::*;
 
-use crate::state::CandyGuard;
+use crate::{
+    events::{AuthorityChangeKind, AuthorityEvent},
+    state::CandyGuard,
+};
 
 pub fn set_authority(ctx: Context<SetAuthority>, new_authority: Pubkey) -> Result<()> {
     let candy_guard = &mut ctx.accounts.candy_guard;
 
     candy_guard.authority = new_authority;
 
+    emit!(AuthorityEvent {
+        candy_guard: candy_guard.key(),
+        authority: ctx.accounts.authority.key(),
+        new_authority,
+        kind: AuthorityChangeKind::Set,
+    });
+
     Ok(())
 }
 
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use solana_program::{program::invoke_signed, system_instruction};
+
+use crate::{
+    errors::CandyGuardError,
+    events::DelegateEvent,
+    state::{CandyGuard, Delegate, DelegateRole, DELEGATE_SEED},
+    utils::assert_keys_equal,
+};
+
+pub fn set_delegate(ctx: Context<SetDelegate>, delegate: Pubkey, roles: u8) -> Result<()> {
+    if roles == 0 || roles & !DelegateRole::all() > 0 {
+        return err!(CandyGuardError::InvalidDelegateRoles);
+    }
+
+    let candy_guard_key = ctx.accounts.candy_guard.key();
+    let delegate_info = ctx.accounts.delegate_record.to_account_info();
+
+    let mut delegate_record: Account<Delegate> = if delegate_info.data_is_empty() {
+        let signer = [
+            DELEGATE_SEED,
+            candy_guard_key.as_ref(),
+            delegate.as_ref(),
+            &[*ctx.bumps.get("delegate_record").unwrap()],
+        ];
+        let rent = Rent::get()?;
+
+        invoke_signed(
+            &system_instruction::create_account(
+                &ctx.accounts.payer.key(),
+                &delegate_info.key(),
+                rent.minimum_balance(Delegate::SIZE),
+                Delegate::SIZE as u64,
+                &crate::ID,
+            ),
+            &[ctx.accounts.payer.to_account_info(), delegate_info.clone()],
+            &[&signer],
+        )?;
+
+        // the record was just created by this instruction, so it has no
+        // discriminator to validate yet
+        let mut delegate_record: Account<Delegate> = Account::try_from_unchecked(&delegate_info)?;
+        delegate_record.candy_guard = candy_guard_key;
+        delegate_record.delegate = delegate;
+
+        delegate_record
+    } else {
+        let delegate_record: Account<Delegate> = Account::try_from(&delegate_info)?;
+        assert_keys_equal(&delegate_record.candy_guard, &candy_guard_key)?;
+
+        delegate_record
+    };
+
+    // (re)assigning the roles binds them to the current authority
+    delegate_record.authority = ctx.accounts.authority.key();
+    delegate_record.roles = roles;
+    delegate_record.exit(&crate::ID)?;
+
+    emit!(DelegateEvent {
+        candy_guard: candy_guard_key,
+        authority: delegate_record.authority,
+        delegate,
+        roles,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct SetDelegate<'info> {
+    #[account(has_one = authority)]
+    pub candy_guard: Account<'info, CandyGuard>,
+    /// CHECK: account constraints checked in instruction
+    #[account(
+        mut,
+        seeds = [DELEGATE_SEED, candy_guard.key().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub delegate_record: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    // Payer for the delegate account creation.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -4,9 +4,11 @@ use mpl_candy_machine_core::{
     CandyMachine,
 };
 
-use crate::state::CandyGuard;
+use crate::{guards::ConfigLock, state::CandyGuard};
 
 pub fn unwrap(ctx: Context<Unwrap>) -> Result<()> {
+    ConfigLock::assert_unlocked(&ctx.accounts.candy_guard.to_account_info())?;
+
     let candy_machine_program = ctx.accounts.candy_machine_program.to_account_info();
     let candy_machine_authority = &ctx.accounts.candy_machine_authority;
 
@@ -1,94 +1,124 @@
 use anchor_lang::prelude::*;
-use solana_program::{
-    entrypoint::MAX_PERMITTED_DATA_INCREASE, program::invoke, system_instruction,
-};
 
 use crate::{
     errors::CandyGuardError,
-    state::{CandyGuard, CandyGuardData, DATA_OFFSET, SEED},
+    events::{UpdateEvent, UpdateKind},
+    guards::ConfigLock,
+    state::{CandyGuard, CandyGuardData, Delegate, DelegateRole, GuardSet, DATA_OFFSET, SEED},
 };
 
-pub fn update(ctx: Context<Update>, data: Vec<u8>) -> Result<()> {
+pub fn update<'info>(ctx: Context<'_, '_, '_, 'info, Update<'info>>, data: Vec<u8>) -> Result<()> {
+    // only the authority or a delegate with the updater role can update
+    // the configuration
+    let is_authority = Delegate::assert_authority_or_role(
+        &ctx.accounts.candy_guard.key(),
+        &ctx.accounts.candy_guard.authority,
+        &ctx.accounts.authority.to_account_info(),
+        DelegateRole::Updater,
+        ctx.remaining_accounts,
+    )?;
+
+    let account_info = ctx.accounts.candy_guard.to_account_info();
+    ConfigLock::assert_unlocked(&account_info)?;
+
     // deserializes the candy guard data
-    let data = CandyGuardData::load(&data)?;
+    let mut data = CandyGuardData::load(&data)?;
+
+    // the pending update, config lock and pause guards are managed by their own
+    // instructions, so the current values are preserved
+    let (current, _) = GuardSet::from_data(&account_info.data.borrow()[DATA_OFFSET..])?;
+    data.default.copy_managed_guards(&current);
+
+    // delegates can only change dates and prices
+    if !is_authority {
+        let current = CandyGuardData::load(&account_info.data.borrow()[DATA_OFFSET..])?;
+        assert_only_dates_and_prices(&current, &data)?;
+    }
+
     // validates guard settings
     data.verify()?;
 
-    let account_info = ctx.accounts.candy_guard.to_account_info();
+    // save the guards information to the account data and stores
+    // the updated feature flag
+    data.save_to_account(
+        &account_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
 
-    // check whether we need to grow or shrink the account size or not
-    if data.account_size() != account_info.data_len() {
-        // no risk of overflow here since the sizes will range from DATA_OFFSET to 10_000_000
-        let difference = data.account_size() as i64 - account_info.data_len() as i64;
-        let snapshot = account_info.lamports();
+    emit!(UpdateEvent {
+        candy_guard: ctx.accounts.candy_guard.key(),
+        authority: ctx.accounts.authority.key(),
+        kind: UpdateKind::Update,
+    });
 
-        if difference > 0 {
-            if difference as usize > MAX_PERMITTED_DATA_INCREASE {
-                return err!(CandyGuardError::DataIncrementLimitExceeded);
-            }
+    Ok(())
+}
 
-            let lamports_diff = Rent::get()?
-                .minimum_balance(data.account_size())
-                .checked_sub(snapshot)
-                .ok_or(CandyGuardError::NumericalOverflowError)?;
-
-            msg!("Funding {} lamports for account realloc", lamports_diff);
-
-            invoke(
-                &system_instruction::transfer(
-                    ctx.accounts.payer.key,
-                    account_info.key,
-                    lamports_diff,
-                ),
-                &[
-                    ctx.accounts.payer.to_account_info(),
-                    account_info.clone(),
-                    ctx.accounts.system_program.to_account_info(),
-                ],
-            )?;
-        } else {
-            let lamports_diff = snapshot
-                .checked_sub(Rent::get()?.minimum_balance(data.account_size()))
-                .ok_or(CandyGuardError::NumericalOverflowError)?;
-
-            msg!(
-                "Withdrawing {} lamports from account realloc",
-                lamports_diff
-            );
-
-            **account_info.lamports.borrow_mut() = snapshot - lamports_diff;
-            let payer = &ctx.accounts.payer;
-
-            **payer.lamports.borrow_mut() = payer
-                .lamports()
-                .checked_add(lamports_diff)
-                .ok_or(CandyGuardError::NumericalOverflowError)?;
+/// Checks that the only differences between the `current` and `new` configurations
+/// are the dates and prices of the guards.
+fn assert_only_dates_and_prices(current: &CandyGuardData, new: &CandyGuardData) -> Result<()> {
+    let normalize = |data: &CandyGuardData| -> Result<Vec<u8>> {
+        let mut data = data.clone();
+        clear_dates_and_prices(&mut data.default);
+
+        if let Some(groups) = &mut data.groups {
+            for group in groups {
+                clear_dates_and_prices(&mut group.guards);
+            }
         }
 
-        msg!("Account realloc by {} bytes", difference);
-        // changes the account size to fit the size required by the guards
-        // this means that the size can grow or shrink
-        account_info.realloc(data.account_size(), false)?;
-    }
+        let mut bytes = vec![0; data.size()];
+        data.save(&mut bytes)?;
 
-    // save the guards information to the account data and stores
-    // the updated feature flag
-    let mut account_data = account_info.data.borrow_mut();
-    data.save(&mut account_data[DATA_OFFSET..])?;
+        Ok(bytes)
+    };
+
+    if normalize(current)? != normalize(new)? {
+        return err!(CandyGuardError::DelegateUpdateNotAllowed);
+    }
 
     Ok(())
 }
 
+/// Resets the dates and prices of the guards in the `guard_set`.
+fn clear_dates_and_prices(guard_set: &mut GuardSet) {
+    if let Some(start_date) = &mut guard_set.start_date {
+        start_date.date = 0;
+    }
+    if let Some(end_date) = &mut guard_set.end_date {
+        end_date.date = 0;
+    }
+    if let Some(sol_payment) = &mut guard_set.sol_payment {
+        sol_payment.lamports = 0;
+    }
+    if let Some(token_payment) = &mut guard_set.token_payment {
+        token_payment.amount = 0;
+    }
+    if let Some(freeze_sol_payment) = &mut guard_set.freeze_sol_payment {
+        freeze_sol_payment.lamports = 0;
+    }
+    if let Some(freeze_token_payment) = &mut guard_set.freeze_token_payment {
+        freeze_token_payment.amount = 0;
+    }
+    if let Some(multi_token_payment) = &mut guard_set.multi_token_payment {
+        for payment in &mut multi_token_payment.payments {
+            payment.amount = 0;
+        }
+    }
+}
+
 #[derive(Accounts)]
 #[instruction(data: Vec<u8>)]
 pub struct Update<'info> {
     #[account(
         mut,
-        has_one = authority,
         seeds = [SEED, candy_guard.base.key().as_ref()],
         bump = candy_guard.bump
     )]
     pub candy_guard: Account<'info, CandyGuard>,
+    // Candy guard authority or a delegate with the updater role (the delegate
+    // record must be provided as a remaining account).
     pub authority: Signer<'info>,
     // Payer for the account resizing.
     pub payer: Signer<'info>,
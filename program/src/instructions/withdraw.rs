@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::state::CandyGuard;
+use crate::{guards::ConfigLock, state::CandyGuard};
 
-pub fn withdraw<'info>(_ctx: Context<Withdraw<'info>>) -> Result<()> {
-    Ok(())
+pub fn withdraw<'info>(ctx: Context<Withdraw<'info>>) -> Result<()> {
+    // closing the account would discard the lock, allowing the authority to
+    // initialize the candy guard again with a different configuration
+    ConfigLock::assert_unlocked(&ctx.accounts.candy_guard.to_account_info())
 }
 
 /// Withdraw the rent SOL from the candy guard account.
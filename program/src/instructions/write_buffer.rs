@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use solana_program::{
+    entrypoint::MAX_PERMITTED_DATA_INCREASE, program::invoke_signed, system_instruction,
+};
+
+use crate::{
+    errors::CandyGuardError,
+    state::{CandyGuard, UpdateBuffer, BUFFER_DATA_OFFSET, BUFFER_SEED, SEED},
+    utils::{assert_keys_equal, resize_account},
+};
+
+pub fn write_buffer(ctx: Context<WriteBuffer>, offset: u32, data: Vec<u8>) -> Result<()> {
+    let candy_guard_key = ctx.accounts.candy_guard.key();
+    let buffer_info = ctx.accounts.buffer.to_account_info();
+
+    let start = BUFFER_DATA_OFFSET + offset as usize;
+    let end = start + data.len();
+
+    if buffer_info.data_is_empty() {
+        // an account created through a CPI is subject to the same limit as a realloc
+        if end > MAX_PERMITTED_DATA_INCREASE {
+            return err!(CandyGuardError::DataIncrementLimitExceeded);
+        }
+
+        let signer = [
+            BUFFER_SEED,
+            candy_guard_key.as_ref(),
+            &[*ctx.bumps.get("buffer").unwrap()],
+        ];
+        let rent = Rent::get()?;
+
+        invoke_signed(
+            &system_instruction::create_account(
+                &ctx.accounts.payer.key(),
+                &buffer_info.key(),
+                rent.minimum_balance(end),
+                end as u64,
+                &crate::ID,
+            ),
+            &[ctx.accounts.payer.to_account_info(), buffer_info.clone()],
+            &[&signer],
+        )?;
+
+        // the account was created above, so there is no discriminator to check yet
+        let mut buffer: Account<UpdateBuffer> = Account::try_from_unchecked(&buffer_info)?;
+        buffer.candy_guard = candy_guard_key;
+        buffer.exit(&crate::ID)?;
+    } else {
+        let buffer: Account<UpdateBuffer> = Account::try_from(&buffer_info)?;
+        assert_keys_equal(&buffer.candy_guard, &candy_guard_key)?;
+
+        // a chunk at the start of the data begins a new configuration, so the buffer
+        // is truncated to discard data staged previously; other chunks only grow the
+        // buffer and can be written in any order
+        if offset == 0 || end > buffer_info.data_len() {
+            resize_account(
+                &buffer_info,
+                end,
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+        }
+    }
+
+    let mut account_data = buffer_info.data.borrow_mut();
+    account_data[start..end].copy_from_slice(&data);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(offset: u32, data: Vec<u8>)]
+pub struct WriteBuffer<'info> {
+    #[account(
+        has_one = authority,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    /// CHECK: account constraints checked in instruction
+    #[account(
+        mut,
+        seeds = [BUFFER_SEED, candy_guard.key().as_ref()],
+        bump
+    )]
+    pub buffer: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    // Payer for the buffer account creation and resizing.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,10 +1,13 @@
 use anchor_lang::prelude::*;
 
 use instructions::*;
+use state::GuardType;
 
 pub mod errors;
+pub mod events;
 pub mod guards;
 pub mod instructions;
+pub mod programmable;
 pub mod state;
 pub mod utils;
 
@@ -14,11 +17,50 @@ declare_id!("Guard1JwRhJkVH6XZhzoYxeBVQe872VH6QggF4BWmS9g");
 pub mod candy_guard {
     use super::*;
 
+    /// Accept the transfer of the candy guard authority. The proposed authority must
+    /// sign the transaction.
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority(ctx)
+    }
+
+    /// Apply an active scheduled update to the candy guard configuration.
+    pub fn apply_scheduled_update(ctx: Context<ApplyScheduledUpdate>) -> Result<()> {
+        instructions::apply_scheduled_update(ctx)
+    }
+
+    /// Cancel a pending transfer of the candy guard authority.
+    pub fn cancel_authority_proposal(ctx: Context<CancelAuthorityProposal>) -> Result<()> {
+        instructions::cancel_authority_proposal(ctx)
+    }
+
+    /// Cancel a scheduled update of the candy guard configuration.
+    pub fn cancel_scheduled_update(ctx: Context<CancelScheduledUpdate>) -> Result<()> {
+        instructions::cancel_scheduled_update(ctx)
+    }
+
+    /// Close the update buffer of a candy guard, discarding the staged data.
+    pub fn close_buffer(ctx: Context<CloseBuffer>) -> Result<()> {
+        instructions::close_buffer(ctx)
+    }
+
+    /// Commit the data staged in the update buffer to the candy guard account. The
+    /// instruction needs to be called multiple times when the account must grow by
+    /// more than the realloc limit.
+    pub fn commit_buffer(ctx: Context<CommitBuffer>) -> Result<()> {
+        instructions::commit_buffer(ctx)
+    }
+
     /// Create a new candy guard account.
     pub fn initialize(ctx: Context<Initialize>, data: Vec<u8>) -> Result<()> {
         instructions::initialize(ctx, data)
     }
 
+    /// Lock the candy guard configuration permanently (when `until` is not specified)
+    /// or until the specified time.
+    pub fn lock_config(ctx: Context<LockConfig>, until: Option<i64>) -> Result<()> {
+        instructions::lock_config(ctx, until)
+    }
+
     /// Mint an NFT from a candy machine wrapped in the candy guard.
     pub fn mint<'info>(
         ctx: Context<'_, '_, '_, 'info, Mint<'info>>,
@@ -28,6 +70,32 @@ pub mod candy_guard {
         instructions::mint(ctx, mint_args, label)
     }
 
+    /// Pause the mint (and optionally the route instructions of the specified guards)
+    /// across all groups.
+    pub fn pause<'info>(
+        ctx: Context<'_, '_, '_, 'info, PauseMint<'info>>,
+        reason: Option<u32>,
+        routes: Vec<GuardType>,
+    ) -> Result<()> {
+        instructions::pause(ctx, reason, routes)
+    }
+
+    /// Propose a new authority of the candy guard. The transfer only takes effect
+    /// once the proposed authority accepts it.
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority(ctx, new_authority)
+    }
+
+    /// Remove a delegate of the candy guard, closing the delegate record.
+    pub fn revoke_delegate(ctx: Context<RevokeDelegate>) -> Result<()> {
+        instructions::revoke_delegate(ctx)
+    }
+
+    /// Resume a paused mint.
+    pub fn resume<'info>(ctx: Context<'_, '_, '_, 'info, PauseMint<'info>>) -> Result<()> {
+        instructions::resume(ctx)
+    }
+
     /// Route the transaction to a guard instruction.
     pub fn route<'info>(
         ctx: Context<'_, '_, '_, 'info, Route<'info>>,
@@ -37,19 +105,43 @@ pub mod candy_guard {
         instructions::route(ctx, args, label)
     }
 
+    /// Schedule a change of the candy guard configuration (or of a single group) that
+    /// takes effect at the specified activation time.
+    pub fn schedule_update(
+        ctx: Context<ScheduleUpdate>,
+        activation: i64,
+        label: Option<String>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::schedule_update(ctx, activation, label, data)
+    }
+
     /// Set a new authority of the candy guard.
+    ///
+    /// Deprecated: use `propose_authority` and `accept_authority`, which require the
+    /// new authority to sign the transfer.
     pub fn set_authority(ctx: Context<SetAuthority>, new_authority: Pubkey) -> Result<()> {
         instructions::set_authority(ctx, new_authority)
     }
 
+    /// Set the roles of a delegate of the candy guard. Only the candy guard authority
+    /// can manage the roles of delegates.
+    pub fn set_delegate(ctx: Context<SetDelegate>, delegate: Pubkey, roles: u8) -> Result<()> {
+        instructions::set_delegate(ctx, delegate, roles)
+    }
+
     /// Remove a candy guard from a candy machine, setting the authority to the
     /// candy guard authority.
     pub fn unwrap(ctx: Context<Unwrap>) -> Result<()> {
         instructions::unwrap(ctx)
     }
 
-    /// Update the candy guard configuration.
-    pub fn update(ctx: Context<Update>, data: Vec<u8>) -> Result<()> {
+    /// Update the candy guard configuration. Delegates with the updater role can
+    /// only change dates and prices.
+    pub fn update<'info>(
+        ctx: Context<'_, '_, '_, 'info, Update<'info>>,
+        data: Vec<u8>,
+    ) -> Result<()> {
         instructions::update(ctx, data)
     }
 
@@ -63,4 +155,10 @@ pub mod candy_guard {
     pub fn wrap(ctx: Context<Wrap>) -> Result<()> {
         instructions::wrap(ctx)
     }
+
+    /// Write a chunk of candy guard data to the update buffer, starting at the
+    /// specified offset.
+    pub fn write_buffer(ctx: Context<WriteBuffer>, offset: u32, data: Vec<u8>) -> Result<()> {
+        instructions::write_buffer(ctx, offset, data)
+    }
 }
@@ -0,0 +1,601 @@
+//! Helpers to interact with programmable NFTs (pNFTs).
+//!
+//! The token-metadata crate used by the program predates programmable NFTs, so the
+//! metadata fields and instructions specific to pNFTs are read and built directly
+//! from their serialized layout.
+
+use anchor_lang::prelude::*;
+use mpl_candy_machine_core::CandyMachine;
+use mpl_token_metadata::{
+    pda::find_master_edition_account,
+    state::{Collection, CollectionDetails, Data, Key, Metadata, TokenMetadataAccount, Uses},
+};
+use solana_program::{instruction::Instruction, program::invoke_signed};
+
+use crate::{errors::CandyGuardError, utils::cmp_pubkeys};
+
+/// Token-auth-rules program.
+pub mod mpl_token_auth_rules {
+    anchor_lang::declare_id!("auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg");
+}
+
+// Token standard of programmable NFTs.
+const PROGRAMMABLE_NON_FUNGIBLE: u8 = 4;
+
+// Seed of the token record PDA.
+const TOKEN_RECORD_SEED: &[u8] = b"token_record";
+
+// Offset of the token state in the token record account (after the key and bump).
+const TOKEN_STATE_OFFSET: usize = 2;
+
+// Token state of a locked token.
+const TOKEN_STATE_LOCKED: u8 = 1;
+
+// Token-metadata instruction discriminators.
+const BURN: u8 = 41;
+const DELEGATE: u8 = 44;
+const REVOKE: u8 = 45;
+const LOCK: u8 = 46;
+const UNLOCK: u8 = 47;
+const TRANSFER: u8 = 49;
+
+// Utility variant of the delegate and revoke arguments.
+const UTILITY_DELEGATE: u8 = 4;
+
+/// Programmable configuration of a pNFT.
+pub struct ProgrammableConfig {
+    /// Address of the rule set (authorization rules) of the pNFT.
+    pub rule_set: Option<Pubkey>,
+}
+
+/// Accounts of a programmable NFT and its token account.
+pub struct ProgrammableNft<'a> {
+    pub mint: AccountInfo<'a>,
+    pub metadata: AccountInfo<'a>,
+    pub master_edition: AccountInfo<'a>,
+    pub token: AccountInfo<'a>,
+    pub token_record: AccountInfo<'a>,
+}
+
+/// Program and sysvar accounts required by the token-metadata instructions.
+pub struct ProgrammablePrograms<'a> {
+    pub token_metadata_program: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub sysvar_instructions: AccountInfo<'a>,
+    pub spl_token_program: AccountInfo<'a>,
+    /// Token-auth-rules program and rule set accounts (only required when the pNFT
+    /// has a rule set).
+    pub authorization_rules: Option<(AccountInfo<'a>, AccountInfo<'a>)>,
+}
+
+/// Destination accounts of a pNFT transfer.
+pub struct TransferDestination<'a> {
+    pub owner: AccountInfo<'a>,
+    pub token: AccountInfo<'a>,
+    pub token_record: AccountInfo<'a>,
+    pub spl_ata_program: AccountInfo<'a>,
+}
+
+/// Deserializes a metadata account, including the fields of programmable NFTs. Returns
+/// the metadata and the programmable configuration (`None` if the NFT is not programmable).
+pub fn load_metadata(account: &AccountInfo) -> Result<(Metadata, Option<ProgrammableConfig>)> {
+    let mut metadata = Metadata::from_account_info(account)?;
+
+    let data = account.try_borrow_data()?;
+    let mut buf: &[u8] = &data;
+
+    // the token-metadata crate discards the token standard, collection and uses
+    // of pNFTs, since it does not know the programmable token standard
+    let programmable = (|| -> std::io::Result<Option<ProgrammableConfig>> {
+        Key::deserialize(&mut buf)?;
+        Pubkey::deserialize(&mut buf)?; // update authority
+        Pubkey::deserialize(&mut buf)?; // mint
+        Data::deserialize(&mut buf)?;
+        bool::deserialize(&mut buf)?; // primary sale happened
+        bool::deserialize(&mut buf)?; // is mutable
+        Option::<u8>::deserialize(&mut buf)?; // edition nonce
+
+        if Option::<u8>::deserialize(&mut buf)? != Some(PROGRAMMABLE_NON_FUNGIBLE) {
+            return Ok(None);
+        }
+
+        metadata.collection = Option::<Collection>::deserialize(&mut buf)?;
+        metadata.uses = Option::<Uses>::deserialize(&mut buf)?;
+        metadata.collection_details = Option::<CollectionDetails>::deserialize(&mut buf)?;
+        // programmable config (`V1` variant followed by the optional rule set)
+        let rule_set = Option::<(u8, Option<Pubkey>)>::deserialize(&mut buf)?
+            .and_then(|(_, rule_set)| rule_set);
+
+        Ok(Some(ProgrammableConfig { rule_set }))
+    })()
+    .map_err(|_| CandyGuardError::InvalidProgrammableMetadata)?;
+
+    Ok((metadata, programmable))
+}
+
+/// Checks whether the candy machine mints programmable NFTs or not. The token standard
+/// is stored in the second byte of the candy machine `features` field.
+pub fn mints_programmable(candy_machine: &CandyMachine) -> bool {
+    candy_machine.features.to_le_bytes()[1] == PROGRAMMABLE_NON_FUNGIBLE
+}
+
+/// Returns the address of the token record PDA of a token account.
+pub fn token_record_address(mint: &Pubkey, token: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::ID.as_ref(),
+            mint.as_ref(),
+            TOKEN_RECORD_SEED,
+            token.as_ref(),
+        ],
+        &mpl_token_metadata::ID,
+    )
+    .0
+}
+
+/// Returns the address of the master edition PDA of a mint.
+pub fn master_edition_address(mint: &Pubkey) -> Pubkey {
+    find_master_edition_account(mint).0
+}
+
+/// Checks whether the token of a token record is locked or not.
+pub fn is_locked(token_record: &AccountInfo) -> Result<bool> {
+    if !cmp_pubkeys(token_record.owner, &mpl_token_metadata::ID) {
+        return err!(CandyGuardError::IncorrectOwner);
+    }
+
+    let data = token_record.try_borrow_data()?;
+
+    match data.get(TOKEN_STATE_OFFSET) {
+        Some(state) => Ok(*state == TOKEN_STATE_LOCKED),
+        None => err!(CandyGuardError::InvalidProgrammableMetadata),
+    }
+}
+
+/// Looks for an account in the `accounts` list by its address.
+pub fn find_account<'c, 'info>(
+    accounts: &'c [AccountInfo<'info>],
+    key: &Pubkey,
+) -> Result<&'c AccountInfo<'info>> {
+    accounts
+        .iter()
+        .find(|account| cmp_pubkeys(account.key, key))
+        .ok_or_else(|| error!(CandyGuardError::MissingRemainingAccount))
+}
+
+/// Looks for the token-auth-rules program and rule set accounts of a pNFT in the
+/// `accounts` list. Returns `None` when the pNFT does not have a rule set.
+pub fn find_authorization_rules<'info>(
+    accounts: &[AccountInfo<'info>],
+    config: &ProgrammableConfig,
+) -> Result<Option<(AccountInfo<'info>, AccountInfo<'info>)>> {
+    if let Some(rule_set) = &config.rule_set {
+        let program = find_account(accounts, &mpl_token_auth_rules::ID)?;
+        let rule_set = find_account(accounts, rule_set)?;
+
+        Ok(Some((
+            program.to_account_info(),
+            rule_set.to_account_info(),
+        )))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Burns a pNFT using the token-metadata `Burn` instruction.
+pub fn burn<'a>(
+    programs: &ProgrammablePrograms<'a>,
+    nft: &ProgrammableNft<'a>,
+    authority: &AccountInfo<'a>,
+    collection_metadata: Option<&AccountInfo<'a>>,
+) -> Result<()> {
+    let placeholder = &programs.token_metadata_program;
+
+    let accounts = [
+        (authority, true, true),
+        (collection_metadata.unwrap_or(placeholder), true, false),
+        (&nft.metadata, true, false),
+        (&nft.master_edition, true, false),
+        (&nft.mint, true, false),
+        (&nft.token, true, false),
+        // parent master edition accounts (only used when burning print editions)
+        (placeholder, false, false),
+        (placeholder, false, false),
+        (placeholder, false, false),
+        (placeholder, false, false),
+        (&nft.token_record, true, false),
+        (&programs.system_program, false, false),
+        (&programs.sysvar_instructions, false, false),
+        (&programs.spl_token_program, false, false),
+    ];
+
+    // BurnArgs::V1 { amount }
+    let mut data = vec![BURN, 0];
+    data.extend_from_slice(&1u64.to_le_bytes());
+
+    invoke_metadata_instruction(programs, &accounts, data, &[])
+}
+
+/// Transfers a pNFT using the token-metadata `Transfer` instruction. The `owner` is
+/// the authority of the transfer and pays for the creation of the destination
+/// accounts.
+pub fn transfer<'a>(
+    programs: &ProgrammablePrograms<'a>,
+    nft: &ProgrammableNft<'a>,
+    owner: &AccountInfo<'a>,
+    destination: &TransferDestination<'a>,
+) -> Result<()> {
+    let mut accounts = vec![
+        (&nft.token, true, false),
+        (owner, false, false),
+        (&destination.token, true, false),
+        (&destination.owner, false, false),
+        (&nft.mint, false, false),
+        (&nft.metadata, true, false),
+        (&nft.master_edition, false, false),
+        (&nft.token_record, true, false),
+        (&destination.token_record, true, false),
+        (owner, false, true),
+        (owner, true, true),
+        (&programs.system_program, false, false),
+        (&programs.sysvar_instructions, false, false),
+        (&programs.spl_token_program, false, false),
+        (&destination.spl_ata_program, false, false),
+    ];
+    push_authorization_rules(programs, &mut accounts);
+
+    // TransferArgs::V1 { amount, authorization_data: None }
+    let mut data = vec![TRANSFER, 0];
+    data.extend_from_slice(&1u64.to_le_bytes());
+    data.push(0);
+
+    invoke_metadata_instruction(programs, &accounts, data, &[])
+}
+
+/// Approves a utility delegate on a pNFT using the token-metadata `Delegate`
+/// instruction. The `owner` of the token is the authority of the instruction.
+pub fn delegate_utility<'a>(
+    programs: &ProgrammablePrograms<'a>,
+    nft: &ProgrammableNft<'a>,
+    delegate: &AccountInfo<'a>,
+    owner: &AccountInfo<'a>,
+) -> Result<()> {
+    let accounts = delegate_accounts(programs, nft, delegate, owner);
+
+    // DelegateArgs::UtilityV1 { amount, authorization_data: None }
+    let mut data = vec![DELEGATE, UTILITY_DELEGATE];
+    data.extend_from_slice(&1u64.to_le_bytes());
+    data.push(0);
+
+    invoke_metadata_instruction(programs, &accounts, data, &[])
+}
+
+/// Revokes the utility delegate of a pNFT using the token-metadata `Revoke`
+/// instruction. The `owner` of the token is the authority of the instruction.
+pub fn revoke_utility<'a>(
+    programs: &ProgrammablePrograms<'a>,
+    nft: &ProgrammableNft<'a>,
+    delegate: &AccountInfo<'a>,
+    owner: &AccountInfo<'a>,
+) -> Result<()> {
+    let accounts = delegate_accounts(programs, nft, delegate, owner);
+
+    // RevokeArgs::UtilityV1
+    let data = vec![REVOKE, UTILITY_DELEGATE];
+
+    invoke_metadata_instruction(programs, &accounts, data, &[])
+}
+
+/// Locks a pNFT using the token-metadata `Lock` instruction. The `authority` must be
+/// the utility delegate of the token.
+pub fn lock<'a>(
+    programs: &ProgrammablePrograms<'a>,
+    nft: &ProgrammableNft<'a>,
+    authority: &AccountInfo<'a>,
+    token_owner: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let accounts = lock_accounts(programs, nft, authority, token_owner, payer);
+
+    // LockArgs::V1 { authorization_data: None }
+    let data = vec![LOCK, 0, 0];
+
+    invoke_metadata_instruction(programs, &accounts, data, signer_seeds)
+}
+
+/// Unlocks a pNFT using the token-metadata `Unlock` instruction. The `authority` must
+/// be the utility delegate of the token.
+pub fn unlock<'a>(
+    programs: &ProgrammablePrograms<'a>,
+    nft: &ProgrammableNft<'a>,
+    authority: &AccountInfo<'a>,
+    token_owner: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let accounts = lock_accounts(programs, nft, authority, token_owner, payer);
+
+    // UnlockArgs::V1 { authorization_data: None }
+    let data = vec![UNLOCK, 0, 0];
+
+    invoke_metadata_instruction(programs, &accounts, data, signer_seeds)
+}
+
+// List of accounts of an instruction: account, writable and signer flags.
+type AccountList<'b, 'a> = Vec<(&'b AccountInfo<'a>, bool, bool)>;
+
+fn delegate_accounts<'b, 'a>(
+    programs: &'b ProgrammablePrograms<'a>,
+    nft: &'b ProgrammableNft<'a>,
+    delegate: &'b AccountInfo<'a>,
+    owner: &'b AccountInfo<'a>,
+) -> AccountList<'b, 'a> {
+    let mut accounts = vec![
+        // delegate record (only used by metadata delegates)
+        (&programs.token_metadata_program, false, false),
+        (delegate, false, false),
+        (&nft.metadata, true, false),
+        (&nft.master_edition, false, false),
+        (&nft.token_record, true, false),
+        (&nft.mint, false, false),
+        (&nft.token, true, false),
+        (owner, false, true),
+        (owner, true, true),
+        (&programs.system_program, false, false),
+        (&programs.sysvar_instructions, false, false),
+        (&programs.spl_token_program, false, false),
+    ];
+    push_authorization_rules(programs, &mut accounts);
+
+    accounts
+}
+
+fn lock_accounts<'b, 'a>(
+    programs: &'b ProgrammablePrograms<'a>,
+    nft: &'b ProgrammableNft<'a>,
+    authority: &'b AccountInfo<'a>,
+    token_owner: &'b AccountInfo<'a>,
+    payer: &'b AccountInfo<'a>,
+) -> AccountList<'b, 'a> {
+    let mut accounts = vec![
+        (authority, false, true),
+        (token_owner, false, false),
+        (&nft.token, true, false),
+        (&nft.mint, false, false),
+        (&nft.metadata, true, false),
+        (&nft.master_edition, false, false),
+        (&nft.token_record, true, false),
+        (payer, true, true),
+        (&programs.system_program, false, false),
+        (&programs.sysvar_instructions, false, false),
+        (&programs.spl_token_program, false, false),
+    ];
+    push_authorization_rules(programs, &mut accounts);
+
+    accounts
+}
+
+/// Adds the (optional) token-auth-rules program and rule set accounts, which are
+/// the last accounts of the instructions.
+fn push_authorization_rules<'b, 'a>(
+    programs: &'b ProgrammablePrograms<'a>,
+    accounts: &mut AccountList<'b, 'a>,
+) {
+    if let Some((program, rule_set)) = &programs.authorization_rules {
+        accounts.push((program, false, false));
+        accounts.push((rule_set, false, false));
+    } else {
+        accounts.push((&programs.token_metadata_program, false, false));
+        accounts.push((&programs.token_metadata_program, false, false));
+    }
+}
+
+fn invoke_metadata_instruction<'a>(
+    programs: &ProgrammablePrograms<'a>,
+    accounts: &[(&AccountInfo<'a>, bool, bool)],
+    data: Vec<u8>,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let metas = accounts
+        .iter()
+        .map(|(account, writable, signer)| {
+            // optional accounts that are not present are represented by the
+            // token-metadata program id
+            if cmp_pubkeys(account.key, &mpl_token_metadata::ID) {
+                AccountMeta::new_readonly(mpl_token_metadata::ID, false)
+            } else if *writable {
+                AccountMeta::new(*account.key, *signer)
+            } else {
+                AccountMeta::new_readonly(*account.key, *signer)
+            }
+        })
+        .collect();
+
+    let mut infos: Vec<AccountInfo> = accounts
+        .iter()
+        .map(|(account, _, _)| account.to_account_info())
+        .collect();
+    infos.push(programs.token_metadata_program.to_account_info());
+
+    let instruction = Instruction {
+        program_id: mpl_token_metadata::ID,
+        accounts: metas,
+        data,
+    };
+
+    let mut signers = vec![];
+    if !signer_seeds.is_empty() {
+        signers.push(signer_seeds);
+    }
+
+    invoke_signed(&instruction, &infos, &signers).map_err(|error| error.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use mpl_token_metadata::state::MAX_METADATA_LEN;
+
+    use super::*;
+
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+    }
+
+    impl TestAccount {
+        fn new(owner: Pubkey, data: Vec<u8>) -> Self {
+            Self {
+                key: Pubkey::new_unique(),
+                owner,
+                lamports: 1,
+                data,
+            }
+        }
+
+        /// Metadata account with the given token standard, collection and (for pNFTs)
+        /// programmable config.
+        fn metadata(
+            mint: &Pubkey,
+            token_standard: u8,
+            collection: Option<Collection>,
+            rule_set: Option<Option<Pubkey>>,
+        ) -> Self {
+            let mut data = Vec::with_capacity(MAX_METADATA_LEN);
+            Key::MetadataV1.serialize(&mut data).unwrap();
+            Pubkey::new_unique().serialize(&mut data).unwrap(); // update authority
+            mint.serialize(&mut data).unwrap();
+            Data {
+                name: "NFT".to_string(),
+                symbol: "NFT".to_string(),
+                uri: "https://example.com".to_string(),
+                seller_fee_basis_points: 500,
+                creators: None,
+            }
+            .serialize(&mut data)
+            .unwrap();
+            false.serialize(&mut data).unwrap(); // primary sale happened
+            true.serialize(&mut data).unwrap(); // is mutable
+            Some(255u8).serialize(&mut data).unwrap(); // edition nonce
+            Some(token_standard).serialize(&mut data).unwrap();
+            collection.serialize(&mut data).unwrap();
+            Option::<Uses>::None.serialize(&mut data).unwrap();
+            Option::<CollectionDetails>::None
+                .serialize(&mut data)
+                .unwrap();
+            rule_set
+                .map(|rule_set| (0u8, rule_set))
+                .serialize(&mut data)
+                .unwrap();
+            data.resize(MAX_METADATA_LEN, 0);
+
+            Self::new(mpl_token_metadata::ID, data)
+        }
+
+        fn info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(
+                &self.key,
+                false,
+                false,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                false,
+                0,
+            )
+        }
+    }
+
+    fn verified_collection() -> Collection {
+        Collection {
+            verified: true,
+            key: Pubkey::new_unique(),
+        }
+    }
+
+    #[test]
+    fn regular_nft_is_not_programmable() {
+        let mint = Pubkey::new_unique();
+        let collection = verified_collection();
+        // token standard of non-fungible tokens
+        let mut account = TestAccount::metadata(&mint, 0, Some(collection.clone()), None);
+
+        let (metadata, config) = load_metadata(&account.info()).unwrap();
+        assert!(config.is_none());
+        assert_eq!(metadata.mint, mint);
+        assert_eq!(metadata.collection, Some(collection));
+    }
+
+    #[test]
+    fn programmable_nft_fields_are_read() {
+        let mint = Pubkey::new_unique();
+        let rule_set = Pubkey::new_unique();
+        let collection = verified_collection();
+        let mut account = TestAccount::metadata(
+            &mint,
+            PROGRAMMABLE_NON_FUNGIBLE,
+            Some(collection.clone()),
+            Some(Some(rule_set)),
+        );
+
+        let (metadata, config) = load_metadata(&account.info()).unwrap();
+        assert_eq!(metadata.mint, mint);
+        assert_eq!(metadata.collection, Some(collection));
+        assert_eq!(config.unwrap().rule_set, Some(rule_set));
+    }
+
+    #[test]
+    fn programmable_nft_without_rule_set() {
+        let mint = Pubkey::new_unique();
+        // pNFT without a programmable config
+        let mut account = TestAccount::metadata(&mint, PROGRAMMABLE_NON_FUNGIBLE, None, None);
+        let (_, config) = load_metadata(&account.info()).unwrap();
+        assert_eq!(config.unwrap().rule_set, None);
+
+        // pNFT with a programmable config without a rule set
+        let mut account = TestAccount::metadata(&mint, PROGRAMMABLE_NON_FUNGIBLE, None, Some(None));
+        let (_, config) = load_metadata(&account.info()).unwrap();
+        assert_eq!(config.unwrap().rule_set, None);
+    }
+
+    #[test]
+    fn candy_machine_token_standard() {
+        let mut candy_machine = CandyMachine::default();
+        assert!(!mints_programmable(&candy_machine));
+
+        // the first byte of the features is not the token standard
+        candy_machine.features = PROGRAMMABLE_NON_FUNGIBLE as u64;
+        assert!(!mints_programmable(&candy_machine));
+
+        candy_machine.features = (PROGRAMMABLE_NON_FUNGIBLE as u64) << 8;
+        assert!(mints_programmable(&candy_machine));
+
+        // other feature flags are ignored
+        candy_machine.features = ((PROGRAMMABLE_NON_FUNGIBLE as u64) << 8) | 1;
+        assert!(mints_programmable(&candy_machine));
+    }
+
+    #[test]
+    fn token_record_state() {
+        let mut other = TestAccount::new(Pubkey::new_unique(), vec![0, 255, TOKEN_STATE_LOCKED]);
+        assert_eq!(
+            is_locked(&other.info()).unwrap_err(),
+            CandyGuardError::IncorrectOwner.into()
+        );
+
+        let mut truncated = TestAccount::new(mpl_token_metadata::ID, vec![0, 255]);
+        assert_eq!(
+            is_locked(&truncated.info()).unwrap_err(),
+            CandyGuardError::InvalidProgrammableMetadata.into()
+        );
+
+        let mut unlocked = TestAccount::new(mpl_token_metadata::ID, vec![0, 255, 0]);
+        assert!(!is_locked(&unlocked.info()).unwrap());
+
+        let mut locked = TestAccount::new(mpl_token_metadata::ID, vec![0, 255, TOKEN_STATE_LOCKED]);
+        assert!(is_locked(&locked.info()).unwrap());
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+// Seed value for the authority proposal PDA.
+pub const AUTHORITY_PROPOSAL_SEED: &[u8] = b"authority_proposal";
+
+/// PDA to store a pending transfer of the candy guard authority. The transfer
+/// only takes effect once the proposed authority accepts it.
+#[account]
+#[derive(Default)]
+pub struct AuthorityProposal {
+    // Candy guard associated with the proposal
+    pub candy_guard: Pubkey,
+    // Proposed authority of the candy guard
+    pub new_authority: Pubkey,
+    // Authority that created the proposal; the proposal can only be accepted
+    // while this address is the candy guard authority
+    pub proposed_by: Pubkey,
+}
+
+impl AuthorityProposal {
+    pub const SIZE: usize = 8 // discriminator
+        + 32 // candy guard
+        + 32 // new authority
+        + 32; // proposed by
+}
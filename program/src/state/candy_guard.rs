@@ -3,7 +3,11 @@ use std::collections::HashSet;
 use anchor_lang::{prelude::*, AnchorDeserialize};
 use solana_program::program_memory::sol_memcmp;
 
-use crate::{errors::CandyGuardError, guards::*, utils::fixed_length_string};
+use crate::{
+    errors::CandyGuardError,
+    guards::*,
+    utils::{fixed_length_string, resize_account},
+};
 use mpl_candy_guard_derive::GuardSet;
 
 // Bytes offset for the start of the data section:
@@ -53,6 +57,21 @@ pub struct CandyGuard {
     // 17) freeze sol payment
     // 18) freeze token payment
     // 19) program gate
+    // 20) pending update
+    // 21) config lock
+    // 22) pause
+    // 23) mint receipt
+    // 24) bot tax policy
+    // 25) instruction gate
+    // 26) signed voucher
+    // 27) third party multisig
+    // 28) stake gate
+    // 29) nft usage limit
+    // 30) nft match gate
+    // 31) nft match burn
+    // 32) nft match payment
+    // 33) edition burn
+    // 34) multi token payment
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -110,6 +129,36 @@ pub struct GuardSet {
     pub freeze_token_payment: Option<FreezeTokenPayment>,
     /// Program gate guard (restricts the programs that can be in a mint transaction).
     pub program_gate: Option<ProgramGate>,
+    /// Pending update guard (signals that a configuration change is scheduled).
+    pub pending_update: Option<PendingUpdate>,
+    /// Config lock guard (locks the configuration permanently or until a specified time).
+    pub config_lock: Option<ConfigLock>,
+    /// Pause guard (pauses the mint and selected route instructions).
+    pub pause: Option<Pause>,
+    /// Mint receipt guard (writes a receipt for each mint).
+    pub mint_receipt: Option<MintReceipt>,
+    /// Bot tax policy guard (destination, escalation and exemptions of the bot tax).
+    pub bot_tax_policy: Option<BotTaxPolicy>,
+    /// Instruction gate guard (restricts the instructions that can be in a mint transaction).
+    pub instruction_gate: Option<InstructionGate>,
+    /// Signed voucher guard (requires a voucher signed off-chain by a specified key).
+    pub signed_voucher: Option<SignedVoucher>,
+    /// Third party multisig guard (requires a threshold of extra signers for the transaction).
+    pub third_party_multisig: Option<ThirdPartyMultisig>,
+    /// Stake gate guard (restrict access to holders of a token or collection, including staked assets).
+    pub stake_gate: Option<StakeGate>,
+    /// NFT usage limit guard (limit the number of mints unlocked by each NFT of a collection).
+    pub nft_usage_limit: Option<NftUsageLimit>,
+    /// NFT match gate guard (check access based on holding an NFT matching the criteria).
+    pub nft_match_gate: Option<NftMatchGate>,
+    /// NFT match burn guard (burn NFTs matching the criteria).
+    pub nft_match_burn: Option<NftMatchBurn>,
+    /// NFT match payment guard (charge NFTs matching the criteria in order to mint).
+    pub nft_match_payment: Option<NftMatchPayment>,
+    /// Edition burn guard (burn a print edition or an amount of a fungible asset).
+    pub edition_burn: Option<EditionBurn>,
+    /// Multi token payment guard (charge amounts of tokens paid to destination wallets).
+    pub multi_token_payment: Option<MultiTokenPayment>,
 }
 
 /// Available guard types.
@@ -134,6 +183,21 @@ pub enum GuardType {
     FreezeSolPayment,
     FreezeTokenPayment,
     ProgramGate,
+    PendingUpdate,
+    ConfigLock,
+    Pause,
+    MintReceipt,
+    BotTaxPolicy,
+    InstructionGate,
+    SignedVoucher,
+    ThirdPartyMultisig,
+    StakeGate,
+    NftUsageLimit,
+    NftMatchGate,
+    NftMatchBurn,
+    NftMatchPayment,
+    EditionBurn,
+    MultiTokenPayment,
 }
 
 impl GuardType {
@@ -142,6 +206,23 @@ impl GuardType {
     }
 }
 
+impl GuardSet {
+    /// Copies the guards that are managed by their own instructions (pending update,
+    /// config lock and pause) from the `current` guard set.
+    pub fn copy_managed_guards(&mut self, current: &GuardSet) {
+        self.pending_update = current.pending_update.clone();
+        self.config_lock = current.config_lock.clone();
+        self.pause = current.pause.clone();
+    }
+
+    /// Removes the guards that are managed by their own instructions.
+    pub fn clear_managed_guards(&mut self) {
+        self.pending_update = None;
+        self.config_lock = None;
+        self.pause = None;
+    }
+}
+
 impl CandyGuardData {
     /// Serialize the candy guard data into the specified data array.
     pub fn save(&self, data: &mut [u8]) -> Result<()> {
@@ -250,6 +331,37 @@ impl CandyGuardData {
         Ok(Box::new(default))
     }
 
+    /// Replaces the guards of the group with the specified label; when there is no
+    /// group with the label, a new group is added.
+    pub fn replace_group(&mut self, label: String, guards: GuardSet) -> Result<()> {
+        let label = fixed_length_string(label, MAX_LABEL_SIZE)?;
+        let groups = self.groups.get_or_insert_with(Vec::new);
+
+        if let Some(group) = groups.iter_mut().find(|group| group.label == label) {
+            group.guards = guards;
+        } else {
+            groups.push(Group { label, guards });
+        }
+
+        Ok(())
+    }
+
+    /// Resizes the candy guard account to fit the guards data and serializes the
+    /// data into the account.
+    pub fn save_to_account<'info>(
+        &self,
+        account_info: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        // changes the account size to fit the size required by the guards
+        // this means that the size can grow or shrink
+        resize_account(account_info, self.account_size(), payer, system_program)?;
+
+        let mut account_data = account_info.data.borrow_mut();
+        self.save(&mut account_data[DATA_OFFSET..])
+    }
+
     pub fn account_size(&self) -> usize {
         DATA_OFFSET + self.size()
     }
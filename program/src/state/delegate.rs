@@ -0,0 +1,316 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CandyGuardError, utils::cmp_pubkeys};
+
+// Seed value for the delegate PDA.
+pub const DELEGATE_SEED: &[u8] = b"delegate";
+
+/// PDA to store the roles delegated by the candy guard authority to
+/// another address.
+#[account]
+#[derive(Default)]
+pub struct Delegate {
+    // Candy guard associated with the delegate
+    pub candy_guard: Pubkey,
+    // Address of the delegate
+    pub delegate: Pubkey,
+    // Authority that assigned the roles; the roles are only valid while
+    // this address is the candy guard authority
+    pub authority: Pubkey,
+    // Bitmask of the roles (see `DelegateRole`) assigned to the delegate
+    pub roles: u8,
+}
+
+impl Delegate {
+    pub const SIZE: usize = 8 // discriminator
+        + 32 // candy guard
+        + 32 // delegate
+        + 32 // authority
+        + 1; // roles
+
+    /// Returns the PDA address of the delegate record for the specified candy guard.
+    pub fn address(candy_guard: &Pubkey, delegate: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[DELEGATE_SEED, candy_guard.as_ref(), delegate.as_ref()],
+            &crate::ID,
+        )
+    }
+
+    /// Checks whether the delegate has the specified role or not.
+    pub fn has_role(&self, role: DelegateRole) -> bool {
+        self.roles & DelegateRole::as_mask(role) > 0
+    }
+
+    /// Checks that the `signer` is either the candy guard `authority` or a delegate
+    /// with the specified `role` assigned by the current `authority`. The delegate
+    /// record, when required, is retrieved from the `accounts`.
+    ///
+    /// Returns `true` if the signer is the candy guard authority.
+    pub fn assert_authority_or_role(
+        candy_guard: &Pubkey,
+        authority: &Pubkey,
+        signer: &AccountInfo,
+        role: DelegateRole,
+        accounts: &[AccountInfo],
+    ) -> Result<bool> {
+        if !signer.is_signer {
+            return err!(CandyGuardError::MissingRequiredSignature);
+        }
+
+        if cmp_pubkeys(signer.key, authority) {
+            return Ok(true);
+        }
+
+        let (pda, _) = Self::address(candy_guard, signer.key);
+
+        let account_info = accounts
+            .iter()
+            .find(|account| cmp_pubkeys(account.key, &pda))
+            .ok_or(CandyGuardError::DelegateNotAuthorized)?;
+
+        let delegate: Account<Delegate> = Account::try_from(account_info)?;
+
+        // roles assigned by a previous authority are no longer valid
+        if !cmp_pubkeys(&delegate.authority, authority) || !delegate.has_role(role) {
+            return err!(CandyGuardError::DelegateNotAuthorized);
+        }
+
+        Ok(false)
+    }
+}
+
+/// Roles that can be assigned to a delegate.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub enum DelegateRole {
+    /// Can update the dates and prices of the guards.
+    Updater,
+    /// Can unlock the funds of the freeze guards.
+    Treasurer,
+}
+
+impl DelegateRole {
+    pub fn as_mask(role: DelegateRole) -> u8 {
+        0b1u8 << (role as u8)
+    }
+
+    /// Returns the bitmask of all available roles.
+    pub fn all() -> u8 {
+        DelegateRole::as_mask(DelegateRole::Updater)
+            | DelegateRole::as_mask(DelegateRole::Treasurer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+    }
+
+    impl TestAccount {
+        fn new(key: Pubkey) -> Self {
+            Self {
+                key,
+                owner: Pubkey::default(),
+                lamports: 0,
+                data: Vec::new(),
+            }
+        }
+
+        fn record(candy_guard: &Pubkey, delegate: &Pubkey, authority: &Pubkey, roles: u8) -> Self {
+            let (key, _) = Delegate::address(candy_guard, delegate);
+            let mut data = Vec::with_capacity(Delegate::SIZE);
+            Delegate {
+                candy_guard: *candy_guard,
+                delegate: *delegate,
+                authority: *authority,
+                roles,
+            }
+            .try_serialize(&mut data)
+            .unwrap();
+
+            Self {
+                key,
+                owner: crate::ID,
+                lamports: 1,
+                data,
+            }
+        }
+
+        fn info(&mut self, is_signer: bool) -> AccountInfo<'_> {
+            AccountInfo::new(
+                &self.key,
+                is_signer,
+                false,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                false,
+                0,
+            )
+        }
+    }
+
+    fn assert_error(result: Result<bool>, error: CandyGuardError) {
+        assert_eq!(result.unwrap_err(), error.into());
+    }
+
+    #[test]
+    fn authority_is_accepted_without_record() {
+        let candy_guard = Pubkey::new_unique();
+        let mut authority = TestAccount::new(Pubkey::new_unique());
+        let key = authority.key;
+
+        let result = Delegate::assert_authority_or_role(
+            &candy_guard,
+            &key,
+            &authority.info(true),
+            DelegateRole::Updater,
+            &[],
+        );
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn signer_is_required() {
+        let candy_guard = Pubkey::new_unique();
+        let mut authority = TestAccount::new(Pubkey::new_unique());
+        let key = authority.key;
+
+        let result = Delegate::assert_authority_or_role(
+            &candy_guard,
+            &key,
+            &authority.info(false),
+            DelegateRole::Updater,
+            &[],
+        );
+        assert_error(result, CandyGuardError::MissingRequiredSignature);
+    }
+
+    #[test]
+    fn delegate_with_role_is_accepted() {
+        let candy_guard = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let mut signer = TestAccount::new(Pubkey::new_unique());
+        let mut record = TestAccount::record(
+            &candy_guard,
+            &signer.key,
+            &authority,
+            DelegateRole::as_mask(DelegateRole::Updater),
+        );
+
+        let result = Delegate::assert_authority_or_role(
+            &candy_guard,
+            &authority,
+            &signer.info(true),
+            DelegateRole::Updater,
+            &[record.info(false)],
+        );
+        assert!(!result.unwrap());
+    }
+
+    #[test]
+    fn delegate_without_record_is_rejected() {
+        let candy_guard = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let mut signer = TestAccount::new(Pubkey::new_unique());
+
+        let result = Delegate::assert_authority_or_role(
+            &candy_guard,
+            &authority,
+            &signer.info(true),
+            DelegateRole::Updater,
+            &[],
+        );
+        assert_error(result, CandyGuardError::DelegateNotAuthorized);
+    }
+
+    #[test]
+    fn delegate_without_role_is_rejected() {
+        let candy_guard = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let mut signer = TestAccount::new(Pubkey::new_unique());
+        let mut record = TestAccount::record(
+            &candy_guard,
+            &signer.key,
+            &authority,
+            DelegateRole::as_mask(DelegateRole::Treasurer),
+        );
+
+        let result = Delegate::assert_authority_or_role(
+            &candy_guard,
+            &authority,
+            &signer.info(true),
+            DelegateRole::Updater,
+            &[record.info(false)],
+        );
+        assert_error(result, CandyGuardError::DelegateNotAuthorized);
+    }
+
+    #[test]
+    fn record_of_another_delegate_is_ignored() {
+        let candy_guard = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let mut signer = TestAccount::new(Pubkey::new_unique());
+        let mut record = TestAccount::record(
+            &candy_guard,
+            &Pubkey::new_unique(),
+            &authority,
+            DelegateRole::all(),
+        );
+
+        let result = Delegate::assert_authority_or_role(
+            &candy_guard,
+            &authority,
+            &signer.info(true),
+            DelegateRole::Updater,
+            &[record.info(false)],
+        );
+        assert_error(result, CandyGuardError::DelegateNotAuthorized);
+    }
+
+    #[test]
+    fn roles_of_previous_authority_are_rejected() {
+        let candy_guard = Pubkey::new_unique();
+        let previous_authority = Pubkey::new_unique();
+        let mut signer = TestAccount::new(Pubkey::new_unique());
+        let mut record = TestAccount::record(
+            &candy_guard,
+            &signer.key,
+            &previous_authority,
+            DelegateRole::all(),
+        );
+
+        let result = Delegate::assert_authority_or_role(
+            &candy_guard,
+            &Pubkey::new_unique(),
+            &signer.info(true),
+            DelegateRole::Updater,
+            &[record.info(false)],
+        );
+        assert_error(result, CandyGuardError::DelegateNotAuthorized);
+    }
+
+    #[test]
+    fn record_owned_by_another_program_is_rejected() {
+        let candy_guard = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let mut signer = TestAccount::new(Pubkey::new_unique());
+        let mut record =
+            TestAccount::record(&candy_guard, &signer.key, &authority, DelegateRole::all());
+        record.owner = Pubkey::new_unique();
+
+        let result = Delegate::assert_authority_or_role(
+            &candy_guard,
+            &authority,
+            &signer.info(true),
+            DelegateRole::Updater,
+            &[record.info(false)],
+        );
+        assert!(result.is_err());
+    }
+}
@@ -1,3 +1,11 @@
+pub use authority_proposal::*;
 pub use candy_guard::*;
+pub use delegate::*;
+pub use scheduled_update::*;
+pub use update_buffer::*;
 
+pub mod authority_proposal;
 pub mod candy_guard;
+pub mod delegate;
+pub mod scheduled_update;
+pub mod update_buffer;
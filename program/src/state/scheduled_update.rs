@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CandyGuardError,
+    state::{CandyGuardData, GuardSet, MAX_LABEL_SIZE},
+    utils::{assert_keys_equal, fixed_length_string},
+};
+
+// Bytes offset for the start of the scheduled data section:
+//     8 (discriminator)
+//  + 32 (candy guard)
+//  +  8 (activation)
+//  +  1 (option)
+//  +  4 (string length)
+//  +  6 (label)
+pub const SCHEDULED_DATA_OFFSET: usize = 8 + 32 + 8 + 1 + 4 + MAX_LABEL_SIZE;
+
+// Seed value for the scheduled update PDA.
+pub const SCHEDULED_UPDATE_SEED: &[u8] = b"scheduled_update";
+
+/// PDA to store a configuration change that will take effect at a future time.
+#[account]
+#[derive(Default)]
+pub struct ScheduledUpdate {
+    // Candy guard associated with the scheduled update
+    pub candy_guard: Pubkey,
+    // Timestamp when the configuration takes effect
+    pub activation: i64,
+    // Label of the group replaced by the scheduled update; when the label
+    // is not specified, the whole configuration is replaced
+    pub label: Option<String>,
+    // after this there is a flexible amount of data to serialize:
+    //  - when there is no label: data (CandyGuardData struct) of the
+    //    configuration that replaces the current one
+    //  - when there is a label: data (GuardSet struct) of the group
+    //    that replaces (or is added to) the current configuration
+}
+
+impl ScheduledUpdate {
+    /// Returns the PDA address of the scheduled update for the specified candy guard.
+    pub fn address(candy_guard: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[SCHEDULED_UPDATE_SEED, candy_guard.as_ref()], &crate::ID)
+    }
+
+    /// Returns the active guard set, taking into account any scheduled update. The
+    /// `data` represents the current configuration of the candy guard and the scheduled
+    /// update account, when required, is retrieved from the `remaining_accounts`.
+    pub fn active_set(
+        candy_guard: &Pubkey,
+        data: &[u8],
+        label: Option<String>,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<Box<GuardSet>> {
+        let (default, _) = GuardSet::from_data(data)?;
+
+        let mut guard_set = match &default.pending_update {
+            Some(pending_update) if pending_update.is_active(Clock::get()?.unix_timestamp) => {
+                let (pda, _) = Self::address(candy_guard);
+
+                let account_info = remaining_accounts
+                    .iter()
+                    .find(|account| account.key == &pda)
+                    .ok_or(CandyGuardError::MissingScheduledUpdate)?;
+
+                let scheduled_update: Account<ScheduledUpdate> = Account::try_from(account_info)?;
+                assert_keys_equal(&scheduled_update.candy_guard, candy_guard)?;
+
+                let scheduled_data = account_info.data.borrow();
+                let scheduled_data = &scheduled_data[SCHEDULED_DATA_OFFSET..];
+
+                match &scheduled_update.label {
+                    // replaces the whole configuration
+                    None => CandyGuardData::active_set(scheduled_data, label)?,
+                    // replaces (or adds) the group with the scheduled label; the active set
+                    // is resolved from the resulting configuration so that the outcome is the
+                    // same as after the update is applied
+                    Some(scheduled_label) => {
+                        let (guards, _) = GuardSet::from_data(scheduled_data)?;
+                        let mut scheduled = CandyGuardData::load(data)?;
+                        scheduled.replace_group(scheduled_label.to_string(), guards)?;
+
+                        let mut updated = vec![0; scheduled.size()];
+                        scheduled.save(&mut updated)?;
+                        CandyGuardData::active_set(&updated, label)?
+                    }
+                }
+            }
+            _ => CandyGuardData::active_set(data, label)?,
+        };
+
+        // the managed guards are not part of a scheduled update and the pending update
+        // has been resolved at this point
+        guard_set.copy_managed_guards(&default);
+        guard_set.pending_update = None;
+
+        Ok(guard_set)
+    }
+
+    /// Returns the padded label to be stored in the account.
+    pub fn padded_label(label: Option<String>) -> Result<Option<String>> {
+        label
+            .map(|label| fixed_length_string(label, MAX_LABEL_SIZE))
+            .transpose()
+    }
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+// Bytes offset for the start of the buffer data section:
+//     8 (discriminator)
+//  + 32 (candy guard)
+pub const BUFFER_DATA_OFFSET: usize = 8 + 32;
+
+// Seed value for the buffer PDA.
+pub const BUFFER_SEED: &[u8] = b"update_buffer";
+
+/// PDA to stage a candy guard configuration that is too large to be sent
+/// in a single transaction.
+#[account]
+#[derive(Default)]
+pub struct UpdateBuffer {
+    // Candy guard associated with the buffer
+    pub candy_guard: Pubkey,
+    // after this there is a flexible amount of data to serialize
+    // data (CandyGuardData struct) written in chunks by the
+    // `write_buffer` instruction; the account is resized using
+    // realloc as new chunks are written
+}
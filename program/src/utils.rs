@@ -1,17 +1,38 @@
 use anchor_lang::prelude::*;
 use solana_program::{
-    program::invoke_signed,
+    entrypoint::MAX_PERMITTED_DATA_INCREASE,
+    instruction::Instruction,
+    program::{invoke, invoke_signed},
     program_memory::sol_memcmp,
     program_pack::{IsInitialized, Pack},
     pubkey::PUBKEY_BYTES,
+    system_instruction,
 };
 use spl_associated_token_account::get_associated_token_address;
+use spl_token::{instruction::TokenInstruction, state::Multisig};
 
 use crate::errors::CandyGuardError;
 
 // Empty value used for string padding.
 const NULL_STRING: &str = "\0";
 
+/// Token-2022 program.
+pub mod spl_token_2022 {
+    anchor_lang::declare_id!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+}
+
+// Token-2022 account type of a mint account (stored after the base state).
+const TOKEN_2022_MINT_TYPE: u8 = 1;
+
+// Token-2022 account type of a token account (stored after the base state).
+const TOKEN_2022_ACCOUNT_TYPE: u8 = 2;
+
+/// Token-2022 `TransferFeeConfig` mint extension type.
+pub const TRANSFER_FEE_CONFIG_EXTENSION: u16 = 1;
+
+/// Token-2022 `NonTransferable` mint extension type.
+pub const NON_TRANSFERABLE_EXTENSION: u16 = 9;
+
 /// TokenBurnParams
 pub struct TokenBurnParams<'a: 'b, 'b> {
     /// mint
@@ -90,6 +111,108 @@ pub fn assert_is_token_account(
     Ok(token_account)
 }
 
+/// Checks that the account is a token account of either the spl-token or Token-2022
+/// program, owned by the `wallet` and holding tokens of the `mint`. The account is not
+/// required to be the associated token account of the `wallet`.
+///
+/// Token-2022 accounts can include extensions, which are not part of the returned state.
+pub fn assert_is_any_token_account(
+    ta: &AccountInfo,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+) -> Result<spl_token::state::Account> {
+    if !is_token_program(ta.owner) {
+        return err!(CandyGuardError::IncorrectOwner);
+    }
+
+    let data = ta.try_borrow_data()?;
+    let base = spl_token::state::Account::LEN;
+
+    // Token-2022 accounts with extensions store the account type after the base state;
+    // the multisig length is reserved so it is never used by accounts with extensions
+    if data.len() > base
+        && (cmp_pubkeys(ta.owner, &spl_token::ID)
+            || data.len() == Multisig::LEN
+            || data[base] != TOKEN_2022_ACCOUNT_TYPE)
+    {
+        return err!(CandyGuardError::Uninitialized);
+    }
+
+    let token_account = spl_token::state::Account::unpack(&data[..base.min(data.len())])
+        .map_err(|_| CandyGuardError::Uninitialized)?;
+
+    assert_keys_equal(&token_account.owner, wallet)?;
+    assert_keys_equal(&token_account.mint, mint)?;
+
+    Ok(token_account)
+}
+
+/// Returns whether the `mint` account has the specified Token-2022 extension or not.
+/// Mints of the spl-token program have no extensions.
+pub fn mint_has_extension(mint: &AccountInfo, extension: u16) -> Result<bool> {
+    if !cmp_pubkeys(mint.owner, &spl_token_2022::ID) {
+        return Ok(false);
+    }
+
+    let data = mint.try_borrow_data()?;
+    // extensions are stored after the base state padded to the size of a token account
+    let base = spl_token::state::Account::LEN;
+
+    if data.len() <= base || data[base] != TOKEN_2022_MINT_TYPE {
+        return Ok(false);
+    }
+
+    // type-length-value entries: u16 type, u16 length and the value
+    let mut offset = base + 1;
+
+    while offset + 4 <= data.len() {
+        let extension_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let length = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+
+        if extension_type == extension {
+            return Ok(true);
+        }
+
+        offset += 4 + length;
+    }
+
+    Ok(false)
+}
+
+/// Returns the decimals of a mint account of either the spl-token or Token-2022 program.
+pub fn get_mint_decimals(mint: &AccountInfo) -> Result<u8> {
+    if !is_token_program(mint.owner) {
+        return err!(CandyGuardError::IncorrectOwner);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let base = spl_token::state::Mint::LEN;
+
+    if data.len() < base {
+        return err!(CandyGuardError::Uninitialized);
+    }
+
+    let mint_account = spl_token::state::Mint::unpack(&data[..base])
+        .map_err(|_| CandyGuardError::Uninitialized)?;
+
+    Ok(mint_account.decimals)
+}
+
+/// Returns whether the `program_id` is the spl-token or Token-2022 program.
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    cmp_pubkeys(program_id, &spl_token::ID) || cmp_pubkeys(program_id, &spl_token_2022::ID)
+}
+
+/// Returns the address of the associated token account of a `wallet` for a `mint`
+/// of the specified token program (either spl-token or Token-2022).
+pub fn associated_token_address(wallet: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &spl_associated_token_account::ID,
+    )
+    .0
+}
+
 pub fn assert_keys_equal(key1: &Pubkey, key2: &Pubkey) -> Result<()> {
     if !cmp_pubkeys(key1, key2) {
         err!(CandyGuardError::PublicKeyMismatch)
@@ -131,15 +254,19 @@ pub fn spl_token_burn(params: TokenBurnParams) -> Result<()> {
     if let Some(seed) = authority_signer_seeds {
         seeds.push(seed);
     }
+    // the instruction is created directly since the burn instruction is the
+    // same for the spl-token and Token-2022 programs
+    let instruction = Instruction {
+        program_id: *token_program.key,
+        accounts: vec![
+            AccountMeta::new(*source.key, false),
+            AccountMeta::new(*mint.key, false),
+            AccountMeta::new_readonly(*authority.key, true),
+        ],
+        data: TokenInstruction::Burn { amount }.pack(),
+    };
     let result = invoke_signed(
-        &spl_token::instruction::burn(
-            token_program.key,
-            source.key,
-            mint.key,
-            authority.key,
-            &[],
-            amount,
-        )?,
+        &instruction,
         &[source, mint, authority, token_program],
         seeds.as_slice(),
     );
@@ -176,3 +303,642 @@ pub fn spl_token_transfer(params: TokenTransferParams<'_, '_>) -> Result<()> {
 
     result.map_err(|_| CandyGuardError::TokenTransferFailed.into())
 }
+
+/// Transfers tokens using the `TransferChecked` instruction, which is required by
+/// Token-2022 mints with extensions (e.g., transfer fees). Any transfer fee is
+/// withheld from the amount received by the destination.
+pub fn spl_token_transfer_checked<'a>(
+    params: TokenTransferParams<'a, '_>,
+    mint: AccountInfo<'a>,
+    decimals: u8,
+) -> Result<()> {
+    let TokenTransferParams {
+        source,
+        destination,
+        authority,
+        token_program,
+        amount,
+        authority_signer_seeds,
+    } = params;
+
+    let mut signer_seeds = vec![];
+    if !authority_signer_seeds.is_empty() {
+        signer_seeds.push(authority_signer_seeds)
+    }
+
+    // the instruction is created directly since the spl-token instruction
+    // builder only accepts the spl-token program id
+    let instruction = Instruction {
+        program_id: *token_program.key,
+        accounts: vec![
+            AccountMeta::new(*source.key, false),
+            AccountMeta::new_readonly(*mint.key, false),
+            AccountMeta::new(*destination.key, false),
+            AccountMeta::new_readonly(*authority.key, true),
+        ],
+        data: TokenInstruction::TransferChecked { amount, decimals }.pack(),
+    };
+
+    let result = invoke_signed(
+        &instruction,
+        &[source, mint, destination, authority, token_program],
+        &signer_seeds,
+    );
+
+    result.map_err(|_| CandyGuardError::TokenTransferFailed.into())
+}
+
+/// Creates the associated token account of a `wallet` using the `CreateIdempotent`
+/// instruction of the associated token program, which does not fail if the account
+/// already exists. The instruction is created directly since the associated token
+/// instruction builder does not support it nor Token-2022 mints.
+pub fn create_associated_token_account_idempotent<'a>(
+    payer: AccountInfo<'a>,
+    ata: AccountInfo<'a>,
+    wallet: AccountInfo<'a>,
+    mint: AccountInfo<'a>,
+    system_program: AccountInfo<'a>,
+    token_program: AccountInfo<'a>,
+) -> Result<()> {
+    let instruction = Instruction {
+        program_id: spl_associated_token_account::ID,
+        accounts: vec![
+            AccountMeta::new(*payer.key, true),
+            AccountMeta::new(*ata.key, false),
+            AccountMeta::new_readonly(*wallet.key, false),
+            AccountMeta::new_readonly(*mint.key, false),
+            AccountMeta::new_readonly(*system_program.key, false),
+            AccountMeta::new_readonly(*token_program.key, false),
+        ],
+        // AssociatedTokenAccountInstruction::CreateIdempotent
+        data: vec![1],
+    };
+
+    invoke(
+        &instruction,
+        &[payer, ata, wallet, mint, system_program, token_program],
+    )
+    .map_err(|error| error.into())
+}
+
+/// Resizes a program-owned account to `new_size` bytes, funding the rent difference
+/// from the `payer` when the account grows and returning the excess to the `payer`
+/// when the account shrinks.
+///
+/// The account can only grow by `MAX_PERMITTED_DATA_INCREASE` bytes in a single
+/// instruction; larger increments return an error.
+pub fn resize_account<'info>(
+    account_info: &AccountInfo<'info>,
+    new_size: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if new_size == account_info.data_len() {
+        return Ok(());
+    }
+
+    // no risk of overflow here since the sizes will range from 0 to 10_000_000
+    let difference = new_size as i64 - account_info.data_len() as i64;
+    let snapshot = account_info.lamports();
+
+    if difference > 0 {
+        if difference as usize > MAX_PERMITTED_DATA_INCREASE {
+            return err!(CandyGuardError::DataIncrementLimitExceeded);
+        }
+
+        let lamports_diff = Rent::get()?
+            .minimum_balance(new_size)
+            .checked_sub(snapshot)
+            .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+        msg!("Funding {} lamports for account realloc", lamports_diff);
+
+        invoke(
+            &system_instruction::transfer(payer.key, account_info.key, lamports_diff),
+            &[payer.clone(), account_info.clone(), system_program.clone()],
+        )?;
+    } else {
+        let lamports_diff = snapshot
+            .checked_sub(Rent::get()?.minimum_balance(new_size))
+            .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+        msg!(
+            "Withdrawing {} lamports from account realloc",
+            lamports_diff
+        );
+
+        **account_info.lamports.borrow_mut() = snapshot - lamports_diff;
+
+        **payer.lamports.borrow_mut() = payer
+            .lamports()
+            .checked_add(lamports_diff)
+            .ok_or(CandyGuardError::NumericalOverflowError)?;
+    }
+
+    msg!("Account realloc by {} bytes", difference);
+    // changes the account size (the size can grow or shrink)
+    account_info.realloc(new_size, false)?;
+
+    Ok(())
+}
+
+/// Creates a PDA account owned by `owner`, funding the rent from the `payer`. Unlike
+/// `create_account`, it does not fail when the address already holds lamports, e.g.,
+/// sent by someone else to block the creation of the account; only the missing rent
+/// is transferred before the account is allocated and assigned.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account_info: &AccountInfo<'info>,
+    size: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let required_lamports = Rent::get()?
+        .minimum_balance(size)
+        .saturating_sub(account_info.lamports());
+
+    if required_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account_info.key, required_lamports),
+            &[payer.clone(), account_info.clone()],
+        )?;
+    }
+
+    invoke_signed(
+        &system_instruction::allocate(account_info.key, size as u64),
+        std::slice::from_ref(account_info),
+        &[signer_seeds],
+    )?;
+
+    invoke_signed(
+        &system_instruction::assign(account_info.key, owner),
+        std::slice::from_ref(account_info),
+        &[signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+/// Returns the numeric code of an error. Errors that do not have a custom code
+/// are represented by `0`.
+pub fn error_code(error: &Error) -> u32 {
+    match error {
+        Error::AnchorError(error) => error.error_code_number,
+        Error::ProgramError(error) => match error.program_error {
+            ProgramError::Custom(code) => code,
+            _ => 0,
+        },
+    }
+}
+
+/// Instruction read from the instructions sysvar.
+pub struct SysvarInstruction<'a> {
+    /// Program id of the instruction.
+    pub program_id: Pubkey,
+    /// Instruction data.
+    pub data: &'a [u8],
+}
+
+/// Bounds-checked reader of the instructions sysvar data. The sysvar data has
+/// the following layout:
+///
+///   - `u16` number of instructions
+///   - `u16` offset of each instruction
+///   - for each instruction:
+///       - `u16` number of accounts
+///       - `[u8; 33]` (flags + pubkey) for each account
+///       - `[u8; 32]` program id
+///       - `u16` data length
+///       - `[u8]` data
+///
+/// Malformed data results in a `CandyGuardError::InvalidInstructionsSysvar` error.
+pub struct InstructionsSysvarReader<'a> {
+    data: &'a [u8],
+    num_instructions: u16,
+}
+
+impl<'a> InstructionsSysvarReader<'a> {
+    pub fn new(data: &'a [u8]) -> Result<Self> {
+        let num_instructions = read_u16_at(data, 0)?;
+        // the offsets of all instructions must be present
+        read_slice_at(data, 2, num_instructions as usize * 2)?;
+
+        Ok(Self {
+            data,
+            num_instructions,
+        })
+    }
+
+    /// Returns the number of instructions in the transaction.
+    pub fn num_instructions(&self) -> u16 {
+        self.num_instructions
+    }
+
+    /// Returns the instruction at the specified index.
+    pub fn instruction(&self, index: u16) -> Result<SysvarInstruction<'a>> {
+        if index >= self.num_instructions {
+            return err!(CandyGuardError::InvalidInstructionsSysvar);
+        }
+
+        let mut offset = read_u16_at(self.data, 2 + index as usize * 2)? as usize;
+
+        let num_accounts = read_u16_at(self.data, offset)? as usize;
+        offset += 2 + num_accounts * (1 + PUBKEY_BYTES);
+
+        let program_id = Pubkey::new(read_slice_at(self.data, offset, PUBKEY_BYTES)?);
+        offset += PUBKEY_BYTES;
+
+        let data_len = read_u16_at(self.data, offset)? as usize;
+        offset += 2;
+
+        Ok(SysvarInstruction {
+            program_id,
+            data: read_slice_at(self.data, offset, data_len)?,
+        })
+    }
+
+    /// Returns an iterator over the instructions of the transaction.
+    pub fn instructions(&self) -> impl Iterator<Item = Result<SysvarInstruction<'a>>> + '_ {
+        (0..self.num_instructions).map(move |index| self.instruction(index))
+    }
+}
+
+/// Reads a `u16` (little-endian) at the specified offset.
+fn read_u16_at(data: &[u8], offset: usize) -> Result<u16> {
+    let bytes = read_slice_at(data, offset, 2)?;
+    Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Reads a slice of `len` bytes at the specified offset.
+fn read_slice_at(data: &[u8], offset: usize, len: usize) -> Result<&[u8]> {
+    offset
+        .checked_add(len)
+        .and_then(|end| data.get(offset..end))
+        .ok_or_else(|| error!(CandyGuardError::InvalidInstructionsSysvar))
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        program_option::COption,
+        sysvar::instructions::{
+            construct_instructions_data, BorrowedAccountMeta, BorrowedInstruction,
+        },
+    };
+    use spl_token::state::{Account, AccountState};
+
+    use super::*;
+
+    // Number of generated cases for each property.
+    const CASES: usize = 500;
+
+    /// Deterministic pseudo-random generator (xorshift64*), so failures can be
+    /// reproduced from the seed.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 >> 12;
+            self.0 ^= self.0 << 25;
+            self.0 ^= self.0 >> 27;
+            self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+        }
+
+        fn below(&mut self, bound: usize) -> usize {
+            (self.next_u64() % bound as u64) as usize
+        }
+
+        fn bytes(&mut self, len: usize) -> Vec<u8> {
+            (0..len).map(|_| self.next_u64() as u8).collect()
+        }
+
+        fn pubkey(&mut self) -> Pubkey {
+            Pubkey::new(&self.bytes(PUBKEY_BYTES))
+        }
+    }
+
+    struct GeneratedInstruction {
+        program_id: Pubkey,
+        accounts: Vec<(Pubkey, bool, bool)>,
+        data: Vec<u8>,
+    }
+
+    fn generate_instructions(rng: &mut Rng) -> Vec<GeneratedInstruction> {
+        (0..rng.below(8))
+            .map(|_| GeneratedInstruction {
+                program_id: rng.pubkey(),
+                accounts: (0..rng.below(6))
+                    .map(|_| (rng.pubkey(), rng.below(2) == 0, rng.below(2) == 0))
+                    .collect(),
+                data: {
+                    let len = rng.below(64);
+                    rng.bytes(len)
+                },
+            })
+            .collect()
+    }
+
+    /// Serializes the instructions using the same layout as the runtime.
+    fn sysvar_data(instructions: &[GeneratedInstruction]) -> Vec<u8> {
+        let borrowed = instructions
+            .iter()
+            .map(|instruction| BorrowedInstruction {
+                program_id: &instruction.program_id,
+                accounts: instruction
+                    .accounts
+                    .iter()
+                    .map(|(pubkey, is_signer, is_writable)| BorrowedAccountMeta {
+                        pubkey,
+                        is_signer: *is_signer,
+                        is_writable: *is_writable,
+                    })
+                    .collect(),
+                data: &instruction.data,
+            })
+            .collect::<Vec<_>>();
+
+        construct_instructions_data(&borrowed)
+    }
+
+    /// Reads every instruction, which must not panic whatever the data is.
+    fn read_all(data: &[u8]) -> Option<Vec<Result<SysvarInstruction<'_>>>> {
+        InstructionsSysvarReader::new(data)
+            .ok()
+            .map(|reader| reader.instructions().collect())
+    }
+
+    #[test]
+    fn reads_generated_layouts() {
+        let mut rng = Rng(0x5eed_0001);
+
+        for _ in 0..CASES {
+            let instructions = generate_instructions(&mut rng);
+            let data = sysvar_data(&instructions);
+
+            let reader = InstructionsSysvarReader::new(&data).unwrap();
+            assert_eq!(reader.num_instructions() as usize, instructions.len());
+
+            for (read, expected) in reader.instructions().zip(&instructions) {
+                let read = read.unwrap();
+                assert_eq!(read.program_id, expected.program_id);
+                assert_eq!(read.data, expected.data.as_slice());
+            }
+
+            assert!(reader.instruction(reader.num_instructions()).is_err());
+        }
+    }
+
+    #[test]
+    fn truncated_layouts_are_rejected() {
+        let mut rng = Rng(0x5eed_0002);
+
+        for _ in 0..CASES {
+            let instructions = generate_instructions(&mut rng);
+            let data = sysvar_data(&instructions);
+            // the last 2 bytes hold the current instruction index, which is not read
+            let len = rng.below(data.len() - 2);
+
+            if let Some(read) = read_all(&data[..len]) {
+                // the last instruction always ends after the truncated length
+                assert!(read.last().unwrap().is_err());
+
+                // a truncated instruction is never read partially
+                for (read, expected) in read.into_iter().zip(&instructions) {
+                    if let Ok(read) = read {
+                        assert_eq!(read.program_id, expected.program_id);
+                        assert_eq!(read.data, expected.data.as_slice());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn corrupted_layouts_do_not_panic() {
+        let mut rng = Rng(0x5eed_0003);
+
+        for _ in 0..CASES {
+            let instructions = generate_instructions(&mut rng);
+            let mut data = sysvar_data(&instructions);
+
+            for _ in 0..rng.below(8) + 1 {
+                let index = rng.below(data.len());
+                data[index] = rng.next_u64() as u8;
+            }
+
+            if let Some(read) = read_all(&data) {
+                for instruction in read.into_iter().flatten() {
+                    // data slices always point inside the sysvar data
+                    let start = instruction.data.as_ptr() as usize - data.as_ptr() as usize;
+                    assert!(start + instruction.data.len() <= data.len());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn random_bytes_do_not_panic() {
+        let mut rng = Rng(0x5eed_0004);
+
+        for _ in 0..CASES {
+            let len = rng.below(512);
+            let data = rng.bytes(len);
+
+            if let Some(read) = read_all(&data) {
+                assert_eq!(read.len(), u16::from_le_bytes([data[0], data[1]]) as usize);
+            }
+        }
+    }
+
+    fn account_info<'a>(
+        key: &'a Pubkey,
+        owner: &'a Pubkey,
+        lamports: &'a mut u64,
+        data: &'a mut [u8],
+    ) -> AccountInfo<'a> {
+        AccountInfo::new(key, false, false, lamports, data, owner, false, 0)
+    }
+
+    fn token_account_data(wallet: &Pubkey, mint: &Pubkey) -> Vec<u8> {
+        let mut data = vec![0; Account::LEN];
+        Account {
+            mint: *mint,
+            owner: *wallet,
+            amount: 10,
+            delegate: COption::None,
+            state: AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        }
+        .pack_into_slice(&mut data);
+        data
+    }
+
+    fn mint_data(decimals: u8) -> Vec<u8> {
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+        spl_token::state::Mint {
+            mint_authority: COption::None,
+            supply: 10,
+            decimals,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+        .pack_into_slice(&mut data);
+        data
+    }
+
+    /// Appends Token-2022 extensions (type-length-value entries) to the base state,
+    /// which is padded to the size of a token account.
+    fn with_extensions(
+        mut data: Vec<u8>,
+        account_type: u8,
+        extensions: &[(u16, &[u8])],
+    ) -> Vec<u8> {
+        data.resize(Account::LEN, 0);
+        data.push(account_type);
+
+        for (extension_type, value) in extensions {
+            data.extend_from_slice(&extension_type.to_le_bytes());
+            data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+            data.extend_from_slice(value);
+        }
+
+        data
+    }
+
+    fn assert_error<T: std::fmt::Debug>(result: Result<T>, error: CandyGuardError) {
+        assert_eq!(result.unwrap_err(), error.into());
+    }
+
+    #[test]
+    fn accepts_token_accounts_of_both_programs() {
+        let (key, wallet, mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut lamports = 0;
+
+        let mut data = token_account_data(&wallet, &mint);
+        let info = account_info(&key, &spl_token::ID, &mut lamports, &mut data);
+        assert_eq!(
+            assert_is_any_token_account(&info, &wallet, &mint)
+                .unwrap()
+                .amount,
+            10
+        );
+
+        // Token-2022 account with an extension (immutable owner)
+        let mut data = with_extensions(
+            token_account_data(&wallet, &mint),
+            TOKEN_2022_ACCOUNT_TYPE,
+            &[(7, &[])],
+        );
+        let info = account_info(&key, &spl_token_2022::ID, &mut lamports, &mut data);
+        assert_eq!(
+            assert_is_any_token_account(&info, &wallet, &mint)
+                .unwrap()
+                .amount,
+            10
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_token_accounts() {
+        let (key, wallet, mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut lamports = 0;
+
+        // not owned by a token program
+        let mut data = token_account_data(&wallet, &mint);
+        let owner = Pubkey::new_unique();
+        let info = account_info(&key, &owner, &mut lamports, &mut data);
+        assert_error(
+            assert_is_any_token_account(&info, &wallet, &mint),
+            CandyGuardError::IncorrectOwner,
+        );
+
+        // spl-token accounts have no extensions
+        let mut data = with_extensions(
+            token_account_data(&wallet, &mint),
+            TOKEN_2022_ACCOUNT_TYPE,
+            &[],
+        );
+        let info = account_info(&key, &spl_token::ID, &mut lamports, &mut data);
+        assert_error(
+            assert_is_any_token_account(&info, &wallet, &mint),
+            CandyGuardError::Uninitialized,
+        );
+
+        // Token-2022 mint with extensions
+        let mut data = with_extensions(mint_data(0), TOKEN_2022_MINT_TYPE, &[]);
+        let info = account_info(&key, &spl_token_2022::ID, &mut lamports, &mut data);
+        assert_error(
+            assert_is_any_token_account(&info, &wallet, &mint),
+            CandyGuardError::Uninitialized,
+        );
+
+        // multisig account
+        let mut data = vec![0; Multisig::LEN];
+        data[..Account::LEN].copy_from_slice(&token_account_data(&wallet, &mint));
+        data[Account::LEN] = TOKEN_2022_ACCOUNT_TYPE;
+        let info = account_info(&key, &spl_token_2022::ID, &mut lamports, &mut data);
+        assert_error(
+            assert_is_any_token_account(&info, &wallet, &mint),
+            CandyGuardError::Uninitialized,
+        );
+
+        // owned by another wallet
+        let mut data = token_account_data(&Pubkey::new_unique(), &mint);
+        let info = account_info(&key, &spl_token::ID, &mut lamports, &mut data);
+        assert_error(
+            assert_is_any_token_account(&info, &wallet, &mint),
+            CandyGuardError::PublicKeyMismatch,
+        );
+
+        // tokens of another mint
+        let mut data = token_account_data(&wallet, &Pubkey::new_unique());
+        let info = account_info(&key, &spl_token::ID, &mut lamports, &mut data);
+        assert_error(
+            assert_is_any_token_account(&info, &wallet, &mint),
+            CandyGuardError::PublicKeyMismatch,
+        );
+    }
+
+    #[test]
+    fn finds_mint_extensions() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+
+        // transfer fee config (1) and non-transferable (9) extensions
+        let mut data = with_extensions(
+            mint_data(6),
+            TOKEN_2022_MINT_TYPE,
+            &[(1, &[0; 108]), (NON_TRANSFERABLE_EXTENSION, &[])],
+        );
+        let info = account_info(&key, &spl_token_2022::ID, &mut lamports, &mut data);
+        assert!(mint_has_extension(&info, NON_TRANSFERABLE_EXTENSION).unwrap());
+        assert!(mint_has_extension(&info, 1).unwrap());
+        assert!(!mint_has_extension(&info, 3).unwrap());
+        assert_eq!(get_mint_decimals(&info).unwrap(), 6);
+
+        // Token-2022 mint without extensions
+        let mut data = mint_data(2);
+        let info = account_info(&key, &spl_token_2022::ID, &mut lamports, &mut data);
+        assert!(!mint_has_extension(&info, NON_TRANSFERABLE_EXTENSION).unwrap());
+        assert_eq!(get_mint_decimals(&info).unwrap(), 2);
+
+        // spl-token mints have no extensions
+        let mut data = with_extensions(
+            mint_data(0),
+            TOKEN_2022_MINT_TYPE,
+            &[(NON_TRANSFERABLE_EXTENSION, &[])],
+        );
+        let info = account_info(&key, &spl_token::ID, &mut lamports, &mut data);
+        assert!(!mint_has_extension(&info, NON_TRANSFERABLE_EXTENSION).unwrap());
+    }
+}